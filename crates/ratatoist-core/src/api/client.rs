@@ -1,39 +1,71 @@
+use std::io::Read;
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
 use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
+use tokio::sync::{mpsc, watch};
 use tracing::{debug, error, info, warn};
 
-use super::models::{Comment, CompletedTasksResponse, Paginated, Task, UserInfo};
+use super::demo::DemoBackend;
+use super::error::TodoistError;
+use super::metrics::ApiMetrics;
+use super::models::{
+    ActivityEvent, Comment, CompletedTasksResponse, Paginated, ProductivityStats, Task, UserInfo,
+    WorkspaceUser, WorkspaceUsersPage,
+};
 use super::sync::{SyncRequest, SyncResponse};
 
 const BASE_URL: &str = "https://api.todoist.com/api/v1";
-const SYNC_URL: &str = "https://api.todoist.com/api/v1/sync";
+/// Replaces [`BASE_URL`], version segment included, to target a staging
+/// server, a local mock or another API version.
+const BASE_URL_ENV: &str = "RATATOIST_API_BASE";
 const MAX_RETRIES: u32 = 3;
 const MAX_PAGES: usize = 50;
+/// Body chunks buffered between the download and the sync parser.
+const SYNC_BODY_CHUNKS: usize = 16;
 
-#[derive(Debug)]
-struct RateLimitError {
-    retry_after_secs: Option<u64>,
+/// The Todoist API, or the `--demo` stand-in for it. Each call goes to
+/// one backend; the HTTP paths know nothing of the demo.
+pub struct TodoistClient {
+    backend: Backend,
+    metrics: Arc<ApiMetrics>,
 }
 
-impl std::fmt::Display for RateLimitError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.retry_after_secs {
-            Some(s) => write!(f, "rate limited (retry after {s}s)"),
-            None => write!(f, "rate limited"),
-        }
-    }
+enum Backend {
+    Http(HttpClient),
+    /// Set by [`TodoistClient::demo`]: every call is served from memory.
+    Demo(Mutex<DemoBackend>),
 }
 
-impl std::error::Error for RateLimitError {}
-
-pub struct TodoistClient {
+/// Requests against the real API.
+struct HttpClient {
     client: reqwest::Client,
+    /// API root every endpoint is joined onto, without a trailing slash.
+    base_url: String,
+    /// Shared with [`TodoistClient::metrics`].
+    metrics: Arc<ApiMetrics>,
 }
 
 impl TodoistClient {
+    /// A client for [`default_base_url`](Self::default_base_url).
     pub fn new(token: &str) -> Result<Self> {
+        Self::with_base_url(token, &Self::default_base_url())
+    }
+
+    /// `RATATOIST_API_BASE` when set, otherwise Todoist's v1 API. The
+    /// websocket needs no setting of its own: its URL comes from this
+    /// server's sync response.
+    pub fn default_base_url() -> String {
+        std::env::var(BASE_URL_ENV)
+            .ok()
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| BASE_URL.to_string())
+    }
+
+    /// A client for an API root other than the default, such as a mock
+    /// server in tests. REST and sync endpoints are joined onto it.
+    pub fn with_base_url(token: &str, base_url: &str) -> Result<Self> {
         let mut headers = HeaderMap::new();
         let auth = format!("Bearer {token}");
         headers.insert(
@@ -41,42 +73,196 @@ impl TodoistClient {
             HeaderValue::from_str(&auth).context("invalid API token characters")?,
         );
 
+        // Sends Accept-Encoding and decodes transparently; full syncs are
+        // megabytes of JSON that shrink several-fold.
         let client = reqwest::Client::builder()
             .default_headers(headers)
+            .gzip(true)
+            .brotli(true)
             .timeout(Duration::from_secs(30))
             .connect_timeout(Duration::from_secs(10))
             .build()
             .context("failed to build HTTP client")?;
 
-        info!("todoist client initialized");
-        Ok(Self { client })
+        info!(base_url, "todoist client initialized");
+        let metrics = Arc::<ApiMetrics>::default();
+        Ok(Self {
+            backend: Backend::Http(HttpClient {
+                client,
+                base_url: base_url.trim_end_matches('/').to_string(),
+                metrics: Arc::clone(&metrics),
+            }),
+            metrics,
+        })
+    }
+
+    /// A client backed by generated sample data instead of the network, for
+    /// screenshots and trying the app without an account.
+    pub fn demo() -> Self {
+        info!("demo client initialized");
+        Self {
+            backend: Backend::Demo(Mutex::new(DemoBackend::new())),
+            metrics: Arc::default(),
+        }
+    }
+
+    /// The request budget as of the latest response, shared so the UI can
+    /// read it while requests are in flight.
+    pub fn metrics(&self) -> Arc<ApiMetrics> {
+        Arc::clone(&self.metrics)
     }
 
     /// All reads and writes. Retries on 429 with exponential backoff + jitter.
     pub async fn sync(&self, req: &SyncRequest) -> Result<SyncResponse> {
-        self.sync_with_retry(req).await
+        match &self.backend {
+            Backend::Http(http) => http.sync_with_retry(req, None).await,
+            Backend::Demo(demo) => lock(demo).sync(req),
+        }
+    }
+
+    /// [`sync`](Self::sync), reporting the bytes received so far to
+    /// `progress` while the response downloads.
+    pub async fn sync_with_progress(
+        &self,
+        req: &SyncRequest,
+        progress: &watch::Sender<u64>,
+    ) -> Result<SyncResponse> {
+        match &self.backend {
+            Backend::Http(http) => http.sync_with_retry(req, Some(progress)).await,
+            Backend::Demo(demo) => lock(demo).sync(req),
+        }
     }
 
     /// Auth check on startup; also returns websocket_url.
     pub async fn get_user(&self) -> Result<UserInfo> {
-        let url = format!("{BASE_URL}/user");
-        let resp = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .context("failed to reach Todoist API")?;
-        let status = resp.status();
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            anyhow::bail!("Todoist API error ({status}): {body}");
+        match &self.backend {
+            Backend::Http(http) => http.get_user().await,
+            Backend::Demo(demo) => Ok(lock(demo).user()),
+        }
+    }
+
+    /// Completion counts, goals and streaks — REST only, not part of Sync.
+    pub async fn get_productivity_stats(&self) -> Result<ProductivityStats> {
+        match &self.backend {
+            Backend::Http(http) => http.get_productivity_stats().await,
+            Backend::Demo(demo) => Ok(lock(demo).productivity_stats()),
+        }
+    }
+
+    /// Adds a task from one line of text, parsed server-side exactly as the
+    /// official apps' quick add does: `#project`, `/section`, `@label`,
+    /// `+assignee`, `p1`–`p4` and natural-language dates.
+    pub async fn quick_add(&self, text: &str) -> Result<Task> {
+        match &self.backend {
+            Backend::Http(http) => http.quick_add(text).await,
+            Backend::Demo(demo) => Ok(lock(demo).quick_add(text)),
         }
-        resp.json().await.context("failed to parse user response")
     }
 
     /// Per-task comment fetch — targeted REST call, not available via Sync.
     pub async fn get_comments(&self, task_id: &str) -> Result<Vec<Comment>> {
-        let base = format!("{BASE_URL}/comments?task_id={task_id}");
+        match &self.backend {
+            Backend::Http(http) => http.get_comments(task_id).await,
+            Backend::Demo(demo) => Ok(lock(demo).comments(task_id)),
+        }
+    }
+
+    /// The newest activity log entries for one task, newest first. Only the
+    /// first page: the detail pane shows recent history, not all of it.
+    pub async fn get_task_activity(&self, task_id: &str) -> Result<Vec<ActivityEvent>> {
+        match &self.backend {
+            Backend::Http(http) => http.get_task_activity(task_id).await,
+            Backend::Demo(demo) => Ok(lock(demo).activity(task_id)),
+        }
+    }
+
+    /// Workspace members — REST only, the Sync API doesn't list them.
+    pub async fn get_workspace_users(&self, workspace_id: &str) -> Result<Vec<WorkspaceUser>> {
+        match &self.backend {
+            Backend::Http(http) => http.get_workspace_users(workspace_id).await,
+            // The demo account has no workspaces.
+            Backend::Demo(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Completed tasks are not available through the Sync API.
+    /// Uses `annotate_items=1` to get the full Task object (with parent_id, priority, etc.).
+    pub async fn get_completed_tasks(
+        &self,
+        project_id: Option<&str>,
+        since: Option<&str>,
+    ) -> Result<Vec<Task>> {
+        match &self.backend {
+            Backend::Http(http) => http.get_completed_tasks(project_id, since).await,
+            Backend::Demo(demo) => Ok(lock(demo).completed_tasks(project_id)),
+        }
+    }
+}
+
+fn lock(demo: &Mutex<DemoBackend>) -> MutexGuard<'_, DemoBackend> {
+    demo.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl HttpClient {
+    /// Sends a request and notes the budget its response reports.
+    async fn send(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> std::result::Result<reqwest::Response, TodoistError> {
+        let resp = req.send().await.map_err(TodoistError::Network)?;
+        self.metrics.record(resp.headers());
+        if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = resp
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|s| s.parse::<u64>().ok());
+            self.metrics.record_exhausted(retry_after);
+        }
+        Ok(resp)
+    }
+
+    async fn get_user(&self) -> Result<UserInfo> {
+        let url = format!("{}/user", self.base_url);
+        let resp = self.send(self.client.get(&url)).await?;
+        if !resp.status().is_success() {
+            return Err(TodoistError::from_response(resp).await.into());
+        }
+        resp.json().await.context("failed to parse user response")
+    }
+
+    async fn get_productivity_stats(&self) -> Result<ProductivityStats> {
+        let url = format!("{}/tasks/completed/stats", self.base_url);
+        debug!("GET productivity stats");
+        let resp = self.send(self.client.get(&url)).await?;
+        if !resp.status().is_success() {
+            return Err(TodoistError::from_response(resp).await.into());
+        }
+        resp.json()
+            .await
+            .context("failed to parse productivity stats response")
+    }
+
+    async fn quick_add(&self, text: &str) -> Result<Task> {
+        let url = format!("{}/tasks/quick", self.base_url);
+        debug!("POST quick add");
+        let resp = self
+            .send(
+                self.client
+                    .post(&url)
+                    .json(&serde_json::json!({ "text": text })),
+            )
+            .await?;
+        if !resp.status().is_success() {
+            return Err(TodoistError::from_response(resp).await.into());
+        }
+        resp.json()
+            .await
+            .context("failed to parse quick add response")
+    }
+
+    async fn get_comments(&self, task_id: &str) -> Result<Vec<Comment>> {
+        let base = format!("{}/comments?task_id={task_id}", self.base_url);
         let start = Instant::now();
 
         debug!(task_id, "GET comments");
@@ -89,17 +275,10 @@ impl TodoistClient {
                 None => base.clone(),
             };
 
-            let resp = self
-                .client
-                .get(&url)
-                .send()
-                .await
-                .context("failed to reach Todoist API")?;
+            let resp = self.send(self.client.get(&url)).await?;
 
-            let status = resp.status();
-            if !status.is_success() {
-                let body = resp.text().await.unwrap_or_default();
-                anyhow::bail!("Todoist API error ({status}): {body}");
+            if !resp.status().is_success() {
+                return Err(TodoistError::from_response(resp).await.into());
             }
 
             let page: Paginated<Comment> = resp
@@ -131,15 +310,82 @@ impl TodoistClient {
         Ok(all)
     }
 
-    /// Completed tasks are not available through the Sync API.
-    /// Uses `annotate_items=1` to get the full Task object (with parent_id, priority, etc.).
-    pub async fn get_completed_tasks(
+    async fn get_task_activity(&self, task_id: &str) -> Result<Vec<ActivityEvent>> {
+        let url = format!(
+            "{}/activities?object_type=item&object_id={task_id}&limit=50",
+            self.base_url
+        );
+
+        debug!(task_id, "GET activities");
+
+        let resp = self.send(self.client.get(&url)).await?;
+
+        if !resp.status().is_success() {
+            return Err(TodoistError::from_response(resp).await.into());
+        }
+
+        let page: Paginated<ActivityEvent> = resp
+            .json()
+            .await
+            .context("failed to parse activity response")?;
+
+        info!(count = page.results.len(), task_id, "fetched task activity");
+        Ok(page.results)
+    }
+
+    async fn get_workspace_users(&self, workspace_id: &str) -> Result<Vec<WorkspaceUser>> {
+        let base = format!(
+            "{}/workspaces/users?workspace_id={workspace_id}",
+            self.base_url
+        );
+
+        debug!(workspace_id, "GET workspace users");
+
+        let mut all = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..MAX_PAGES {
+            let url = match &cursor {
+                Some(c) => format!("{base}&cursor={c}"),
+                None => base.clone(),
+            };
+
+            let resp = self.send(self.client.get(&url)).await?;
+
+            if !resp.status().is_success() {
+                return Err(TodoistError::from_response(resp).await.into());
+            }
+
+            let page: WorkspaceUsersPage = resp
+                .json()
+                .await
+                .context("failed to parse workspace users response")?;
+
+            all.extend(page.workspace_users);
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        if cursor.is_some() {
+            warn!(
+                workspace_id,
+                max_pages = MAX_PAGES,
+                "workspace users pagination truncated"
+            );
+        }
+
+        info!(count = all.len(), workspace_id, "fetched workspace users");
+        Ok(all)
+    }
+
+    async fn get_completed_tasks(
         &self,
         project_id: Option<&str>,
         since: Option<&str>,
     ) -> Result<Vec<Task>> {
         let start = Instant::now();
-        let mut base = format!("{BASE_URL}/tasks/completed?annotate_items=1");
+        let mut base = format!("{}/tasks/completed?annotate_items=1", self.base_url);
 
         if let Some(pid) = project_id {
             base = format!("{base}&project_id={pid}");
@@ -158,22 +404,16 @@ impl TodoistClient {
 
             debug!(url = %url, "GET completed tasks");
 
-            let resp = self
-                .client
-                .get(&url)
-                .send()
-                .await
-                .context("failed to reach Todoist API")?;
+            let resp = self.send(self.client.get(&url)).await?;
 
             let status = resp.status();
             if !status.is_success() {
-                let body = resp.text().await.unwrap_or_default();
                 error!(
                     status = status.as_u16(),
                     elapsed_ms = start.elapsed().as_millis() as u64,
                     "completed tasks fetch failed"
                 );
-                anyhow::bail!("Todoist API error ({status}): {body}");
+                return Err(TodoistError::from_response(resp).await.into());
             }
 
             let wrapper: CompletedTasksResponse = resp
@@ -218,15 +458,18 @@ impl TodoistClient {
         Ok(tasks)
     }
 
-    async fn sync_with_retry(&self, body: &SyncRequest) -> Result<SyncResponse> {
+    async fn sync_with_retry(
+        &self,
+        body: &SyncRequest,
+        progress: Option<&watch::Sender<u64>>,
+    ) -> Result<SyncResponse> {
         let mut base_delay = Duration::from_secs(1);
         for attempt in 0..=MAX_RETRIES {
-            match self.post_sync_once(body).await {
+            match self.post_sync_once(body, progress).await {
                 Ok(resp) => return Ok(resp),
                 Err(e) => {
-                    if let Some(rate_limit) = e.downcast_ref::<RateLimitError>() {
-                        let retry_secs =
-                            rate_limit.retry_after_secs.unwrap_or(base_delay.as_secs());
+                    if let Some(TodoistError::RateLimited { retry_after }) = e.downcast_ref() {
+                        let retry_secs = retry_after.unwrap_or(base_delay.as_secs());
                         let jitter: u64 = rand::random::<u64>() % 3;
                         warn!(attempt, retry_secs, jitter, "rate limited, backing off");
                         tokio::time::sleep(Duration::from_secs(retry_secs + jitter)).await;
@@ -237,10 +480,15 @@ impl TodoistClient {
                 }
             }
         }
-        anyhow::bail!("rate limited after {} retries", MAX_RETRIES + 1)
+        warn!(retries = MAX_RETRIES + 1, "still rate limited, giving up");
+        Err(TodoistError::RateLimited { retry_after: None }.into())
     }
 
-    async fn post_sync_once(&self, body: &SyncRequest) -> Result<SyncResponse> {
+    async fn post_sync_once(
+        &self,
+        body: &SyncRequest,
+        progress: Option<&watch::Sender<u64>>,
+    ) -> Result<SyncResponse> {
         let start = Instant::now();
         debug!(
             sync_token = %body.sync_token,
@@ -250,44 +498,26 @@ impl TodoistClient {
         );
 
         let resp = self
-            .client
-            .post(SYNC_URL)
-            .json(body)
-            .send()
-            .await
-            .context("failed to reach Todoist API")?;
+            .send(
+                self.client
+                    .post(format!("{}/sync", self.base_url))
+                    .json(body),
+            )
+            .await?;
 
         let status = resp.status();
         let elapsed = start.elapsed();
 
-        if status.as_u16() == 429 {
-            let retry_after = resp
-                .headers()
-                .get("Retry-After")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|s| s.parse::<u64>().ok());
-
-            let body_text = resp.text().await.unwrap_or_default();
-            let from_body = serde_json::from_str::<serde_json::Value>(&body_text)
-                .ok()
-                .and_then(|v| v["error_extra"]["retry_after"].as_u64());
-
-            return Err(anyhow::Error::new(RateLimitError {
-                retry_after_secs: retry_after.or(from_body),
-            }));
-        }
-
         if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
             error!(
                 status = status.as_u16(),
                 elapsed_ms = elapsed.as_millis() as u64,
                 "sync api error"
             );
-            anyhow::bail!("Todoist API error ({status}): {body}");
+            return Err(TodoistError::from_response(resp).await.into());
         }
 
-        let sync_resp: SyncResponse = resp.json().await.context("failed to parse sync response")?;
+        let sync_resp = read_sync_body(resp, progress).await?;
 
         info!(
             full_sync = sync_resp.full_sync,
@@ -301,3 +531,99 @@ impl TodoistClient {
         Ok(sync_resp)
     }
 }
+
+/// Parses a sync body while it downloads. Chunks go to a blocking parser
+/// through a bounded channel, so a full sync of a large account is never
+/// held as one buffer and parsing it doesn't stall the runtime.
+async fn read_sync_body(
+    mut resp: reqwest::Response,
+    progress: Option<&watch::Sender<u64>>,
+) -> Result<SyncResponse> {
+    let (tx, rx) = mpsc::channel(SYNC_BODY_CHUNKS);
+    let parse = tokio::task::spawn_blocking(move || {
+        serde_json::from_reader::<_, SyncResponse>(std::io::BufReader::new(ChunkReader::new(rx)))
+    });
+
+    let mut received = 0;
+    loop {
+        let chunk = match resp.chunk().await {
+            Ok(Some(bytes)) => {
+                received += bytes.len() as u64;
+                if let Some(progress) = progress {
+                    progress.send_replace(received);
+                }
+                Ok(bytes)
+            }
+            Ok(None) => break,
+            Err(e) => Err(e),
+        };
+        let failed = chunk.is_err();
+        // A closed channel means the parser already failed; its error says why.
+        if tx.send(chunk).await.is_err() || failed {
+            break;
+        }
+    }
+    drop(tx);
+
+    parse
+        .await
+        .context("sync parser stopped")?
+        .context("failed to parse sync response")
+}
+
+/// Blocking reads over body chunks arriving on a channel; a download error
+/// surfaces as an I/O error.
+struct ChunkReader<B> {
+    rx: mpsc::Receiver<reqwest::Result<B>>,
+    chunk: B,
+    pos: usize,
+}
+
+impl<B: Default> ChunkReader<B> {
+    fn new(rx: mpsc::Receiver<reqwest::Result<B>>) -> Self {
+        Self {
+            rx,
+            chunk: B::default(),
+            pos: 0,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Read for ChunkReader<B> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.pos == self.chunk.as_ref().len() {
+            match self.rx.blocking_recv() {
+                Some(Ok(chunk)) => {
+                    self.chunk = chunk;
+                    self.pos = 0;
+                }
+                Some(Err(e)) => return Err(std::io::Error::other(e)),
+                None => return Ok(0),
+            }
+        }
+        let rest = &self.chunk.as_ref()[self.pos..];
+        let n = rest.len().min(buf.len());
+        buf[..n].copy_from_slice(&rest[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_sync_body_split_across_chunks() {
+        let body = br#"{"sync_token":"abc","full_sync":true,"items":[]}"#;
+        let (tx, rx) = mpsc::channel(SYNC_BODY_CHUNKS);
+        for piece in body.chunks(7) {
+            tx.try_send(Ok(piece.to_vec())).unwrap();
+        }
+        drop(tx);
+        let resp: SyncResponse = serde_json::from_reader(ChunkReader::new(rx)).unwrap();
+        assert_eq!(resp.sync_token, "abc");
+        assert!(resp.full_sync);
+        assert_eq!(resp.items.map(|i| i.len()), Some(0));
+    }
+}
@@ -0,0 +1,777 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+
+use super::models::{
+    ActivityEvent, Collaborator, Comment, DayCompletions, Due, Filter, Goals, Label,
+    LiveNotification, ProductivityStats, Project, Section, Streak, Task, UserInfo, WeekCompletions,
+};
+use super::sync::{CollaboratorState, SyncCommand, SyncCommandResult, SyncRequest, SyncResponse};
+
+const USER_ID: &str = "demo-user";
+
+/// In-memory stand-in for the Todoist backend behind `--demo`. Seeded with a
+/// believable account relative to today; commands mutate the store so a full
+/// resync shows them, but nothing outlives the process.
+pub(crate) struct DemoBackend {
+    projects: Vec<Project>,
+    sections: Vec<Section>,
+    labels: Vec<Label>,
+    filters: Vec<Filter>,
+    tasks: Vec<Task>,
+    comments: Vec<Comment>,
+    notifications: Vec<LiveNotification>,
+    next_id: u64,
+}
+
+impl DemoBackend {
+    pub(crate) fn new() -> Self {
+        let today = Local::now().date_naive();
+        Self {
+            projects: projects(),
+            sections: vec![
+                section("s-plan", "p-work", "Planning", 1),
+                section("s-doing", "p-work", "In progress", 2),
+                section("s-weekend", "p-home", "Weekend", 1),
+            ],
+            labels: ["deep-work", "errand", "waiting", "quick"]
+                .iter()
+                .enumerate()
+                .map(|(i, name)| Label {
+                    id: format!("l-{name}"),
+                    name: name.to_string(),
+                    color: ["blue", "orange", "grey", "green"][i].to_string(),
+                    item_order: Some(i as i32),
+                    is_favorite: *name == "deep-work",
+                    ..Label::default()
+                })
+                .collect(),
+            filters: vec![Filter {
+                id: "f-quick-wins".to_string(),
+                name: "Quick wins".to_string(),
+                query: "@quick | (today & p1)".to_string(),
+                color: "green".to_string(),
+                item_order: Some(0),
+                is_favorite: true,
+                is_deleted: false,
+            }],
+            tasks: tasks(today),
+            comments: comments(),
+            notifications: notifications(),
+            next_id: 1,
+        }
+    }
+
+    pub(crate) fn user(&self) -> UserInfo {
+        UserInfo {
+            id: USER_ID.to_string(),
+            full_name: Some("Demo User".to_string()),
+            email: Some("demo@example.com".to_string()),
+            websocket_url: None,
+            start_page: Some("today".to_string()),
+            daily_goal: Some(5),
+            weekly_goal: Some(25),
+            tz_info: None,
+        }
+    }
+
+    pub(crate) fn sync(&mut self, req: &SyncRequest) -> Result<SyncResponse> {
+        let mut sync_status = HashMap::new();
+        let mut temp_id_mapping = HashMap::new();
+        for cmd in &req.commands {
+            if let Some(temp_id) = &cmd.temp_id {
+                let id = self.fresh_id();
+                temp_id_mapping.insert(temp_id.clone(), id);
+            }
+            let real_id = cmd.temp_id.as_ref().and_then(|t| temp_id_mapping.get(t));
+            self.apply(cmd, real_id.cloned());
+            sync_status.insert(cmd.uuid.clone(), SyncCommandResult::Ok("ok".to_string()));
+        }
+
+        let full = req.sync_token == "*";
+        let pick =
+            |wanted: &str| full && req.resource_types.iter().any(|r| r == wanted || r == "all");
+        Ok(SyncResponse {
+            full_sync: full,
+            sync_token: "demo".to_string(),
+            items: pick("items")
+                .then(|| self.tasks.iter().filter(|t| !t.checked).cloned().collect()),
+            projects: pick("projects").then(|| self.projects.clone()),
+            sections: pick("sections").then(|| self.sections.clone()),
+            labels: pick("labels").then(|| self.labels.clone()),
+            filters: pick("filters").then(|| self.filters.clone()),
+            notes: pick("notes").then(|| self.comments.clone()),
+            collaborators: pick("collaborators").then(collaborators),
+            workspaces: None,
+            folders: None,
+            collaborator_states: pick("collaborators").then(collaborator_states),
+            live_notifications: pick("live_notifications").then(|| self.notifications.clone()),
+            user: pick("user").then(|| self.user()),
+            sync_status,
+            temp_id_mapping,
+        })
+    }
+
+    pub(crate) fn comments(&self, task_id: &str) -> Vec<Comment> {
+        self.comments
+            .iter()
+            .filter(|c| c.item_id.as_deref() == Some(task_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Creation and completion from the store, plus a reschedule on the
+    /// planning doc so the history shows one.
+    pub(crate) fn activity(&self, task_id: &str) -> Vec<ActivityEvent> {
+        let Some(task) = self.tasks.iter().find(|t| t.id == task_id) else {
+            return Vec::new();
+        };
+        let event = |event_type: &str, at: &str, extra_data: serde_json::Value| ActivityEvent {
+            event_type: event_type.to_string(),
+            event_date: at.to_string(),
+            initiator_id: Some(USER_ID.to_string()),
+            extra_data,
+        };
+        let mut events = Vec::new();
+        if let Some(at) = &task.completed_at {
+            events.push(event("completed", at, serde_json::Value::Null));
+        }
+        if task.id == "t-work-1" {
+            events.push(event(
+                "updated",
+                "2026-01-06T16:02:00Z",
+                serde_json::json!({
+                    "last_due_date": "2026-01-09",
+                    "due_date": task.due.as_ref().map(|d| d.date.clone()),
+                }),
+            ));
+        }
+        if let Some(at) = &task.added_at {
+            events.push(event("added", at, serde_json::Value::Null));
+        }
+        events
+    }
+
+    pub(crate) fn completed_tasks(&self, project_id: Option<&str>) -> Vec<Task> {
+        self.tasks
+            .iter()
+            .filter(|t| t.checked)
+            .filter(|t| project_id.is_none_or(|pid| t.project_id == pid))
+            .cloned()
+            .collect()
+    }
+
+    /// Goals and a streak, with completions counted from the store so
+    /// tasks closed during the session show up after a refetch.
+    pub(crate) fn productivity_stats(&self) -> ProductivityStats {
+        let today = Local::now().date_naive();
+        let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        let completed_on = |date: NaiveDate| {
+            self.tasks
+                .iter()
+                .filter(|t| {
+                    t.completed_at
+                        .as_deref()
+                        .is_some_and(|at| at.starts_with(&date.to_string()))
+                })
+                .count() as u32
+        };
+        let week_total = (0..=(today - week_start).num_days())
+            .map(|d| completed_on(week_start + Duration::days(d)))
+            .sum();
+        ProductivityStats {
+            days_items: vec![DayCompletions {
+                date: today.to_string(),
+                total_completed: completed_on(today),
+            }],
+            week_items: vec![WeekCompletions {
+                from: week_start.to_string(),
+                to: (week_start + Duration::days(6)).to_string(),
+                total_completed: week_total,
+            }],
+            goals: Goals {
+                daily_goal: Some(5),
+                weekly_goal: Some(25),
+                current_daily_streak: Some(Streak { count: 12 }),
+            },
+        }
+    }
+
+    /// A rough stand-in for the server's parser: `#project`, `@label` and
+    /// `p1`–`p4` are picked out, everything else is the title.
+    pub(crate) fn quick_add(&mut self, text: &str) -> Task {
+        let mut task = Task {
+            id: self.fresh_id(),
+            project_id: "p-inbox".to_string(),
+            priority: 1,
+            added_at: Some(Local::now().to_rfc3339()),
+            ..Task::default()
+        };
+        let mut words = Vec::new();
+        for word in text.split_whitespace() {
+            if let Some(name) = word.strip_prefix('#')
+                && let Some(project) = self
+                    .projects
+                    .iter()
+                    .find(|p| p.name.eq_ignore_ascii_case(name))
+            {
+                task.project_id = project.id.clone();
+            } else if let Some(label) = word.strip_prefix('@') {
+                task.labels.push(label.to_string());
+            } else if let Some(p @ 1..=4) =
+                word.strip_prefix('p').and_then(|n| n.parse::<u8>().ok())
+            {
+                task.priority = 5 - p;
+            } else {
+                words.push(word);
+            }
+        }
+        task.content = words.join(" ");
+        self.tasks.push(task.clone());
+        task
+    }
+
+    fn fresh_id(&mut self) -> String {
+        self.next_id += 1;
+        format!("demo-{}", self.next_id)
+    }
+
+    /// Applies the commands the TUI sends; anything else is acknowledged
+    /// without effect.
+    fn apply(&mut self, cmd: &SyncCommand, new_id: Option<String>) {
+        let args = &cmd.args;
+        let id = args["id"].as_str().unwrap_or_default();
+        match cmd.r#type.as_str() {
+            "item_add" => {
+                let mut fields = args.clone();
+                fields["id"] = new_id.unwrap_or_else(|| self.fresh_id()).into();
+                let Ok(mut task) = serde_json::from_value::<Task>(fields) else {
+                    return;
+                };
+                task.priority = task.priority.max(1);
+                task.added_at = Some(Local::now().to_rfc3339());
+                if let Some(s) = args["due_string"].as_str() {
+                    task.due = Some(Due {
+                        date: Local::now().date_naive().to_string(),
+                        string: Some(s.to_string()),
+                        ..Due::default()
+                    });
+                }
+                self.tasks.push(task);
+            }
+            "item_update" => {
+                if let Some(task) = self.tasks.iter_mut().find(|t| t.id == id)
+                    && let Ok(mut value) = serde_json::to_value(&*task)
+                    && let Some(fields) = args.as_object()
+                {
+                    for (k, v) in fields {
+                        value[k] = v.clone();
+                    }
+                    if let Ok(updated) = serde_json::from_value(value) {
+                        *task = updated;
+                    }
+                }
+            }
+            "project_reorder" => {
+                for entry in args["projects"].as_array().into_iter().flatten() {
+                    if let Some(project) = self
+                        .projects
+                        .iter_mut()
+                        .find(|p| entry["id"].as_str() == Some(p.id.as_str()))
+                        && let Some(order) = entry["child_order"].as_i64()
+                    {
+                        project.child_order = order as i32;
+                    }
+                }
+            }
+            "label_update_orders" | "filter_update_orders" => {
+                for (id, order) in args["id_order_mapping"].as_object().into_iter().flatten() {
+                    let order = order.as_i64().map(|o| o as i32);
+                    if let Some(label) = self.labels.iter_mut().find(|l| &l.id == id) {
+                        label.item_order = order;
+                    } else if let Some(filter) = self.filters.iter_mut().find(|f| &f.id == id) {
+                        filter.item_order = order;
+                    }
+                }
+            }
+            "shared_label_rename" | "shared_label_remove" => {
+                let old = args["name_old"]
+                    .as_str()
+                    .or(args["name"].as_str())
+                    .unwrap_or_default();
+                let new = args["name_new"].as_str();
+                for task in &mut self.tasks {
+                    if task.labels.iter().any(|l| l == old) {
+                        task.labels.retain(|l| l != old);
+                        if let Some(new) = new
+                            && !task.labels.iter().any(|l| l == new)
+                        {
+                            task.labels.push(new.to_string());
+                        }
+                    }
+                }
+            }
+            "section_reorder" => {
+                for entry in args["sections"].as_array().into_iter().flatten() {
+                    if let Some(section) = self
+                        .sections
+                        .iter_mut()
+                        .find(|s| entry["id"].as_str() == Some(s.id.as_str()))
+                    {
+                        section.section_order = entry["section_order"].as_i64().map(|o| o as i32);
+                    }
+                }
+            }
+            "item_move" => {
+                if let Some(task) = self.tasks.iter_mut().find(|t| t.id == id) {
+                    if let Some(parent) = args["parent_id"].as_str() {
+                        task.parent_id = Some(parent.to_string());
+                    } else if let Some(section) = args["section_id"].as_str() {
+                        task.parent_id = None;
+                        task.section_id = Some(section.to_string());
+                    } else if let Some(project) = args["project_id"].as_str() {
+                        task.parent_id = None;
+                        task.section_id = None;
+                        task.project_id = project.to_string();
+                    }
+                }
+                // Subtasks follow their parent into its new home.
+                let Some(home) = self
+                    .tasks
+                    .iter()
+                    .find(|t| t.id == id)
+                    .map(|t| (t.project_id.clone(), t.section_id.clone()))
+                else {
+                    return;
+                };
+                let mut moved = vec![id.to_string()];
+                let mut i = 0;
+                while let Some(parent) = moved.get(i).cloned() {
+                    for task in &mut self.tasks {
+                        if task.parent_id.as_deref() == Some(parent.as_str()) {
+                            task.project_id = home.0.clone();
+                            task.section_id = home.1.clone();
+                            moved.push(task.id.clone());
+                        }
+                    }
+                    i += 1;
+                }
+            }
+            "item_close" | "item_complete" => {
+                if let Some(task) = self.tasks.iter_mut().find(|t| t.id == id) {
+                    task.checked = true;
+                    task.completed_at = Some(Local::now().to_rfc3339());
+                }
+            }
+            "item_reopen" | "item_uncomplete" => {
+                if let Some(task) = self.tasks.iter_mut().find(|t| t.id == id) {
+                    task.checked = false;
+                    task.completed_at = None;
+                }
+            }
+            "item_delete" => self.tasks.retain(|t| t.id != id),
+            "note_add" => {
+                let item_id = args["item_id"].as_str().map(str::to_string);
+                if let Some(task) = self
+                    .tasks
+                    .iter_mut()
+                    .find(|t| Some(&t.id) == item_id.as_ref())
+                {
+                    task.note_count = Some(task.note_count.unwrap_or(0) + 1);
+                }
+                let id = new_id.unwrap_or_else(|| self.fresh_id());
+                self.comments.push(Comment {
+                    id,
+                    content: args["content"].as_str().unwrap_or_default().to_string(),
+                    posted_at: Some(Local::now().to_rfc3339()),
+                    posted_by_uid: Some(USER_ID.to_string()),
+                    item_id,
+                    ..Comment::default()
+                });
+            }
+            "live_notifications_mark_read" => {
+                let ids: Vec<&str> = args["ids"]
+                    .as_array()
+                    .map(|ids| ids.iter().filter_map(|v| v.as_str()).collect())
+                    .unwrap_or_default();
+                for n in &mut self.notifications {
+                    if ids.contains(&n.id.as_str()) {
+                        n.is_unread = false;
+                    }
+                }
+            }
+            "live_notifications_mark_read_all" => {
+                for n in &mut self.notifications {
+                    n.is_unread = false;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn projects() -> Vec<Project> {
+    let project = |id: &str, name: &str, color: &str, order: i32| Project {
+        id: id.to_string(),
+        name: name.to_string(),
+        color: color.to_string(),
+        child_order: order,
+        view_style: Some("list".to_string()),
+        ..Project::default()
+    };
+    vec![
+        Project {
+            inbox_project: Some(true),
+            ..project("p-inbox", "Inbox", "grey", 0)
+        },
+        Project {
+            is_favorite: true,
+            is_shared: true,
+            creator_uid: Some(USER_ID.to_string()),
+            ..project("p-work", "Work", "blue", 1)
+        },
+        Project {
+            parent_id: Some("p-work".to_string()),
+            ..project("p-launch", "Launch v2", "violet", 0)
+        },
+        project("p-home", "Home", "green", 2),
+        project("p-reading", "Reading list", "orange", 3),
+        Project {
+            is_favorite: true,
+            ..project("p-fitness", "Fitness", "red", 4)
+        },
+    ]
+}
+
+fn section(id: &str, project_id: &str, name: &str, order: i32) -> Section {
+    Section {
+        id: id.to_string(),
+        project_id: project_id.to_string(),
+        name: name.to_string(),
+        section_order: Some(order),
+        ..Section::default()
+    }
+}
+
+fn due(today: NaiveDate, days: i64, string: &str, recurring: bool) -> Option<Due> {
+    Some(Due {
+        date: (today + Duration::days(days)).to_string(),
+        is_recurring: recurring,
+        string: Some(string.to_string()),
+        ..Due::default()
+    })
+}
+
+fn tasks(today: NaiveDate) -> Vec<Task> {
+    let task = |id: &str, project: &str, content: &str, priority: u8, order: i32| Task {
+        id: id.to_string(),
+        project_id: project.to_string(),
+        content: content.to_string(),
+        priority,
+        child_order: order,
+        added_by_uid: Some(USER_ID.to_string()),
+        added_at: Some((today - Duration::days(7)).to_string()),
+        ..Task::default()
+    };
+    let labels = |names: &[&str]| names.iter().map(|n| n.to_string()).collect();
+    let done = |mut t: Task, days_ago: i64| {
+        t.checked = true;
+        t.completed_at = Some(format!("{}T17:30:00Z", today - Duration::days(days_ago)));
+        t
+    };
+
+    vec![
+        Task {
+            due: due(today, 0, "today", false),
+            labels: labels(&["quick"]),
+            ..task(
+                "t-inbox-1",
+                "p-inbox",
+                "Reply to Sam about Friday dinner",
+                2,
+                1,
+            )
+        },
+        Task {
+            labels: labels(&["errand"]),
+            ..task("t-inbox-2", "p-inbox", "Pick up dry cleaning", 1, 2)
+        },
+        task("t-inbox-3", "p-inbox", "Look into a standing desk", 1, 3),
+        Task {
+            due: due(today, -2, "Monday", false),
+            section_id: Some("s-plan".to_string()),
+            labels: labels(&["deep-work"]),
+            description: "Cover hiring, budget and the Q3 roadmap.".to_string(),
+            note_count: Some(2),
+            ..task("t-work-1", "p-work", "Draft quarterly planning doc", 4, 1)
+        },
+        Task {
+            parent_id: Some("t-work-1".to_string()),
+            section_id: Some("s-plan".to_string()),
+            labels: labels(&["q3"]),
+            ..task("t-work-1a", "p-work", "Collect numbers from finance", 3, 1)
+        },
+        Task {
+            parent_id: Some("t-work-1".to_string()),
+            section_id: Some("s-plan".to_string()),
+            labels: labels(&["waiting", "q3"]),
+            ..task("t-work-1b", "p-work", "Get headcount plan from Priya", 2, 2)
+        },
+        Task {
+            due: due(today, 0, "every weekday", true),
+            section_id: Some("s-doing".to_string()),
+            labels: labels(&["quick"]),
+            ..task("t-work-2", "p-work", "Review open pull requests", 3, 1)
+        },
+        Task {
+            due: due(today, 1, "tomorrow", false),
+            section_id: Some("s-doing".to_string()),
+            labels: labels(&["deep-work"]),
+            note_count: Some(1),
+            ..task(
+                "t-work-3",
+                "p-work",
+                "Fix flaky integration test in CI",
+                3,
+                2,
+            )
+        },
+        Task {
+            due: due(today, 4, "in 4 days", false),
+            ..task("t-work-4", "p-work", "1:1 notes for Alex", 2, 3)
+        },
+        Task {
+            due: due(today, 3, "in 3 days", false),
+            description: "Landing page, changelog and announcement post.".to_string(),
+            ..task("t-launch-1", "p-launch", "Prepare launch checklist", 4, 1)
+        },
+        Task {
+            due: due(today, 6, "next week", false),
+            labels: labels(&["waiting"]),
+            ..task(
+                "t-launch-2",
+                "p-launch",
+                "Final copy review with marketing",
+                3,
+                2,
+            )
+        },
+        task("t-launch-3", "p-launch", "Record demo video", 2, 3),
+        Task {
+            due: due(today, -1, "yesterday", false),
+            labels: labels(&["errand"]),
+            ..task("t-home-1", "p-home", "Pay electricity bill", 4, 1)
+        },
+        Task {
+            due: due(today, 0, "every week", true),
+            ..task("t-home-2", "p-home", "Water the plants", 1, 2)
+        },
+        Task {
+            section_id: Some("s-weekend".to_string()),
+            due: due(today, 5, "saturday", false),
+            ..task("t-home-3", "p-home", "Fix the squeaky hallway door", 2, 1)
+        },
+        Task {
+            section_id: Some("s-weekend".to_string()),
+            labels: labels(&["errand"]),
+            ..task("t-home-4", "p-home", "Buy new shower curtain", 1, 2)
+        },
+        Task {
+            description: "Started chapter 4.".to_string(),
+            ..task("t-read-1", "p-reading", "The Pragmatic Programmer", 2, 1)
+        },
+        task(
+            "t-read-2",
+            "p-reading",
+            "Designing Data-Intensive Applications",
+            1,
+            2,
+        ),
+        task(
+            "t-read-3",
+            "p-reading",
+            "A Philosophy of Software Design",
+            1,
+            3,
+        ),
+        Task {
+            due: due(today, 0, "every other day", true),
+            note_count: Some(1),
+            ..task("t-fit-1", "p-fitness", "30 minute run", 3, 1)
+        },
+        Task {
+            due: due(today, 2, "in 2 days", false),
+            ..task("t-fit-2", "p-fitness", "Book physio appointment", 2, 2)
+        },
+        done(task("t-done-1", "p-work", "Ship onboarding email", 3, 9), 1),
+        done(task("t-done-2", "p-work", "Update team wiki", 1, 10), 2),
+        done(task("t-done-3", "p-home", "Renew car insurance", 4, 9), 3),
+        done(task("t-done-4", "p-fitness", "Buy running shoes", 2, 9), 5),
+        done(
+            task("t-done-5", "p-inbox", "Cancel unused subscription", 1, 9),
+            0,
+        ),
+    ]
+}
+
+fn comments() -> Vec<Comment> {
+    let comment = |id: &str, task: &str, content: &str, at: &str| Comment {
+        id: id.to_string(),
+        content: content.to_string(),
+        posted_at: Some(at.to_string()),
+        posted_by_uid: Some(USER_ID.to_string()),
+        item_id: Some(task.to_string()),
+        ..Comment::default()
+    };
+    vec![
+        comment(
+            "c-1",
+            "t-work-1",
+            "Outline agreed in Tuesday's sync.",
+            "2026-01-05T09:12:00Z",
+        ),
+        comment(
+            "c-2",
+            "t-work-1",
+            "Finance numbers due end of week.",
+            "2026-01-06T15:40:00Z",
+        ),
+        comment(
+            "c-3",
+            "t-work-3",
+            "Fails roughly 1 in 20 runs, only on Linux.",
+            "2026-01-06T11:03:00Z",
+        ),
+        comment(
+            "c-4",
+            "t-fit-1",
+            "Focus session: 30 min",
+            "2026-01-04T07:30:00Z",
+        ),
+    ]
+}
+
+fn collaborator(id: &str, name: &str, email: &str) -> Collaborator {
+    Collaborator {
+        id: id.to_string(),
+        name: Some(name.to_string()),
+        email: Some(email.to_string()),
+    }
+}
+
+fn collaborators() -> Vec<Collaborator> {
+    vec![
+        collaborator("demo-priya", "Priya Shah", "priya@example.com"),
+        collaborator("demo-sam", "Sam Okafor", "sam@example.com"),
+    ]
+}
+
+/// The Work project is shared with Priya; Sam hasn't accepted yet.
+fn collaborator_states() -> Vec<CollaboratorState> {
+    [
+        (USER_ID, "active"),
+        ("demo-priya", "active"),
+        ("demo-sam", "invited"),
+    ]
+    .into_iter()
+    .map(|(user_id, state)| CollaboratorState {
+        project_id: "p-work".to_string(),
+        user_id: user_id.to_string(),
+        state: state.to_string(),
+        role: None,
+        is_deleted: false,
+    })
+    .collect()
+}
+
+fn notifications() -> Vec<LiveNotification> {
+    let priya = Some(collaborator(
+        "demo-priya",
+        "Priya Shah",
+        "priya@example.com",
+    ));
+    vec![
+        LiveNotification {
+            id: "n-1".to_string(),
+            notification_type: "item_assigned".to_string(),
+            created_at: Some("2026-01-06T16:02:00Z".to_string()),
+            is_unread: true,
+            from_user: priya.clone(),
+            project_id: Some("p-work".to_string()),
+            item_id: Some("t-work-2".to_string()),
+            item_content: Some("Review open pull requests".to_string()),
+            ..LiveNotification::default()
+        },
+        LiveNotification {
+            id: "n-2".to_string(),
+            notification_type: "note_added".to_string(),
+            created_at: Some("2026-01-06T15:41:00Z".to_string()),
+            is_unread: true,
+            from_user: priya.clone(),
+            project_id: Some("p-work".to_string()),
+            item_id: Some("t-work-1".to_string()),
+            item_content: Some("Draft quarterly planning doc".to_string()),
+            note_content: Some("Finance numbers due end of week.".to_string()),
+            ..LiveNotification::default()
+        },
+        LiveNotification {
+            id: "n-3".to_string(),
+            notification_type: "share_invitation_sent".to_string(),
+            created_at: Some("2026-01-02T10:15:00Z".to_string()),
+            from_user: priya,
+            project_name: Some("Offsite planning".to_string()),
+            ..LiveNotification::default()
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_sync_then_add_round_trips() {
+        let mut demo = DemoBackend::new();
+        let full = demo
+            .sync(&SyncRequest {
+                sync_token: "*".to_string(),
+                resource_types: vec!["all".to_string()],
+                commands: vec![],
+            })
+            .unwrap();
+        let open = full.items.unwrap().len();
+        assert!(open > 10);
+
+        let resp = demo
+            .sync(&SyncRequest {
+                sync_token: "demo".to_string(),
+                resource_types: vec![],
+                commands: vec![SyncCommand {
+                    r#type: "item_add".to_string(),
+                    temp_id: Some("tmp".to_string()),
+                    uuid: "u1".to_string(),
+                    args: serde_json::json!({ "content": "New", "project_id": "p-inbox" }),
+                }],
+            })
+            .unwrap();
+        assert!(!resp.sync_status["u1"].is_err());
+        let real = &resp.temp_id_mapping["tmp"];
+        assert!(
+            demo.tasks
+                .iter()
+                .any(|t| &t.id == real && t.content == "New")
+        );
+    }
+
+    #[test]
+    fn quick_add_picks_out_project_label_and_priority() {
+        let mut demo = DemoBackend::new();
+        let task = demo.quick_add("Buy milk #home @errand p1");
+        assert_eq!(task.content, "Buy milk");
+        assert_eq!(task.project_id, "p-home");
+        assert_eq!(task.labels, vec!["errand"]);
+        assert_eq!(task.priority, 4);
+        assert!(demo.tasks.iter().any(|t| t.id == task.id));
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+
+use reqwest::StatusCode;
+use reqwest::header::RETRY_AFTER;
+
+/// A failed Todoist call. The client returns these inside `anyhow::Error`;
+/// `downcast_ref::<TodoistError>()` gets them back, through any context
+/// added on the way.
+#[derive(Debug)]
+pub enum TodoistError {
+    /// 401: the token is missing, revoked or mistyped.
+    Unauthorized { message: String },
+    /// 403: the token is fine but may not touch this object.
+    Forbidden { message: String },
+    /// 404: usually deleted on another device.
+    NotFound { message: String },
+    /// 429. `retry_after` is in seconds, from the header or the body.
+    RateLimited { retry_after: Option<u64> },
+    /// A 4xx the API explained, e.g. `INVALID_DATE_FORMAT`.
+    Validation { tag: String, message: String },
+    /// Any other error status; `body` is kept as received.
+    Http { status: StatusCode, body: String },
+    /// No response at all: DNS, TLS, timeouts, a dropped connection.
+    Network(reqwest::Error),
+}
+
+impl TodoistError {
+    /// Reads the error out of a non-success response.
+    pub async fn from_response(resp: reqwest::Response) -> Self {
+        let status = resp.status();
+        let retry_after = resp
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u64>().ok());
+        let body = resp.text().await.unwrap_or_default();
+        Self::from_parts(status, retry_after, body)
+    }
+
+    /// Classifies by status, then by the `error_tag` Todoist puts in JSON
+    /// error bodies.
+    pub fn from_parts(status: StatusCode, retry_after: Option<u64>, body: String) -> Self {
+        let parsed = serde_json::from_str::<serde_json::Value>(&body).ok();
+        let field = |key: &str| {
+            parsed
+                .as_ref()
+                .and_then(|v| v[key].as_str())
+                .map(str::to_string)
+        };
+        let message = field("error").unwrap_or_else(|| body.clone());
+
+        match status {
+            StatusCode::UNAUTHORIZED => Self::Unauthorized { message },
+            StatusCode::FORBIDDEN => Self::Forbidden { message },
+            StatusCode::NOT_FOUND => Self::NotFound { message },
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited {
+                retry_after: retry_after.or_else(|| {
+                    parsed
+                        .as_ref()
+                        .and_then(|v| v["error_extra"]["retry_after"].as_u64())
+                }),
+            },
+            s if s.is_client_error() => match field("error_tag") {
+                Some(tag) => Self::Validation { tag, message },
+                None => Self::Http { status, body },
+            },
+            _ => Self::Http { status, body },
+        }
+    }
+}
+
+impl fmt::Display for TodoistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unauthorized { message } => write!(f, "unauthorized (401): {message}"),
+            Self::Forbidden { message } => write!(f, "forbidden (403): {message}"),
+            Self::NotFound { message } => write!(f, "not found (404): {message}"),
+            Self::RateLimited {
+                retry_after: Some(s),
+            } => write!(f, "rate limited (retry after {s}s)"),
+            Self::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            Self::Validation { tag, message } => write!(f, "rejected ({tag}): {message}"),
+            Self::Http { status, body } => write!(f, "Todoist API error ({status}): {body}"),
+            Self::Network(_) => write!(f, "failed to reach Todoist API"),
+        }
+    }
+}
+
+impl std::error::Error for TodoistError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Network(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_by_status_then_tag() {
+        let body = r#"{"error":"Invalid date","error_tag":"INVALID_DATE_FORMAT"}"#;
+        match TodoistError::from_parts(StatusCode::BAD_REQUEST, None, body.to_string()) {
+            TodoistError::Validation { tag, message } => {
+                assert_eq!(tag, "INVALID_DATE_FORMAT");
+                assert_eq!(message, "Invalid date");
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+
+        let gone = r#"{"error":"Task not found","error_tag":"NOT_FOUND"}"#;
+        assert!(matches!(
+            TodoistError::from_parts(StatusCode::NOT_FOUND, None, gone.to_string()),
+            TodoistError::NotFound { message } if message == "Task not found"
+        ));
+
+        assert!(matches!(
+            TodoistError::from_parts(StatusCode::BAD_REQUEST, None, "plain".to_string()),
+            TodoistError::Http { body, .. } if body == "plain"
+        ));
+    }
+
+    #[test]
+    fn retry_after_prefers_the_header_over_the_body() {
+        let body = r#"{"error_extra":{"retry_after":30}}"#.to_string();
+        let limited = |header| match TodoistError::from_parts(
+            StatusCode::TOO_MANY_REQUESTS,
+            header,
+            body.clone(),
+        ) {
+            TodoistError::RateLimited { retry_after } => retry_after,
+            other => panic!("unexpected {other:?}"),
+        };
+        assert_eq!(limited(Some(5)), Some(5));
+        assert_eq!(limited(None), Some(30));
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+
+/// Below this share of the limit the budget counts as low.
+const LOW_BUDGET_PERCENT: u64 = 10;
+
+/// What the API has said about the client's request budget, updated from
+/// the rate-limit headers of every response. Shared with the UI through
+/// [`TodoistClient::metrics`](super::client::TodoistClient::metrics).
+#[derive(Debug, Default)]
+pub struct ApiMetrics {
+    /// False until a response carries the headers; the demo backend and
+    /// servers that don't send them never set it.
+    known: AtomicBool,
+    limit: AtomicU64,
+    remaining: AtomicU64,
+    /// Unix seconds when the window resets; 0 when not reported.
+    reset_at: AtomicU64,
+}
+
+/// A snapshot of [`ApiMetrics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateBudget {
+    pub limit: u64,
+    pub remaining: u64,
+    pub resets_in: Option<Duration>,
+}
+
+impl RateBudget {
+    /// Close enough to the limit that optional requests should wait.
+    pub fn is_low(&self) -> bool {
+        self.remaining.saturating_mul(100) <= self.limit.saturating_mul(LOW_BUDGET_PERCENT)
+    }
+}
+
+impl ApiMetrics {
+    /// Reads `X-RateLimit-Limit`, `-Remaining` and `-Reset`. The reset is
+    /// taken as seconds from now, or as a Unix time when it is that large.
+    pub fn record(&self, headers: &HeaderMap) {
+        let number = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|s| s.trim().parse::<u64>().ok())
+        };
+        let (Some(limit), Some(remaining)) =
+            (number("x-ratelimit-limit"), number("x-ratelimit-remaining"))
+        else {
+            return;
+        };
+        self.limit.store(limit, Ordering::Relaxed);
+        self.remaining.store(remaining, Ordering::Relaxed);
+        let reset_at = match number("x-ratelimit-reset") {
+            Some(t) if t > 1_000_000_000 => t,
+            Some(secs) => now_secs() + secs,
+            None => 0,
+        };
+        self.reset_at.store(reset_at, Ordering::Relaxed);
+        self.known.store(true, Ordering::Relaxed);
+    }
+
+    /// A 429 means the budget is spent whatever the last headers said.
+    pub fn record_exhausted(&self, retry_after: Option<u64>) {
+        self.remaining.store(0, Ordering::Relaxed);
+        if let Some(secs) = retry_after {
+            self.reset_at.store(now_secs() + secs, Ordering::Relaxed);
+        }
+        self.known.store(true, Ordering::Relaxed);
+    }
+
+    /// `None` until the server has reported a budget. Once the reset time
+    /// passes, the budget is assumed full again until the next response.
+    pub fn budget(&self) -> Option<RateBudget> {
+        if !self.known.load(Ordering::Relaxed) {
+            return None;
+        }
+        let limit = self.limit.load(Ordering::Relaxed);
+        let reset_at = self.reset_at.load(Ordering::Relaxed);
+        let now = now_secs();
+        if reset_at != 0 && reset_at <= now {
+            return Some(RateBudget {
+                limit,
+                remaining: limit,
+                resets_in: None,
+            });
+        }
+        Some(RateBudget {
+            limit,
+            remaining: self.remaining.load(Ordering::Relaxed),
+            resets_in: (reset_at != 0).then(|| Duration::from_secs(reset_at - now)),
+        })
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn budget_follows_the_headers() {
+        let metrics = ApiMetrics::default();
+        assert_eq!(metrics.budget(), None);
+
+        metrics.record(&headers(&[("x-ratelimit-remaining", "12")]));
+        assert_eq!(metrics.budget(), None, "remaining alone is not enough");
+
+        metrics.record(&headers(&[
+            ("x-ratelimit-limit", "450"),
+            ("x-ratelimit-remaining", "40"),
+            ("x-ratelimit-reset", "60"),
+        ]));
+        let budget = metrics.budget().unwrap();
+        assert_eq!((budget.limit, budget.remaining), (450, 40));
+        assert!(budget.is_low());
+        assert!(budget.resets_in.unwrap() <= Duration::from_secs(60));
+
+        metrics.record_exhausted(Some(30));
+        assert_eq!(metrics.budget().unwrap().remaining, 0);
+    }
+
+    #[test]
+    fn a_passed_reset_restores_the_budget() {
+        let metrics = ApiMetrics::default();
+        metrics.record(&headers(&[
+            ("x-ratelimit-limit", "450"),
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", &(now_secs() - 5).to_string()),
+        ]));
+        let budget = metrics.budget().unwrap();
+        assert_eq!(budget.remaining, 450);
+        assert!(!budget.is_low());
+    }
+}
@@ -1,3 +1,6 @@
 pub mod client;
+mod demo;
+pub mod error;
+pub mod metrics;
 pub mod models;
 pub mod sync;
@@ -93,6 +93,21 @@ pub struct Label {
     pub is_deleted: Option<bool>,
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Filter {
+    pub id: String,
+    pub name: String,
+    pub query: String,
+    #[serde(default)]
+    pub color: String,
+    pub item_order: Option<i32>,
+    #[serde(default)]
+    pub is_favorite: bool,
+    #[serde(default)]
+    pub is_deleted: bool,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct Section {
@@ -127,6 +142,47 @@ pub struct Comment {
     pub uids_to_notify: Option<Vec<String>>,
 }
 
+/// One entry of a task's activity log.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ActivityEvent {
+    /// `added`, `updated`, `completed`, `uncompleted`, `deleted` and so on.
+    #[serde(default)]
+    pub event_type: String,
+    #[serde(default)]
+    pub event_date: String,
+    pub initiator_id: Option<String>,
+    /// Event-specific fields, e.g. `last_due_date` and `due_date` on a
+    /// reschedule or `last_content` on a rename.
+    #[serde(default)]
+    pub extra_data: serde_json::Value,
+}
+
+impl ActivityEvent {
+    /// A short, human description such as "rescheduled 2026-01-05 → 2026-01-07".
+    pub fn describe(&self) -> String {
+        let extra = |key: &str| self.extra_data.get(key).and_then(|v| v.as_str());
+        let day = |date: &str| date.get(..10).unwrap_or(date).to_string();
+        match self.event_type.as_str() {
+            "added" => "created".to_string(),
+            "completed" => "completed".to_string(),
+            "uncompleted" => "reopened".to_string(),
+            "deleted" => "deleted".to_string(),
+            "updated" => {
+                if self.extra_data.get("last_due_date").is_some() {
+                    let from = extra("last_due_date").map_or("no date".to_string(), day);
+                    let to = extra("due_date").map_or("no date".to_string(), day);
+                    format!("rescheduled {from} → {to}")
+                } else if let Some(old) = extra("last_content") {
+                    format!("renamed from “{old}”")
+                } else {
+                    "edited".to_string()
+                }
+            }
+            other => other.replace('_', " "),
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct UserInfo {
@@ -134,6 +190,42 @@ pub struct UserInfo {
     pub full_name: Option<String>,
     pub email: Option<String>,
     pub websocket_url: Option<String>,
+    /// `today`, `inbox`, `project?id=…` and the like, as set in Todoist.
+    pub start_page: Option<String>,
+    pub daily_goal: Option<u32>,
+    pub weekly_goal: Option<u32>,
+    pub tz_info: Option<TzInfo>,
+}
+
+/// The account's timezone and its current UTC offset, DST included.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TzInfo {
+    #[serde(default)]
+    pub timezone: String,
+    /// `+02:00` style.
+    pub gmt_string: Option<String>,
+    #[serde(default)]
+    pub hours: i32,
+    #[serde(default)]
+    pub minutes: i32,
+}
+
+impl TzInfo {
+    /// Seconds east of UTC. Todoist signs `hours` but not `minutes`, so
+    /// `-03:30` arrives as `hours: -3, minutes: 30`.
+    pub fn offset_secs(&self) -> i32 {
+        let west = self.hours < 0
+            || self
+                .gmt_string
+                .as_deref()
+                .is_some_and(|g| g.starts_with('-'));
+        let minutes = if west {
+            -self.minutes.abs()
+        } else {
+            self.minutes.abs()
+        };
+        self.hours * 3600 + minutes * 60
+    }
 }
 
 #[allow(dead_code)]
@@ -145,6 +237,37 @@ pub struct Collaborator {
     pub email: Option<String>,
 }
 
+/// An entry in Todoist's notification center. Only the fields of the kinds
+/// ratatoist shows (see [`LiveNotification::is_personal`]) are kept.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LiveNotification {
+    pub id: String,
+    #[serde(default)]
+    pub notification_type: String,
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub is_unread: bool,
+    #[serde(default)]
+    pub is_deleted: bool,
+    pub from_user: Option<Collaborator>,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub item_id: Option<String>,
+    pub item_content: Option<String>,
+    pub note_content: Option<String>,
+}
+
+impl LiveNotification {
+    /// Shared-project invites, assignments and comments: the kinds addressed
+    /// to the user rather than account or billing notices.
+    pub fn is_personal(&self) -> bool {
+        matches!(
+            self.notification_type.as_str(),
+            "share_invitation_sent" | "item_assigned" | "note_added"
+        )
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct CompletedRecord {
     pub task_id: String,
@@ -162,10 +285,32 @@ pub struct CompletedRecord {
 pub struct Workspace {
     pub id: String,
     pub name: String,
+    /// e.g. `STARTER` or `BUSINESS`.
+    pub plan: Option<String>,
+    /// The user's own role: `ADMIN`, `MEMBER` or `GUEST`.
+    pub role: Option<String>,
+    pub current_member_count: Option<u32>,
+    #[serde(default)]
+    pub is_deleted: bool,
+}
+
+/// A member of a workspace, from the workspace users endpoint.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WorkspaceUser {
+    pub user_id: String,
+    pub full_name: Option<String>,
+    pub user_email: Option<String>,
+    pub role: Option<String>,
     #[serde(default)]
     pub is_deleted: bool,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct WorkspaceUsersPage {
+    pub workspace_users: Vec<WorkspaceUser>,
+    pub next_cursor: Option<String>,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct Folder {
@@ -177,6 +322,46 @@ pub struct Folder {
     pub is_deleted: bool,
 }
 
+/// Karma stats from `tasks/completed/stats`: recent completions per day and
+/// per week, newest first, plus the user's goals.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProductivityStats {
+    #[serde(default)]
+    pub days_items: Vec<DayCompletions>,
+    #[serde(default)]
+    pub week_items: Vec<WeekCompletions>,
+    #[serde(default)]
+    pub goals: Goals,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DayCompletions {
+    pub date: String,
+    #[serde(default)]
+    pub total_completed: u32,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WeekCompletions {
+    pub from: String,
+    pub to: String,
+    #[serde(default)]
+    pub total_completed: u32,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Goals {
+    pub daily_goal: Option<u32>,
+    pub weekly_goal: Option<u32>,
+    pub current_daily_streak: Option<Streak>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Streak {
+    #[serde(default)]
+    pub count: u32,
+}
+
 // Priority metadata shared across all display sites.
 pub const PRIORITY_LABELS: &[(u8, &str)] = &[
     (4, "P1  Urgent"),
@@ -225,6 +410,11 @@ pub struct ItemAddArgs {
     pub parent_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub section_id: Option<String>,
+    /// `{"date": "YYYY-MM-DD"}`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub responsible_uid: Option<String>,
 }
 
 #[cfg(test)]
@@ -252,4 +442,42 @@ mod tests {
             serde_json::from_str(r#"{"items":[],"next_cursor":"abc"}"#).unwrap();
         assert_eq!(with_cursor.next_cursor.as_deref(), Some("abc"));
     }
+
+    #[test]
+    fn activity_events_describe_reschedules_and_renames() {
+        let event = |json: &str| serde_json::from_str::<ActivityEvent>(json).unwrap();
+        assert_eq!(
+            event(r#"{"event_type":"updated","extra_data":{"last_due_date":"2026-01-05T00:00:00Z","due_date":"2026-01-07"}}"#)
+                .describe(),
+            "rescheduled 2026-01-05 → 2026-01-07"
+        );
+        assert_eq!(
+            event(r#"{"event_type":"updated","extra_data":{"last_due_date":null,"due_date":"2026-01-07"}}"#)
+                .describe(),
+            "rescheduled no date → 2026-01-07"
+        );
+        assert_eq!(
+            event(r#"{"event_type":"updated","extra_data":{"last_content":"Old"}}"#).describe(),
+            "renamed from “Old”"
+        );
+        assert_eq!(
+            event(r#"{"event_type":"uncompleted"}"#).describe(),
+            "reopened"
+        );
+    }
+
+    #[test]
+    fn tz_info_offset_follows_the_sign_of_the_hours() {
+        let tz: TzInfo = serde_json::from_str(
+            r#"{"timezone":"America/St_Johns","gmt_string":"-02:30","hours":-2,"minutes":30}"#,
+        )
+        .unwrap();
+        assert_eq!(tz.offset_secs(), -(2 * 3600 + 30 * 60));
+
+        let tz: TzInfo = serde_json::from_str(
+            r#"{"timezone":"Asia/Kolkata","gmt_string":"+05:30","hours":5,"minutes":30}"#,
+        )
+        .unwrap();
+        assert_eq!(tz.offset_secs(), 5 * 3600 + 30 * 60);
+    }
 }
@@ -26,11 +26,13 @@ pub struct SyncResponse {
     pub projects: Option<Vec<super::models::Project>>,
     pub sections: Option<Vec<super::models::Section>>,
     pub labels: Option<Vec<super::models::Label>>,
+    pub filters: Option<Vec<super::models::Filter>>,
     pub notes: Option<Vec<super::models::Comment>>,
     pub collaborators: Option<Vec<super::models::Collaborator>>,
     pub workspaces: Option<Vec<super::models::Workspace>>,
     pub folders: Option<Vec<super::models::Folder>>,
     pub collaborator_states: Option<Vec<CollaboratorState>>,
+    pub live_notifications: Option<Vec<super::models::LiveNotification>>,
     pub user: Option<super::models::UserInfo>,
     #[serde(default)]
     pub sync_status: HashMap<String, SyncCommandResult>,
@@ -68,7 +70,10 @@ pub struct SyncCommandError {
 pub struct CollaboratorState {
     pub project_id: String,
     pub user_id: String,
+    /// `"active"` or `"invited"`.
     pub state: String,
+    /// Workspace projects only: `CREATOR`, `ADMIN`, `READ_WRITE` or `READ_ONLY`.
+    pub role: Option<String>,
     #[serde(default)]
     pub is_deleted: bool,
 }
@@ -1,8 +1,11 @@
 use std::fmt;
-use std::path::PathBuf;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use anyhow::{Context, Result};
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
+use toml_edit::DocumentMut;
 use tracing::{info, warn};
 
 #[derive(Deserialize)]
@@ -10,15 +13,13 @@ struct ConfigFile {
     api_token: Option<String>,
 }
 
-#[derive(Serialize)]
-struct ConfigFileWrite {
-    api_token: String,
-}
-
 pub struct Config {
     api_token: String,
 }
 
+/// Directory set by `--config-dir`; wins over every other lookup.
+static CONFIG_DIR_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
 impl Config {
     pub fn load() -> Result<Self> {
         if let Ok(token) = std::env::var("TODOIST_API_TOKEN")
@@ -62,17 +63,63 @@ impl Config {
         let dir = Self::config_dir();
         std::fs::create_dir_all(&dir).context("failed to create config directory")?;
         let path = Self::config_path();
-        let content = toml::to_string(&ConfigFileWrite {
-            api_token: token.to_string(),
-        })
-        .context("failed to serialize config")?;
-        std::fs::write(&path, content).context("failed to write config file")?;
-        Self::set_secure_permissions(&path)?;
+        Self::edit_file(&path, |doc| doc["api_token"] = toml_edit::value(token))?;
         info!(path = %path.display(), "config saved");
         Ok(())
     }
 
+    /// Applies `edit` to the TOML file at `path`, keeping its comments and
+    /// layout and whatever else is in it (see `Settings`). The result goes
+    /// to a temporary file that is renamed over the original, so a crash
+    /// never leaves half a file. A file that doesn't parse is an error and
+    /// stays as it is: starting from scratch would drop the token.
+    pub(crate) fn edit_file(path: &Path, edit: impl FnOnce(&mut DocumentMut)) -> Result<()> {
+        let mut doc = match std::fs::read_to_string(path) {
+            Ok(src) => src.parse::<DocumentMut>().with_context(|| {
+                format!("{} is not valid TOML, not overwriting it", path.display())
+            })?,
+            Err(e) if e.kind() == ErrorKind::NotFound => DocumentMut::new(),
+            Err(e) => return Err(e).context("failed to read config file"),
+        };
+        edit(&mut doc);
+
+        let tmp = path.with_extension("toml.tmp");
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(&tmp).context("failed to create config file")?;
+        file.write_all(doc.to_string().as_bytes())
+            .and_then(|()| file.sync_all())
+            .context("failed to write config file")?;
+        Self::set_secure_permissions(&tmp)?;
+        std::fs::rename(&tmp, path).context("failed to replace config file")
+    }
+
+    /// Points config, settings, sync state, themes and logs at `dir` for the
+    /// rest of the process. Call once, before anything reads the config.
+    pub fn set_config_dir(dir: PathBuf) {
+        if CONFIG_DIR_OVERRIDE.set(dir).is_err() {
+            warn!("config dir already set, ignoring override");
+        }
+    }
+
+    /// The directory from `--config-dir` or `RATATOIST_CONFIG_DIR`, if any.
+    pub(crate) fn config_dir_override() -> Option<PathBuf> {
+        if let Some(dir) = CONFIG_DIR_OVERRIDE.get() {
+            return Some(dir.clone());
+        }
+        std::env::var("RATATOIST_CONFIG_DIR")
+            .ok()
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+    }
+
     pub fn config_dir() -> PathBuf {
+        if let Some(dir) = Self::config_dir_override() {
+            return dir;
+        }
+
         if let Ok(xdg) = std::env::var("XDG_CONFIG_HOME") {
             return PathBuf::from(xdg).join("ratatoist");
         }
@@ -98,7 +145,7 @@ impl Config {
     }
 
     #[cfg(unix)]
-    fn check_file_permissions(path: &std::path::Path) -> Result<()> {
+    fn check_file_permissions(path: &Path) -> Result<()> {
         use std::os::unix::fs::PermissionsExt;
         let metadata = std::fs::metadata(path).context("failed to read config file metadata")?;
         let mode = metadata.permissions().mode() & 0o777;
@@ -114,7 +161,7 @@ impl Config {
     }
 
     #[cfg(unix)]
-    fn set_secure_permissions(path: &std::path::Path) -> Result<()> {
+    pub(crate) fn set_secure_permissions(path: &Path) -> Result<()> {
         use std::os::unix::fs::PermissionsExt;
         let mut perms = std::fs::metadata(path)
             .context("failed to read config file metadata")?
@@ -125,12 +172,12 @@ impl Config {
     }
 
     #[cfg(not(unix))]
-    fn set_secure_permissions(_path: &std::path::Path) -> Result<()> {
+    pub(crate) fn set_secure_permissions(_path: &Path) -> Result<()> {
         Ok(())
     }
 
     #[cfg(not(unix))]
-    fn check_file_permissions(_path: &std::path::Path) -> Result<()> {
+    fn check_file_permissions(_path: &Path) -> Result<()> {
         Ok(())
     }
 }
@@ -1,4 +1,5 @@
 pub mod api;
 pub mod config;
 pub mod logging;
+pub mod settings;
 pub mod sync_state;
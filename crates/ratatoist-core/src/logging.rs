@@ -1,4 +1,5 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 use anyhow::{Context, Result};
 use tracing_appender::non_blocking::WorkerGuard;
@@ -6,11 +7,20 @@ use tracing_subscriber::EnvFilter;
 use tracing_subscriber::fmt::time::UtcTime;
 use tracing_subscriber::prelude::*;
 
-pub fn init(debug_mode: bool) -> Result<WorkerGuard> {
-    let log_dir = log_dir();
+const LOG_PREFIX: &str = "ratatoist.log";
+/// Log files older than this are deleted on startup.
+const MAX_LOG_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+/// Oldest files are deleted on startup until the directory fits in this.
+const MAX_LOG_BYTES: u64 = 50 * 1024 * 1024;
+
+/// Starts file logging. `log_dir` overrides the default location (see
+/// [`default_log_dir`]); old files there are pruned first.
+pub fn init(debug_mode: bool, log_dir: Option<PathBuf>) -> Result<WorkerGuard> {
+    let log_dir = log_dir.unwrap_or_else(default_log_dir);
     std::fs::create_dir_all(&log_dir).context("failed to create log directory")?;
+    let pruned = prune(&log_dir);
 
-    let file_appender = tracing_appender::rolling::daily(&log_dir, "ratatoist.log");
+    let file_appender = tracing_appender::rolling::daily(&log_dir, LOG_PREFIX);
     let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
 
     let level = if debug_mode { "debug" } else { "info" };
@@ -33,12 +43,145 @@ pub fn init(debug_mode: bool) -> Result<WorkerGuard> {
     tracing::info!(
         version = env!("CARGO_PKG_VERSION"),
         debug_mode,
+        log_dir = %log_dir.display(),
+        pruned,
         "ratatoist starting"
     );
 
     Ok(guard)
 }
 
-fn log_dir() -> PathBuf {
-    crate::config::Config::config_dir().join("logs")
+/// Logs are state, not config: they go under `$XDG_STATE_HOME/ratatoist/logs`
+/// (`~/.local/state` when unset). An explicit config dir keeps everything,
+/// logs included, in one place.
+pub fn default_log_dir() -> PathBuf {
+    if let Some(dir) = crate::config::Config::config_dir_override() {
+        return dir.join("logs");
+    }
+    if let Ok(xdg) = std::env::var("XDG_STATE_HOME")
+        && !xdg.is_empty()
+    {
+        return PathBuf::from(xdg).join("ratatoist").join("logs");
+    }
+    dirs::home_dir()
+        .map(|home| {
+            home.join(".local")
+                .join("state")
+                .join("ratatoist")
+                .join("logs")
+        })
+        .unwrap_or_else(|| crate::config::Config::config_dir().join("logs"))
+}
+
+/// Writes a crash report (panic message, backtrace and the tail of the newest
+/// log file) into `dir` and returns its path.
+pub fn write_crash_report(dir: &Path, panic: &str, backtrace: &str) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir).context("failed to create log directory")?;
+    let now = chrono::Local::now();
+    let path = dir.join(format!("crash-{}.txt", now.format("%Y%m%d-%H%M%S")));
+    let report = format!(
+        "ratatoist {} crashed at {}\n\n{panic}\n\nbacktrace:\n{backtrace}\n\nlast log lines:\n{}\n",
+        env!("CARGO_PKG_VERSION"),
+        now.to_rfc3339(),
+        recent_log_lines(dir, CRASH_LOG_LINES),
+    );
+    std::fs::write(&path, report).context("failed to write crash report")?;
+    Ok(path)
+}
+
+/// The daily log file that covers `at`; files roll over at UTC midnight.
+pub fn log_file_for(dir: &Path, at: chrono::DateTime<chrono::Utc>) -> PathBuf {
+    dir.join(format!("{LOG_PREFIX}.{}", at.format("%Y-%m-%d")))
+}
+
+const CRASH_LOG_LINES: usize = 50;
+
+fn recent_log_lines(dir: &Path, n: usize) -> String {
+    let newest = std::fs::read_dir(dir).ok().and_then(|entries| {
+        entries
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().starts_with(LOG_PREFIX))
+            .max_by_key(|e| e.metadata().and_then(|m| m.modified()).ok())
+    });
+    let Some(src) = newest.and_then(|e| std::fs::read_to_string(e.path()).ok()) else {
+        return "(no log file)".to_string();
+    };
+    let lines: Vec<&str> = src.lines().collect();
+    lines[lines.len().saturating_sub(n)..].join("\n")
+}
+
+/// Deletes expired and excess log files, returning how many were removed.
+fn prune(dir: &Path) -> usize {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    let files: Vec<(PathBuf, SystemTime, u64)> = entries
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with(LOG_PREFIX))
+        .filter_map(|e| {
+            let meta = e.metadata().ok()?;
+            Some((e.path(), meta.modified().ok()?, meta.len()))
+        })
+        .collect();
+    select_for_pruning(files, SystemTime::now())
+        .into_iter()
+        .filter(|path| std::fs::remove_file(path).is_ok())
+        .count()
+}
+
+fn select_for_pruning(mut files: Vec<(PathBuf, SystemTime, u64)>, now: SystemTime) -> Vec<PathBuf> {
+    // Newest first: the first file over the budget and everything older go,
+    // so no gap opens up in the history that is kept.
+    files.sort_by_key(|f| std::cmp::Reverse(f.1));
+    let mut total = 0;
+    let mut full = false;
+    files
+        .into_iter()
+        .filter_map(|(path, modified, len)| {
+            let age = now.duration_since(modified).unwrap_or_default();
+            full |= total + len > MAX_LOG_BYTES;
+            if full || age > MAX_LOG_AGE {
+                return Some(path);
+            }
+            total += len;
+            None
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prunes_old_and_oversized_logs() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        let files = vec![
+            (PathBuf::from("old"), now - 20 * day, 10),
+            (PathBuf::from("today"), now, MAX_LOG_BYTES - 10),
+            (PathBuf::from("yesterday"), now - day, 20),
+            (PathBuf::from("recent"), now - 2 * day, 5),
+        ];
+        let pruned = select_for_pruning(files, now);
+        assert_eq!(
+            pruned,
+            vec![
+                PathBuf::from("yesterday"),
+                PathBuf::from("recent"),
+                PathBuf::from("old")
+            ]
+        );
+    }
+
+    #[test]
+    fn log_file_is_named_by_utc_day() {
+        let at = chrono::DateTime::parse_from_rfc3339("2026-03-01T23:30:00-05:00")
+            .unwrap()
+            .to_utc();
+        assert_eq!(
+            log_file_for(Path::new("logs"), at),
+            PathBuf::from("logs/ratatoist.log.2026-03-02")
+        );
+    }
 }
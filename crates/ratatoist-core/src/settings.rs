@@ -0,0 +1,320 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use toml_edit::Item;
+use tracing::{info, warn};
+
+use crate::config::Config;
+
+/// User-facing preferences, stored as top-level keys of `config.toml` next to
+/// the API token. Enum-like values are kept as their lowercase labels so the
+/// file stays hand-editable; the UI maps them to its own types.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub theme: Option<String>,
+    /// `"vim"` or `"standard"`.
+    pub input_mode: String,
+    /// View shown after startup: `"projects"` or `"today"`.
+    pub default_view: String,
+    /// Sort used by projects without a remembered view.
+    pub default_sort: String,
+    pub default_sort_descending: bool,
+    pub idle_timeout_secs: u64,
+    /// Incremental sync interval while the websocket is down and the app
+    /// isn't idle; 0 turns polling off.
+    pub poll_interval_secs: u64,
+    pub sidebar_pct: u16,
+    pub show_preview: bool,
+    pub title_overflow: String,
+    pub focus_minutes: u64,
+    pub focus_notify: bool,
+    pub plain_mode: bool,
+    pub priority_marker: String,
+    pub show_clock: bool,
+    pub key_hints: String,
+    /// Look for a newer release on GitHub at startup. Off unless opted in.
+    pub check_updates: bool,
+    /// File of key remaps, relative to the config directory unless absolute.
+    /// Hand-edited only, like `statusbar`.
+    #[serde(skip_serializing)]
+    pub keymap: Option<String>,
+    /// Hand-edited only, so never written back.
+    #[serde(skip_serializing)]
+    pub statusbar: StatusbarConfig,
+    /// Hand-edited only, like `statusbar`.
+    #[serde(skip_serializing)]
+    pub hooks: HooksConfig,
+    /// Hand-edited only, like `statusbar`.
+    #[serde(skip_serializing)]
+    pub dock: DockConfig,
+}
+
+/// `[statusbar]` table: segment names shown on the left and right of the
+/// statusbar, in order. A missing list keeps the built-in layout for that side.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct StatusbarConfig {
+    pub left: Option<Vec<String>>,
+    pub right: Option<Vec<String>>,
+}
+
+/// `[hooks]` table: shell commands run on events, with the task (or error) as
+/// JSON on stdin. Unset hooks do nothing.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct HooksConfig {
+    pub on_task_complete: Option<String>,
+    pub on_task_add: Option<String>,
+    pub on_sync_error: Option<String>,
+}
+
+/// `[dock]` table: extra stats dock entries, each the name of a saved Todoist
+/// filter or a filter query of its own.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct DockConfig {
+    pub filters: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: None,
+            input_mode: "vim".to_string(),
+            default_view: "projects".to_string(),
+            default_sort: "order".to_string(),
+            default_sort_descending: false,
+            idle_timeout_secs: 300,
+            poll_interval_secs: 120,
+            sidebar_pct: 30,
+            show_preview: false,
+            title_overflow: "truncate".to_string(),
+            focus_minutes: 25,
+            focus_notify: true,
+            plain_mode: false,
+            priority_marker: "dot".to_string(),
+            show_clock: false,
+            key_hints: "full".to_string(),
+            check_updates: false,
+            keymap: None,
+            statusbar: StatusbarConfig::default(),
+            hooks: HooksConfig::default(),
+            dock: DockConfig::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Loads settings from `config.toml`, first folding in a legacy
+    /// `ui_settings.json` if one is still around.
+    pub fn load(config_dir: &Path) -> Self {
+        let mut settings = Self::read(config_dir);
+        if Self::legacy_path(config_dir).exists() {
+            match settings.migrate_legacy(config_dir) {
+                Ok(()) => info!("migrated ui_settings.json into config.toml"),
+                Err(e) => warn!(error = %e, "failed to migrate ui_settings.json"),
+            }
+        }
+        settings
+    }
+
+    /// Takes the keys one at a time, so a value of the wrong type costs
+    /// only that setting rather than all of them: the next save would
+    /// otherwise write every default over the file.
+    fn read(config_dir: &Path) -> Self {
+        let Ok(src) = std::fs::read_to_string(config_dir.join("config.toml")) else {
+            return Self::default();
+        };
+        let table = match toml::from_str::<toml::Table>(&src) {
+            Ok(table) => table,
+            Err(e) => {
+                warn!(error = %e, "failed to parse config.toml, using default settings");
+                return Self::default();
+            }
+        };
+        let mut valid = toml::Table::new();
+        for (key, value) in table {
+            let mut candidate = valid.clone();
+            candidate.insert(key.clone(), value);
+            match toml::Value::Table(candidate.clone()).try_into::<Self>() {
+                Ok(_) => valid = candidate,
+                Err(e) => warn!(key, error = %e, "ignoring invalid setting in config.toml"),
+            }
+        }
+        toml::Value::Table(valid).try_into().unwrap_or_default()
+    }
+
+    /// Writes the settings into `config.toml`, keeping the token, comments
+    /// and any other keys already in the file. Fails without writing when
+    /// the file doesn't parse.
+    pub fn save(&self, config_dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(config_dir).context("failed to create config directory")?;
+        let values = toml_edit::ser::to_document(self).context("failed to serialize settings")?;
+        Config::edit_file(&config_dir.join("config.toml"), |doc| {
+            // `None` values have no TOML form; drop them so a cleared key goes away.
+            if self.theme.is_none() {
+                doc.remove("theme");
+            }
+            for (key, item) in values.iter() {
+                match (doc.get_mut(key), item) {
+                    // Swap the value but keep the comment after it.
+                    (Some(Item::Value(old)), Item::Value(new)) => {
+                        let decor = old.decor().clone();
+                        *old = new.clone();
+                        *old.decor_mut() = decor;
+                    }
+                    _ => doc[key] = item.clone(),
+                }
+            }
+        })
+    }
+
+    /// Non-preference UI state (per-project views, folds), kept out of
+    /// `config.toml` because it changes constantly.
+    pub fn ui_state_path(config_dir: &Path) -> PathBuf {
+        config_dir.join("ui_state.json")
+    }
+
+    /// Private per-task notes, which never leave this machine.
+    pub fn local_notes_path(config_dir: &Path) -> PathBuf {
+        config_dir.join("local_notes.json")
+    }
+
+    /// Text typed into inputs that were closed without submitting, by
+    /// context, offered back when the same input reopens.
+    pub fn drafts_path(config_dir: &Path) -> PathBuf {
+        config_dir.join("drafts.json")
+    }
+
+    fn legacy_path(config_dir: &Path) -> PathBuf {
+        config_dir.join("ui_settings.json")
+    }
+
+    /// Moves preference keys from `ui_settings.json` into `config.toml` and the
+    /// remaining state keys into `ui_state.json`, then renames the old file.
+    fn migrate_legacy(&mut self, config_dir: &Path) -> Result<()> {
+        let legacy = Self::legacy_path(config_dir);
+        let src = std::fs::read_to_string(&legacy).context("failed to read ui_settings.json")?;
+        let mut old: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(&src).context("failed to parse ui_settings.json")?;
+
+        if let Some(mins) = old.remove("idle_timeout_mins").and_then(|v| v.as_u64()) {
+            old.entry("idle_timeout_secs")
+                .or_insert_with(|| (mins * 60).into());
+        }
+
+        let mut merged = serde_json::to_value(&*self)?;
+        let mut state = serde_json::Map::new();
+        for (key, value) in old {
+            match merged.get_mut(&key) {
+                Some(slot) => *slot = value,
+                None => {
+                    state.insert(key, value);
+                }
+            }
+        }
+        let statusbar = std::mem::take(&mut self.statusbar);
+        let hooks = std::mem::take(&mut self.hooks);
+        let dock = std::mem::take(&mut self.dock);
+        let keymap = self.keymap.take();
+        *self = serde_json::from_value(merged).context("invalid value in ui_settings.json")?;
+        self.statusbar = statusbar;
+        self.hooks = hooks;
+        self.dock = dock;
+        self.keymap = keymap;
+        self.save(config_dir)?;
+
+        if !state.is_empty() {
+            let state_path = Self::ui_state_path(config_dir);
+            std::fs::write(&state_path, serde_json::to_string_pretty(&state)?)
+                .context("failed to write ui_state.json")?;
+        }
+        std::fs::rename(&legacy, config_dir.join("ui_settings.json.bak"))
+            .context("failed to rename ui_settings.json")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(tag: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("ratatoist-settings-{tag}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn save_keeps_token_and_round_trips() {
+        let dir = temp_dir("roundtrip");
+        std::fs::write(
+            dir.join("config.toml"),
+            "# my token\napi_token = \"secret\"\nshow_clock = false # off for now\nkeymap = \"keys.toml\"\n\n[statusbar]\nleft = [\"mode\"]\n\n[hooks]\non_task_add = \"say added\"\n\n[dock]\nfilters = [\"@errand\"]\n",
+        )
+        .unwrap();
+
+        let mut settings = Settings::load(&dir);
+        assert_eq!(settings.statusbar.left, Some(vec!["mode".to_string()]));
+        assert_eq!(settings.hooks.on_task_add.as_deref(), Some("say added"));
+        assert_eq!(settings.dock.filters, vec!["@errand".to_string()]);
+        settings.theme = Some("Nord".to_string());
+        settings.show_clock = true;
+        settings.save(&dir).unwrap();
+
+        let src = std::fs::read_to_string(dir.join("config.toml")).unwrap();
+        assert!(src.starts_with("# my token\napi_token = \"secret\"\n"));
+        assert!(src.contains("show_clock = true # off for now"));
+        assert!(src.contains("keymap = \"keys.toml\""));
+        assert_eq!(Settings::load(&dir), settings);
+    }
+
+    #[test]
+    fn a_bad_value_only_loses_its_own_key() {
+        let dir = temp_dir("bad-value");
+        std::fs::write(
+            dir.join("config.toml"),
+            "theme = \"Nord\"\nshow_clock = \"yes\"\nsidebar_pct = 40\n",
+        )
+        .unwrap();
+
+        let settings = Settings::load(&dir);
+        assert_eq!(settings.theme.as_deref(), Some("Nord"));
+        assert_eq!(settings.sidebar_pct, 40);
+        assert!(!settings.show_clock);
+    }
+
+    #[test]
+    fn save_leaves_an_unparseable_file_alone() {
+        let dir = temp_dir("broken");
+        let broken = "api_token = \"secret\"\ntheme = \n";
+        std::fs::write(dir.join("config.toml"), broken).unwrap();
+
+        assert!(Settings::default().save(&dir).is_err());
+        let src = std::fs::read_to_string(dir.join("config.toml")).unwrap();
+        assert_eq!(src, broken);
+    }
+
+    #[test]
+    fn migrates_legacy_json() {
+        let dir = temp_dir("migrate");
+        std::fs::write(
+            dir.join("ui_settings.json"),
+            r#"{"theme":"Dracula","idle_timeout_mins":2,"show_preview":true,
+                "collapsed_folders":["f1"]}"#,
+        )
+        .unwrap();
+
+        let settings = Settings::load(&dir);
+        assert_eq!(settings.theme.as_deref(), Some("Dracula"));
+        assert_eq!(settings.idle_timeout_secs, 120);
+        assert!(settings.show_preview);
+        assert!(!dir.join("ui_settings.json").exists());
+
+        let state = std::fs::read_to_string(Settings::ui_state_path(&dir)).unwrap();
+        assert!(state.contains("collapsed_folders"));
+        assert_eq!(Settings::load(&dir), settings);
+    }
+}
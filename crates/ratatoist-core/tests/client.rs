@@ -0,0 +1,210 @@
+use ratatoist_core::api::client::TodoistClient;
+use ratatoist_core::api::error::TodoistError;
+use ratatoist_core::api::sync::{SyncCommand, SyncRequest};
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client(server: &MockServer) -> TodoistClient {
+    TodoistClient::with_base_url("test-token", &server.uri()).unwrap()
+}
+
+fn sync_request(commands: Vec<SyncCommand>) -> SyncRequest {
+    SyncRequest {
+        sync_token: "*".to_string(),
+        resource_types: vec!["items".to_string()],
+        commands,
+    }
+}
+
+#[tokio::test]
+async fn comments_follow_the_cursor_across_pages() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/comments"))
+        .and(query_param("task_id", "t1"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{ "id": "c2", "content": "second" }],
+            "next_cursor": null,
+        })))
+        .mount(&server)
+        .await;
+    // Mocks match in mount order, so this only sees the first-page request.
+    Mock::given(method("GET"))
+        .and(path("/comments"))
+        .and(query_param("task_id", "t1"))
+        .and(header("authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{ "id": "c1", "content": "first" }],
+            "next_cursor": "page2",
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+
+    let comments = client(&server).get_comments("t1").await.unwrap();
+    let ids: Vec<&str> = comments.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, ["c1", "c2"]);
+}
+
+#[tokio::test]
+async fn completed_tasks_fall_back_to_the_record_without_an_item_object() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/completed"))
+        .and(query_param("project_id", "p1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [{
+                "task_id": "t9",
+                "content": "done",
+                "completed_at": "2026-01-02T10:00:00Z",
+                "project_id": "p1",
+            }],
+        })))
+        .mount(&server)
+        .await;
+
+    let tasks = client(&server)
+        .get_completed_tasks(Some("p1"), None)
+        .await
+        .unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].id, "t9");
+    assert!(tasks[0].checked);
+    assert_eq!(
+        tasks[0].completed_at.as_deref(),
+        Some("2026-01-02T10:00:00Z")
+    );
+}
+
+#[tokio::test]
+async fn error_responses_come_back_typed() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "Invalid token",
+            "error_tag": "UNAUTHORIZED",
+        })))
+        .mount(&server)
+        .await;
+
+    let err = client(&server).get_user().await.unwrap_err();
+    assert!(
+        matches!(
+            err.downcast_ref(),
+            Some(TodoistError::Unauthorized { message }) if message == "Invalid token"
+        ),
+        "{err:#}"
+    );
+}
+
+#[tokio::test]
+async fn sync_parses_command_status_and_temp_ids() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .and(body_partial_json(
+            json!({ "commands": [{ "type": "item_add" }] }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "full_sync": false,
+            "sync_token": "next",
+            "sync_status": {
+                "u1": "ok",
+                "u2": { "error_code": 22, "error": "Item not found" },
+            },
+            "temp_id_mapping": { "tmp-1": "real-1" },
+        })))
+        .mount(&server)
+        .await;
+
+    let commands = ["u1", "u2"]
+        .into_iter()
+        .map(|uuid| SyncCommand {
+            r#type: "item_add".to_string(),
+            temp_id: Some("tmp-1".to_string()),
+            uuid: uuid.to_string(),
+            args: json!({ "content": "buy milk", "project_id": "p1" }),
+        })
+        .collect();
+    let resp = client(&server).sync(&sync_request(commands)).await.unwrap();
+
+    assert_eq!(resp.sync_token, "next");
+    assert!(!resp.sync_status["u1"].is_err());
+    assert_eq!(
+        resp.sync_status["u2"].error_message(),
+        Some("Item not found")
+    );
+    assert_eq!(resp.temp_id_mapping["tmp-1"], "real-1");
+}
+
+#[tokio::test]
+async fn sync_retries_after_a_rate_limit() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "full_sync": true,
+            "sync_token": "after-retry",
+            "items": [],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let resp = client(&server).sync(&sync_request(vec![])).await.unwrap();
+    assert_eq!(resp.sync_token, "after-retry");
+}
+
+#[tokio::test]
+async fn sync_gives_up_on_server_errors_without_retrying() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sync"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let err = client(&server)
+        .sync(&sync_request(vec![]))
+        .await
+        .unwrap_err();
+    assert!(format!("{err:#}").contains("boom"));
+    assert!(matches!(
+        err.downcast_ref(),
+        Some(TodoistError::Http { status, .. }) if status.as_u16() == 500
+    ));
+}
+
+#[tokio::test]
+async fn rate_limit_headers_update_the_shared_budget() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-RateLimit-Limit", "450")
+                .insert_header("X-RateLimit-Remaining", "449")
+                .set_body_json(json!({ "id": "u1" })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let metrics = client.metrics();
+    assert_eq!(metrics.budget(), None);
+    let _ = client.get_user().await;
+    let budget = metrics.budget().unwrap();
+    assert_eq!((budget.limit, budget.remaining), (450, 449));
+    assert!(!budget.is_low());
+}
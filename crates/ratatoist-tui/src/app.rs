@@ -1,23 +1,35 @@
-use std::collections::{HashMap, HashSet};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU16, AtomicU64, AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
-use chrono::Local;
+use chrono::{DateTime, Local};
 
 use anyhow::Result;
-use crossterm::event::{self, Event};
+use crossterm::event::{self, Event, KeyCode, KeyEvent};
 use ratatui::DefaultTerminal;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
 use ratatoist_core::api::client::TodoistClient;
-use ratatoist_core::api::models::{Comment, Folder, Label, Project, Section, Task, Workspace};
-use ratatoist_core::api::sync::{SyncCommand, SyncRequest, SyncResponse};
+use ratatoist_core::api::error::TodoistError;
+use ratatoist_core::api::metrics::RateBudget;
+use ratatoist_core::api::models::{
+    ActivityEvent, Comment, Filter, Folder, ItemAddArgs, Label, LiveNotification,
+    ProductivityStats, Project, Section, Task, TzInfo, UserInfo, Workspace, WorkspaceUser,
+};
+use ratatoist_core::api::sync::{CollaboratorState, SyncCommand, SyncRequest, SyncResponse};
+use ratatoist_core::settings::{HooksConfig, Settings};
 use ratatoist_core::sync_state::SyncState;
 
+use crate::filter_query::{self, Query};
+use crate::hooks::{self, Hook};
 use crate::keys::{self, KeyAction};
+use crate::recurrence::{Frequency, Recurrence};
+use crate::tour::{Tour, TourGoal, TourTarget};
 use crate::ui;
+use crate::ui::dates::Postpone;
 
 static CMD_COUNTER: AtomicU64 = AtomicU64::new(0);
 
@@ -34,6 +46,77 @@ fn new_temp_id() -> String {
     format!("tmp_{}", CMD_COUNTER.fetch_add(1, Ordering::Relaxed))
 }
 
+/// `raw` with its leading `YYYY-MM-DD` moved by `days`; a time or zone
+/// after it is kept.
+fn shift_due_days(raw: &str, days: i64) -> Option<String> {
+    let day = chrono::NaiveDate::parse_from_str(raw.get(..10)?, "%Y-%m-%d").ok()?;
+    let moved = day.checked_add_signed(chrono::Duration::days(days))?;
+    Some(format!("{}{}", moved.format("%Y-%m-%d"), &raw[10..]))
+}
+
+/// Ways of setting a due date; setting one replaces the others.
+const DUE_ARGS: [&str; 4] = ["due", "due_string", "due_date", "due_datetime"];
+
+/// Folds each `item_update` into the one queued just before it when both
+/// edit the same task, so a burst of edits is one command. The first
+/// command's uuid and snapshot stay: its `before` predates both edits, and
+/// the folded command's snapshot is dropped from `pending`.
+fn coalesce_updates(
+    commands: Vec<SyncCommand>,
+    pending: &mut HashMap<String, OptimisticOp>,
+) -> Vec<SyncCommand> {
+    let mut out: Vec<SyncCommand> = Vec::with_capacity(commands.len());
+    for cmd in commands {
+        if let Some(prev) = out.last_mut()
+            && can_fold(pending, prev, &cmd)
+            && fold_update(prev, &cmd)
+        {
+            debug!(into = %prev.uuid, uuid = %cmd.uuid, "coalesced task update");
+            pending.remove(&cmd.uuid);
+            continue;
+        }
+        out.push(cmd);
+    }
+    out
+}
+
+/// Both commands must be snapshot task updates, or the folded one must
+/// have nothing to revert.
+fn can_fold(
+    pending: &HashMap<String, OptimisticOp>,
+    prev: &SyncCommand,
+    next: &SyncCommand,
+) -> bool {
+    matches!(
+        pending.get(&prev.uuid),
+        Some(OptimisticOp::TaskUpdated { .. })
+    ) && matches!(
+        pending.get(&next.uuid),
+        None | Some(OptimisticOp::TaskUpdated { .. })
+    )
+}
+
+/// Merges `next`'s fields into `prev` when both are `item_update`s of the
+/// same task; later values win.
+fn fold_update(prev: &mut SyncCommand, next: &SyncCommand) -> bool {
+    if prev.r#type != "item_update" || next.r#type != "item_update" {
+        return false;
+    }
+    let (Some(into), Some(from)) = (prev.args.as_object_mut(), next.args.as_object()) else {
+        return false;
+    };
+    if into.get("id").is_none() || into.get("id") != from.get("id") {
+        return false;
+    }
+    if DUE_ARGS.iter().any(|k| from.contains_key(*k)) {
+        into.retain(|k, _| !DUE_ARGS.contains(&k.as_str()));
+    }
+    for (k, v) in from {
+        into.insert(k.clone(), v.clone());
+    }
+    true
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Pane {
     Projects,
@@ -73,6 +156,20 @@ pub struct OverviewStats {
     pub due_week: u32,
     pub overdue: u32,
     pub by_priority: [u32; 5],
+    /// Open tasks among [`App::pinned`].
+    pub pinned: u32,
+    /// Matching active tasks for each [`App::custom_dock`] entry.
+    pub custom: Vec<u32>,
+    /// Completed this week, against the user's weekly goal (0 when unknown).
+    pub week_done: u32,
+    pub week_total: u32,
+    pub week_progress: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProjectCounts {
+    pub active: u32,
+    pub overdue: u32,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -83,6 +180,20 @@ pub enum TaskFilter {
 }
 
 impl TaskFilter {
+    pub fn label(self) -> &'static str {
+        match self {
+            TaskFilter::Active => "active",
+            TaskFilter::Done => "done",
+            TaskFilter::Both => "both",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Self> {
+        [TaskFilter::Active, TaskFilter::Done, TaskFilter::Both]
+            .into_iter()
+            .find(|f| f.label() == label)
+    }
+
     pub fn next(self) -> Self {
         match self {
             TaskFilter::Active => TaskFilter::Done,
@@ -98,9 +209,13 @@ pub enum DockItem {
     DueToday,
     DueWeek,
     Priority(u8),
+    /// Tasks pinned locally with `*`.
+    Pinned,
+    /// Index into [`App::custom_dock`].
+    Custom(usize),
 }
 
-pub const DOCK_ITEMS: [DockItem; 7] = [
+const DOCK_ITEMS: [DockItem; 7] = [
     DockItem::DueOverdue,
     DockItem::DueToday,
     DockItem::DueWeek,
@@ -121,16 +236,46 @@ impl DockItem {
             DockItem::Priority(2) => "medium (P3)",
             DockItem::Priority(1) => "no priority",
             DockItem::Priority(_) => "by priority",
+            DockItem::Pinned => "pinned",
+            DockItem::Custom(_) => "saved filter",
         }
     }
 }
 
+/// Most `[dock] filters` entries shown; more would not fit on the dock row.
+pub const MAX_DOCK_FILTERS: usize = 4;
+
+/// A `[dock] filters` entry, resolved against the user's saved filters.
+pub struct CustomDock {
+    pub name: String,
+    query: Query,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortMode {
     Default,
     Priority,
     DueDate,
     Created,
+    Alphabetical,
+    Label,
+    Deadline,
+}
+
+/// Applies the sort direction to an ascending comparison.
+fn directed(ord: std::cmp::Ordering, descending: bool) -> std::cmp::Ordering {
+    if descending { ord.reverse() } else { ord }
+}
+
+/// Compares optional sort keys so tasks without one stay at the bottom in
+/// either direction.
+fn missing_last<T: Ord>(a: Option<T>, b: Option<T>, descending: bool) -> std::cmp::Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => directed(a.cmp(&b), descending),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    }
 }
 
 impl SortMode {
@@ -140,17 +285,339 @@ impl SortMode {
             SortMode::Priority => "priority",
             SortMode::DueDate => "due",
             SortMode::Created => "created",
+            SortMode::Alphabetical => "a-z",
+            SortMode::Label => "label",
+            SortMode::Deadline => "deadline",
         }
     }
 
+    fn from_label(label: &str) -> Option<Self> {
+        [
+            SortMode::Default,
+            SortMode::Priority,
+            SortMode::DueDate,
+            SortMode::Created,
+            SortMode::Alphabetical,
+            SortMode::Label,
+            SortMode::Deadline,
+        ]
+        .into_iter()
+        .find(|m| m.label() == label)
+    }
+
     pub fn next(&self) -> Self {
         match self {
             SortMode::Default => SortMode::Priority,
             SortMode::Priority => SortMode::DueDate,
             SortMode::DueDate => SortMode::Created,
-            SortMode::Created => SortMode::Default,
+            SortMode::Created => SortMode::Alphabetical,
+            SortMode::Alphabetical => SortMode::Label,
+            SortMode::Label => SortMode::Deadline,
+            SortMode::Deadline => SortMode::Default,
+        }
+    }
+}
+
+/// How task rows handle titles wider than the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TitleOverflow {
+    Truncate,
+    Wrap,
+}
+
+impl TitleOverflow {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TitleOverflow::Truncate => "truncate",
+            TitleOverflow::Wrap => "wrap",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [TitleOverflow::Truncate, TitleOverflow::Wrap]
+            .into_iter()
+            .find(|t| t.label() == label)
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            TitleOverflow::Truncate => TitleOverflow::Wrap,
+            TitleOverflow::Wrap => TitleOverflow::Truncate,
+        }
+    }
+}
+
+/// How priority is marked in the task list and stats dock. `Dot` relies on
+/// color alone; `Glyph` and `Badge` stay distinguishable without it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriorityMarker {
+    Dot,
+    Glyph,
+    Badge,
+}
+
+impl PriorityMarker {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PriorityMarker::Dot => "dot",
+            PriorityMarker::Glyph => "glyph",
+            PriorityMarker::Badge => "badge",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [
+            PriorityMarker::Dot,
+            PriorityMarker::Glyph,
+            PriorityMarker::Badge,
+        ]
+        .into_iter()
+        .find(|m| m.label() == label)
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            PriorityMarker::Dot => PriorityMarker::Glyph,
+            PriorityMarker::Glyph => PriorityMarker::Badge,
+            PriorityMarker::Badge => PriorityMarker::Dot,
+        }
+    }
+
+    /// Prefix drawn before a task title; blank for normal priority.
+    pub fn task_prefix(&self, priority: u8) -> &'static str {
+        match (self, priority) {
+            (PriorityMarker::Dot, p) => crate::ui::theme::Theme::priority_dot(p),
+            (PriorityMarker::Glyph, 4) => "▲ ",
+            (PriorityMarker::Glyph, 3) => "◆ ",
+            (PriorityMarker::Glyph, 2) => "● ",
+            (PriorityMarker::Glyph, _) => "  ",
+            (PriorityMarker::Badge, 4) => "P1 ",
+            (PriorityMarker::Badge, 3) => "P2 ",
+            (PriorityMarker::Badge, 2) => "P3 ",
+            (PriorityMarker::Badge, _) => "   ",
+        }
+    }
+
+    /// Symbol for a priority bucket in the stats dock.
+    pub fn dock_symbol(&self, priority: u8) -> &'static str {
+        match (self, priority) {
+            (PriorityMarker::Badge, 4) => "P1",
+            (PriorityMarker::Badge, 3) => "P2",
+            (PriorityMarker::Badge, 2) => "P3",
+            (PriorityMarker::Badge, _) => "P4",
+            (PriorityMarker::Glyph, 4) => "▲",
+            (PriorityMarker::Glyph, 3) => "◆",
+            (PriorityMarker::Dot, 2..=4) | (PriorityMarker::Glyph, _) => "●",
+            (PriorityMarker::Dot, _) => "─",
+        }
+    }
+}
+
+/// How much of the keyhints row to show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyHints {
+    Full,
+    /// Only the few most relevant hints for the current context.
+    Compact,
+    /// No keyhints row; the line goes to the panes.
+    Off,
+}
+
+impl KeyHints {
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeyHints::Full => "full",
+            KeyHints::Compact => "compact",
+            KeyHints::Off => "off",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [KeyHints::Full, KeyHints::Compact, KeyHints::Off]
+            .into_iter()
+            .find(|h| h.label() == label)
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            KeyHints::Full => KeyHints::Compact,
+            KeyHints::Compact => KeyHints::Off,
+            KeyHints::Off => KeyHints::Full,
+        }
+    }
+}
+
+/// What is open once the initial sync finishes (`default_view` setting).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartView {
+    /// The project list, as last left.
+    Projects,
+    Inbox,
+    Today,
+    /// Wherever the previous session was on exit.
+    Last,
+}
+
+impl StartView {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StartView::Projects => "projects",
+            StartView::Inbox => "inbox",
+            StartView::Today => "today",
+            StartView::Last => "last",
         }
     }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [
+            StartView::Projects,
+            StartView::Inbox,
+            StartView::Today,
+            StartView::Last,
+        ]
+        .into_iter()
+        .find(|v| v.label() == label)
+    }
+}
+
+/// Answers to the recurring-task completion prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurringChoice {
+    Occurrence,
+    Forever,
+    Skip,
+}
+
+/// One step of overdue triage, applied to the task under review.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriageAction {
+    Postpone(Postpone),
+    Complete,
+    Delete,
+    Skip,
+}
+
+/// Overdue triage: walks a snapshot of the project's overdue tasks one at a
+/// time. Deleting takes a second press, tracked by `confirm_delete`.
+#[derive(Debug, Clone)]
+pub struct Triage {
+    pub task_ids: Vec<String>,
+    pub index: usize,
+    pub handled: usize,
+    pub confirm_delete: bool,
+}
+
+/// A yes/no prompt guarding a bulk action.
+#[derive(Debug, Clone)]
+pub struct Confirm {
+    pub title: String,
+    pub message: String,
+    pub action: ConfirmAction,
+}
+
+#[derive(Debug, Clone)]
+pub enum ConfirmAction {
+    RescheduleOverdue(Vec<String>),
+    /// One new task per line, sharing the open task form's other fields.
+    AddTasks(Vec<String>),
+    /// Put a saved draft back into the input it was typed in.
+    RestoreDraft(String),
+    /// Quit with changes still unsynced. Holds any commands that failed to
+    /// send, so declining brings back their retry prompt.
+    Quit(Vec<SyncCommand>),
+}
+
+/// The entry after `current` in `options`, wrapping through `None`.
+fn cycle_option(options: &[String], current: Option<&String>) -> Option<String> {
+    match current.and_then(|c| options.iter().position(|o| o == c)) {
+        Some(i) => options.get(i + 1).cloned(),
+        None => options.first().cloned(),
+    }
+}
+
+/// The prompt for commands that couldn't reach Todoist: `r` re-sends them,
+/// any other key reverts their optimistic changes.
+fn sync_failed_error(commands: Vec<SyncCommand>) -> AppError {
+    AppError {
+        title: "Sync failed".to_string(),
+        message: "Couldn't reach Todoist — your change hasn't been saved.".to_string(),
+        suggestion: Some("Check your connection; any other key reverts the change.".to_string()),
+        recoverable: true,
+        retry: Some(RetryAction::Commands(commands)),
+        context: "Syncing changes".to_string(),
+        raw: None,
+        at: Local::now(),
+    }
+}
+
+/// Download URL of a comment's file attachment, if it has one.
+pub fn comment_attachment_url(comment: &Comment) -> Option<&str> {
+    comment
+        .attachment
+        .as_ref()
+        .and_then(|a| a.get("file_url"))
+        .and_then(|v| v.as_str())
+}
+
+const FLASH_DURATION: Duration = Duration::from_secs(3);
+/// Drafts are written at most this often while typing.
+const DRAFT_SAVE_DELAY: Duration = Duration::from_secs(1);
+/// Queued commands wait this long for more before going out, so a burst
+/// of edits shares one request.
+const COMMAND_FLUSH_DELAY: Duration = Duration::from_millis(250);
+/// How long quitting waits for unsynced changes to be acknowledged.
+const QUIT_SYNC_WAIT: Duration = Duration::from_secs(3);
+/// Input poll interval, roughly one frame.
+const ACTIVE_POLL: Duration = Duration::from_millis(16);
+/// Input poll interval once idle, when nothing is redrawn unless input,
+/// a background result or a websocket event arrives.
+const IDLE_POLL: Duration = Duration::from_millis(500);
+
+/// What an incremental sync asks for.
+const INCREMENTAL_RESOURCES: &[&str] = &[
+    "items",
+    "projects",
+    "sections",
+    "labels",
+    "filters",
+    "notes",
+    "collaborators",
+    "live_notifications",
+];
+
+/// Max API fetches in flight at once; the rest wait for a slot.
+const MAX_CONCURRENT_FETCHES: usize = 6;
+/// Max comment fetches in flight while prefetching a project's threads,
+/// below the overall cap so opening a task never waits behind a prefetch.
+const COMMENT_PREFETCH_CONCURRENCY: usize = 4;
+
+/// Pomodoro lengths offered in settings.
+const FOCUS_OPTIONS: &[u64] = &[25, 50];
+/// Polling intervals offered in settings, in seconds; 0 is off.
+const POLL_OPTIONS: &[u64] = &[0, 60, 120, 300, 600];
+
+/// A running focus (pomodoro) session pinned to one task.
+#[derive(Debug, Clone)]
+pub struct FocusSession {
+    pub task_id: String,
+    pub content: String,
+    pub started_at: chrono::DateTime<Local>,
+    pub started: Instant,
+    pub length: Duration,
+}
+
+impl FocusSession {
+    pub fn remaining(&self) -> Duration {
+        self.length.saturating_sub(self.started.elapsed())
+    }
+}
+
+/// What `y` copies from the selected task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YankTarget {
+    Content,
+    Url,
+    Markdown,
 }
 
 #[derive(Debug, Clone)]
@@ -159,48 +626,104 @@ pub struct AppError {
     pub message: String,
     pub suggestion: Option<String>,
     pub recoverable: bool,
+    /// Offered as `r` in the error popup.
+    pub retry: Option<RetryAction>,
+    /// What was being done when it failed, e.g. "Loading comments".
+    pub context: String,
+    /// The error as received, API response body included.
+    pub raw: Option<String>,
+    pub at: DateTime<Local>,
+}
+
+/// The operation behind an error, kept so the popup can re-dispatch it.
+#[derive(Debug, Clone)]
+pub enum RetryAction {
+    /// A flush that never reached the server. Its optimistic edits stay
+    /// applied while the popup is open: retry re-sends, dismiss reverts.
+    Commands(Vec<SyncCommand>),
+    Comments(String),
+    CompletedTasks(String),
 }
 
 impl AppError {
     fn from_api(err: &anyhow::Error, context: &str) -> Self {
         let raw = format!("{err:#}");
-        let (title, message, suggestion) = parse_api_error(&raw, context);
+        let (message, suggestion) = describe_api_error(err);
         Self {
-            title,
+            title: format!("{context} failed"),
             message,
             suggestion,
             recoverable: true,
+            retry: None,
+            context: context.to_string(),
+            raw: Some(raw),
+            at: Local::now(),
         }
     }
-}
-
-fn parse_api_error(raw: &str, context: &str) -> (String, String, Option<String>) {
-    if let Some(json_start) = raw.find('{')
-        && let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&raw[json_start..])
-    {
-        let error_msg = parsed["error"]
-            .as_str()
-            .unwrap_or("Unknown error")
-            .to_string();
-        let error_tag = parsed["error_tag"].as_str().unwrap_or("");
-
-        let suggestion = match error_tag {
-            "INVALID_DATE_FORMAT" | "BAD_REQUEST" => Some(
-                "Try natural language like \"tomorrow\", \"next monday\", or \"Feb 28\""
-                    .to_string(),
-            ),
-            "NOT_FOUND" => Some("The item may have been deleted. Try refreshing.".to_string()),
-            "FORBIDDEN" => Some("You don't have permission for this action.".to_string()),
-            "UNAUTHORIZED" => {
-                Some("Your API token may have expired. Check your config.".to_string())
-            }
-            _ => None,
-        };
 
-        return (format!("{context} failed"), error_msg, suggestion);
+    /// Everything about the error for a bug report, `c` in the popup. The
+    /// timestamps and log file locate the matching "app error" log line.
+    pub fn report(&self, log_dir: &std::path::Path) -> String {
+        let at_utc = self.at.to_utc();
+        let mut out = format!(
+            "ratatoist {} error\n\ntime: {} ({})\ncontext: {}\ntitle: {}\nmessage: {}\n",
+            env!("CARGO_PKG_VERSION"),
+            self.at.to_rfc3339(),
+            at_utc.to_rfc3339(),
+            self.context,
+            self.title,
+            self.message,
+        );
+        if let Some(suggestion) = &self.suggestion {
+            out.push_str(&format!("hint: {suggestion}\n"));
+        }
+        if let Some(raw) = &self.raw {
+            out.push_str(&format!("raw: {raw}\n"));
+        }
+        out.push_str(&format!(
+            "log: {}\n",
+            ratatoist_core::logging::log_file_for(log_dir, at_utc).display()
+        ));
+        out
     }
+}
 
-    (format!("{context} failed"), raw.to_string(), None)
+/// The popup's message and hint. Errors that aren't from the API, or that
+/// the API didn't explain, are shown in full.
+fn describe_api_error(err: &anyhow::Error) -> (String, Option<String>) {
+    let hint = |s: &str| Some(s.to_string());
+    match err.downcast_ref::<TodoistError>() {
+        Some(TodoistError::Validation { tag, message })
+            if matches!(tag.as_str(), "INVALID_DATE_FORMAT" | "BAD_REQUEST") =>
+        {
+            (
+                message.clone(),
+                hint("Try natural language like \"tomorrow\", \"next monday\", or \"Feb 28\""),
+            )
+        }
+        Some(TodoistError::Validation { message, .. }) => (message.clone(), None),
+        Some(TodoistError::NotFound { message }) => (
+            message.clone(),
+            hint("The item may have been deleted. Try refreshing."),
+        ),
+        Some(TodoistError::Forbidden { message }) => (
+            message.clone(),
+            hint("You don't have permission for this action."),
+        ),
+        Some(TodoistError::Unauthorized { message }) => (
+            message.clone(),
+            hint("Your API token may have expired. Check your config."),
+        ),
+        Some(TodoistError::RateLimited { .. }) => (
+            "Todoist is limiting requests".to_string(),
+            hint("Wait a minute, then retry."),
+        ),
+        Some(TodoistError::Network(_)) => (
+            format!("{err:#}"),
+            hint("Check your connection, then retry."),
+        ),
+        Some(TodoistError::Http { .. }) | None => (format!("{err:#}"), None),
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -231,12 +754,20 @@ impl UserRecord {
     }
 }
 
+/// The new-task composer. Fields in order: content, priority, due date,
+/// project, section, labels, description, deadline and assignee.
 #[derive(Debug, Clone)]
 pub struct TaskForm {
     pub content: String,
     pub priority: u8,
     pub due_string: String,
     pub project_id: String,
+    pub section_id: Option<String>,
+    pub labels: Vec<String>,
+    pub description: String,
+    /// `YYYY-MM-DD`; Todoist deadlines have no time.
+    pub deadline: String,
+    pub responsible_uid: Option<String>,
     pub active_field: usize,
     pub editing: bool,
 }
@@ -248,23 +779,172 @@ impl TaskForm {
             priority: 1,
             due_string: String::new(),
             project_id,
+            section_id: None,
+            labels: Vec::new(),
+            description: String::new(),
+            deadline: String::new(),
+            responsible_uid: None,
             active_field: 0,
             editing: true,
         }
     }
 
     pub fn field_count() -> usize {
-        4
+        9
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecentKind {
+    Opened,
+    Completed,
+}
+
+/// A task opened or completed this session, for the recent panel.
+#[derive(Debug, Clone)]
+pub struct RecentTask {
+    pub task_id: String,
+    pub kind: RecentKind,
+    pub at: DateTime<Local>,
+}
+
+/// Detail pane field for the task's private, unsynced note.
+pub const LOCAL_NOTE_FIELD: usize = 6;
+
+/// First detail pane row for the task's own subtasks; one row per child.
+pub const SUBTASKS_FIELD: usize = LOCAL_NOTE_FIELD + 1;
+
+/// Most entries the recent panel keeps; older ones drop off.
+const MAX_RECENT: usize = 20;
+
+/// State of the Ctrl-P "goto project" popup.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectJumper {
+    pub query: String,
+    pub selection: usize,
+    pub purpose: JumperPurpose,
+}
+
+/// What picking a project in the jumper does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JumperPurpose {
+    /// Opens the project.
+    #[default]
+    Goto,
+    /// Sets the task form's project.
+    Form,
+    /// Moves the task open in the detail pane there.
+    MoveTask,
+}
+
+/// Recurrence builder popup for one task. Rows are the frequency, the
+/// interval and, for weekly and monthly rules, the days it falls on.
+#[derive(Debug, Clone)]
+pub struct RecurrenceEditor {
+    pub task_id: String,
+    pub rule: Recurrence,
+    pub row: usize,
+    /// Highlighted weekday (Monday first) on the weekly days row.
+    pub day_cursor: usize,
+}
+
+impl RecurrenceEditor {
+    pub fn row_count(&self) -> usize {
+        match self.rule.frequency {
+            Frequency::Weekdays => 1,
+            Frequency::Daily => 2,
+            Frequency::Weekly | Frequency::Monthly => 3,
+        }
+    }
+
+    /// h/l on the focused row.
+    pub fn adjust(&mut self, step: isize) {
+        let rule = &mut self.rule;
+        match self.row {
+            0 => {
+                rule.frequency = rule.frequency.cycle(step);
+                self.row = self.row.min(self.row_count() - 1);
+            }
+            1 => {
+                rule.interval = rule
+                    .interval
+                    .saturating_add_signed(step as i32)
+                    .clamp(1, 99)
+            }
+            _ if rule.frequency == Frequency::Weekly => {
+                self.day_cursor = (self.day_cursor as isize + step).rem_euclid(7) as usize;
+            }
+            _ => rule.month_day = (rule.month_day as isize - 1 + step).rem_euclid(31) as u32 + 1,
+        }
+    }
+
+    /// Space on the weekly days row.
+    pub fn toggle_day(&mut self) {
+        if self.row == 2 && self.rule.frequency == Frequency::Weekly {
+            self.rule.weekdays[self.day_cursor] ^= true;
+        }
+    }
+}
+
+/// Find & replace over the open task titles of the selected project. The
+/// popup previews every title that would change before anything is sent.
+#[derive(Debug, Clone, Default)]
+pub struct FindReplace {
+    pub find: String,
+    pub replace: String,
+    /// Typing goes to `replace` rather than `find`.
+    pub on_replace: bool,
+}
+
+/// The shared labels panel: labels on tasks that aren't among the user's
+/// personal labels, renamed or removed across every task carrying them.
+#[derive(Debug, Clone, Default)]
+pub struct LabelManager {
+    pub selection: usize,
+    /// The new name while a rename is being typed.
+    pub rename: Option<String>,
+    /// Set once the change is submitted; a second Enter sends it.
+    pub confirm: Option<LabelChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LabelChange {
+    Rename(String),
+    Remove,
+}
+
+/// The `?` cheatsheet: a `/` filter over the active keymap and how far the
+/// list is scrolled.
+#[derive(Debug, Default)]
+pub struct Cheatsheet {
+    pub query: String,
+    pub searching: bool,
+    pub scroll: u16,
+    /// Furthest the list can scroll at the current size; set while drawing.
+    pub scroll_max: AtomicU16,
+}
+
+impl Cheatsheet {
+    pub fn scroll_by(&mut self, rows: i32) {
+        let max = self.scroll_max.load(Ordering::Relaxed) as i32;
+        self.scroll = (self.scroll as i32 + rows).clamp(0, max) as u16;
     }
 }
 
+/// In-list `/` search over the current task list. `editing` is true while
+/// the query is still being typed; afterwards `n` / `N` cycle through matches.
+#[derive(Debug, Clone, Default)]
+pub struct TaskSearch {
+    pub query: String,
+    pub editing: bool,
+}
+
 // Tracks what was in local state before an optimistic mutation so we can
 // revert if the server rejects the command.
 pub enum OptimisticOp {
     TaskAdded {
         temp_id: String,
     },
-    #[allow(dead_code)] // Used once delete task (d) is wired up.
     TaskRemoved {
         snapshot: Task,
     },
@@ -280,9 +960,30 @@ pub enum OptimisticOp {
         project_id: String,
         before: Project,
     },
+    DayOrdersUpdated {
+        before: Vec<(String, Option<i32>)>,
+    },
+    SectionOrdersUpdated {
+        before: Vec<(String, Option<i32>)>,
+    },
+    ProjectOrdersUpdated {
+        before: Vec<(String, i32)>,
+    },
+    /// Favorite labels' (`labels`) or filters' `item_order`s.
+    FavoriteOrdersUpdated {
+        labels: bool,
+        before: Vec<(String, Option<i32>)>,
+    },
+    /// Several tasks changed by one command: a shared label rename or
+    /// removal, or a move that carries subtasks along.
+    TasksChanged {
+        before: Vec<Task>,
+    },
 }
 
 pub enum ProjectEntry {
+    FavoritesHeader,
+    Favorite(FavoriteView),
     PersonalHeader,
     WorkspaceHeader(usize),
     FolderHeader(usize),
@@ -291,30 +992,104 @@ pub enum ProjectEntry {
     TodayView,
 }
 
+#[derive(Clone)]
 pub enum ProjectNavItem {
+    Favorite(FavoriteView),
     Folder(usize),
     Project(usize),
     TodayView,
 }
 
+/// The sidebar as last built, for the filter query it was built with.
+struct ProjectListCache {
+    query: Option<String>,
+    entries: Arc<[ProjectEntry]>,
+    nav: Arc<[ProjectNavItem]>,
+}
+
+/// A favorited label or filter opened from the sidebar, by id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FavoriteView {
+    Label(String),
+    Filter(String),
+}
+
+/// Rows of the workspace overview; only `Project` rows are selectable.
+pub enum OverviewRow {
+    Workspace(usize),
+    Folder(usize),
+    Project(usize),
+}
+
+/// A background fetch, for spotting an identical one already in flight.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Fetch {
+    /// Keyed by `comments_fetch_seq` too: after the thread changes, a fetch
+    /// started before it no longer counts.
+    Comments {
+        task_id: String,
+        seq: u64,
+    },
+    CompletedTasks(String),
+    History(String),
+}
+
+/// Counters shared with background jobs, shown by the `--debug` overlay.
+#[derive(Default)]
+pub struct PerfCounters {
+    pub in_flight: AtomicUsize,
+    /// Round-trip of the most recent sync request in ms; 0 until one finishes.
+    pub last_sync_ms: AtomicU64,
+}
+
+impl PerfCounters {
+    fn record_sync(&self, started: Instant) {
+        self.last_sync_ms
+            .store(started.elapsed().as_millis() as u64, Ordering::Relaxed);
+    }
+}
+
 enum BgResult {
     SyncDelta(Box<SyncResponse>),
-    CommandResults(Box<SyncResponse>),
-    CommandFailed {
+    /// `uuids` are the commands as sent, in order.
+    CommandResults {
+        resp: Box<SyncResponse>,
         uuids: Vec<String>,
     },
+    CommandFailed {
+        commands: Vec<SyncCommand>,
+        error: String,
+    },
+    SyncFailed(String),
     CompletedTasks {
         project_id: String,
         records: Result<Vec<Task>>,
     },
     WebSocketConnected,
-    WebSocketEvent,
+    /// The resources the message says changed, if it says.
+    WebSocketEvent(Option<&'static [&'static str]>),
     WebSocketDisconnected,
     Comments {
         task_id: String,
         comments: Result<Vec<Comment>>,
         fetch_seq: u64,
     },
+    PrefetchedComments {
+        task_id: String,
+        comments: Result<Vec<Comment>>,
+    },
+    History {
+        task_id: String,
+        events: Result<Vec<ActivityEvent>>,
+    },
+    ThemesChanged,
+    UpdateAvailable(String),
+    WorkspaceUsers {
+        workspace_id: String,
+        users: Result<Vec<WorkspaceUser>>,
+    },
+    ProductivityStats(Result<ProductivityStats>),
+    QuickAdded(Result<Box<Task>>),
 }
 
 pub struct App {
@@ -323,104 +1098,350 @@ pub struct App {
     pub folders: Vec<Folder>,
     pub tasks: Vec<Task>,
     pub labels: Vec<Label>,
+    pub filters: Vec<Filter>,
+    /// Completion counts and goals as of the last stats fetch.
+    pub productivity: Option<ProductivityStats>,
+    /// Completions (minus reopens) made here since `productivity` was fetched.
+    completions_since_stats: i32,
     pub sections: Vec<Section>,
     pub selected_project: usize,
     pub selected_task: usize,
     pub active_pane: Pane,
     pub running: bool,
     pub error: Option<AppError>,
+    /// Named in copied error reports; `--log-dir` overrides it from main.
+    pub log_dir: std::path::PathBuf,
     pub input_mode: InputMode,
     pub show_settings: bool,
-    pub show_help: bool,
+    pub help: Option<Cheatsheet>,
+    /// The first-run tour, while it's showing.
+    pub tour: Option<Tour>,
     pub show_input: bool,
     pub input_buffer: String,
     pub settings_selection: usize,
     pub collapsed: HashSet<String>,
-    pub detail_scroll: u16,
+    /// Tasks pinned to the dock; local only, unlike Todoist favorites.
+    pub pinned: HashSet<String>,
+    /// Private notes by task id, kept in `local_notes.json` and never synced.
+    pub local_notes: HashMap<String, String>,
+    /// Unsubmitted input text by context (see [`App::draft_key`]), kept in
+    /// `drafts.json`.
+    drafts: HashMap<String, String>,
+    /// The context of the open input, and its text when it opened.
+    draft_context: Option<String>,
+    draft_base: String,
+    /// Set when `drafts` changed and hasn't been written since.
+    drafts_dirty_since: Option<Instant>,
+    /// Rows the detail pane's comment thread is scrolled by.
+    pub comments_scroll: u16,
+    /// Furthest the thread can scroll at the current size; set while drawing.
+    pub comments_scroll_max: AtomicU16,
+    /// Offset the thread was last drawn at, which differs from
+    /// `comments_scroll` when it was moved to show the selected comment.
+    pub comments_scroll_drawn: AtomicU16,
+    /// Comment under the detail pane's `[` / `]` cursor.
+    pub selected_comment: Option<usize>,
+    /// Author of the comment being replied to, notified when it's sent.
+    reply_notify: Option<String>,
     pub sort_mode: SortMode,
+    pub sort_descending: bool,
     pub comments: Vec<Comment>,
     pub comment_input: bool,
+    /// The input popup holds a one-line quick add for the server to parse.
+    pub quick_add_input: bool,
     pub detail_field: usize,
     pub show_priority_picker: bool,
+    /// Highlighted row of the detail pane's section picker; row 0 is
+    /// "no section".
+    pub section_picker: Option<usize>,
+    /// Highlighted row of the task form's label picker, while it is open.
+    pub form_label_picker: Option<usize>,
     pub priority_selection: u8,
     pub editing_field: bool,
     pub task_form: Option<TaskForm>,
     pub current_user_id: Option<String>,
     pub user_names: HashMap<String, UserRecord>,
     pub task_filter: TaskFilter,
+    project_views: HashMap<String, ProjectView>,
+    /// View for projects without a remembered one (`default_sort` setting).
+    default_project_view: ProjectView,
+    start_view: StartView,
+    /// The previous session, until the initial sync restores it.
+    last_session: Option<Session>,
     pub dock_focus: Option<usize>,
     pub dock_filter: Option<DockItem>,
+    /// `[dock] filters` from the config, resolved whenever filters sync.
+    custom_dock_entries: Vec<String>,
+    pub custom_dock: Vec<CustomDock>,
     pub themes: Vec<crate::ui::theme::Theme>,
     pub theme_idx: usize,
     pub show_theme_picker: bool,
     pub theme_selection: usize,
+    /// Kept alive for the lifetime of the app; dropping it stops the watch.
+    theme_watcher: Option<notify::RecommendedWatcher>,
     pub websocket_connected: bool,
     pub sync_token: String,
     pub completed_cache: HashMap<String, Vec<Task>>,
     pub comments_by_task: HashMap<String, Vec<Comment>>,
+    /// Whether the detail pane's History section is expanded; it stays
+    /// expanded from task to task until toggled off.
+    pub show_history: bool,
+    /// Activity log per task, or why it couldn't be loaded. Missing while
+    /// the first fetch is in flight.
+    pub task_history: HashMap<String, Result<Vec<ActivityEvent>, String>>,
     pub idle_timeout_secs: u64,
+    /// See `Settings::poll_interval_secs`.
+    pub poll_interval_secs: u64,
+    /// When the websocket fallback last polled.
+    last_poll: Instant,
+    pub sidebar_pct: u16,
+    pub zen_mode: bool,
+    flash: Option<(String, Instant)>,
+    pub confirm: Option<Confirm>,
+    pub triage: Option<Triage>,
+    pub show_recurring_prompt: bool,
+    pub focus: Option<FocusSession>,
+    pub focus_minutes: u64,
+    pub focus_notify: bool,
+    pub plain_mode: bool,
+    pub priority_marker: PriorityMarker,
+    pub statusbar: crate::ui::statusbar::StatusbarLayout,
+    hooks: HooksConfig,
+    pub remap: crate::keymap::Remap,
+    pub show_clock: bool,
+    pub key_hints: KeyHints,
+    pub show_preview: bool,
+    pub title_overflow: TitleOverflow,
     pub idle_forcer: bool,
     pub ephemeral: bool,
     pub last_sync_at: Option<chrono::DateTime<Local>>,
     pub collapsed_folders: HashSet<String>,
+    pub project_filter: Option<String>,
+    /// Rebuilt on demand after `invalidate_project_list`, which everything
+    /// that changes projects, workspaces, folders or favorites calls.
+    project_list_cache: RefCell<Option<ProjectListCache>>,
+    pub project_jumper: Option<ProjectJumper>,
+    pub find_replace: Option<FindReplace>,
+    pub label_manager: Option<LabelManager>,
+    pub recurrence_editor: Option<RecurrenceEditor>,
+    /// Highlighted day of the calendar opened from a due-date input.
+    pub date_picker: Option<chrono::NaiveDate>,
+    /// Invites, assignments and comments from the notification center,
+    /// newest first.
+    pub notifications: Vec<LiveNotification>,
+    /// Who can access each shared project, including pending invites.
+    pub collaborator_states: Vec<CollaboratorState>,
+    pub show_project_info: bool,
+    pub show_workspace_overview: bool,
+    /// Index into the overview's project rows.
+    pub workspace_overview_selection: usize,
+    /// Fetched when the overview first opens; keyed by workspace id.
+    pub workspace_users: HashMap<String, Vec<WorkspaceUser>>,
+    pub show_notifications: bool,
+    pub notification_selection: usize,
+    /// Newest first, one entry per task.
+    pub recent: Vec<RecentTask>,
+    /// Selection in the recent panel, while it is open.
+    pub recent_panel: Option<usize>,
+    pub task_search: Option<TaskSearch>,
+    /// Open tabs; empty until a second one is opened.
+    pub tabs: Vec<ViewTab>,
+    pub active_tab: usize,
+    /// The list last settled on, and the lists left before it (`Ctrl-o`)
+    /// and after it (`Alt-→`).
+    jump_here: Option<Jump>,
+    jumps_back: Vec<Jump>,
+    jumps_forward: Vec<Jump>,
     pub folder_cursor: Option<usize>,
     pub current_user_name: Option<String>,
+    /// The Todoist user record: start page, goals and timezone.
+    pub account: Option<UserInfo>,
     pub today_view_active: bool,
+    pub favorite_view: Option<FavoriteView>,
     pub overdue_section_collapsed: bool,
     last_activity: Instant,
     pending_ws_sync: bool,
     comments_fetch_seq: u64,
+    /// Tasks whose comments were already requested by the project prefetch.
+    comments_prefetched: HashSet<String>,
     websocket_url: Option<String>,
     pending_commands: Vec<SyncCommand>,
+    /// When the oldest command in `pending_commands` was queued.
+    commands_queued_since: Option<Instant>,
+    /// Set while quitting waits for unsynced changes to go through.
+    quit_deadline: Option<Instant>,
     temp_id_pending: HashMap<String, OptimisticOp>,
+    pub check_updates: bool,
+    /// Newer release found by the update check.
+    pub latest_version: Option<String>,
+    /// `--debug`: draw the performance overlay.
+    pub debug_overlay: bool,
+    pub perf: Arc<PerfCounters>,
+    /// Permits for `MAX_CONCURRENT_FETCHES`.
+    fetch_slots: Arc<tokio::sync::Semaphore>,
+    fetches_in_flight: HashSet<Fetch>,
+    /// Time spent in the last `terminal.draw`.
+    pub frame_time: Duration,
+    /// Time spent handling input and background results in the last loop turn.
+    pub loop_latency: Duration,
     bg_tx: mpsc::Sender<BgResult>,
     bg_rx: mpsc::Receiver<BgResult>,
     client: Arc<TodoistClient>,
 }
 
-fn load_theme_idx(themes: &[crate::ui::theme::Theme]) -> usize {
-    let path = ratatoist_core::config::Config::config_dir().join("ui_settings.json");
-    if let Ok(src) = std::fs::read_to_string(&path)
-        && let Ok(val) = serde_json::from_str::<serde_json::Value>(&src)
-        && let Some(name) = val["theme"].as_str()
-        && let Some(idx) = themes.iter().position(|t| t.name == name)
-    {
-        return idx;
-    }
-    0
+pub const SIDEBAR_PCT_MIN: u16 = 15;
+pub const SIDEBAR_PCT_MAX: u16 = 60;
+const SIDEBAR_PCT_STEP: u16 = 5;
+
+/// Sort and filter chosen for one project, restored when it is reopened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProjectView {
+    pub sort: SortMode,
+    pub descending: bool,
+    pub filter: TaskFilter,
 }
 
-fn load_idle_timeout_secs() -> u64 {
-    let path = ratatoist_core::config::Config::config_dir().join("ui_settings.json");
-    if let Ok(src) = std::fs::read_to_string(&path)
-        && let Ok(val) = serde_json::from_str::<serde_json::Value>(&src)
-    {
-        if let Some(secs) = val["idle_timeout_secs"].as_u64() {
-            return secs;
-        }
-        if let Some(mins) = val["idle_timeout_mins"].as_u64() {
-            return mins * 60;
-        }
-    }
-    300
+/// An open tab: the list the task pane shows and how it is shown. The
+/// active tab's entry goes stale while it is open; the live state is on
+/// [`App`] and is written back when switching away.
+#[derive(Debug, Clone)]
+pub struct ViewTab {
+    project_id: Option<String>,
+    today: bool,
+    favorite: Option<FavoriteView>,
+    dock_filter: Option<DockItem>,
+    selected_task: usize,
+    view: ProjectView,
 }
 
-impl App {
-    pub fn theme(&self) -> &crate::ui::theme::Theme {
-        &self.themes[self.theme_idx]
+impl ViewTab {
+    /// Whether both show the same list, however it is sorted or scrolled.
+    fn same_list(&self, other: &ViewTab) -> bool {
+        self.today == other.today
+            && self.favorite == other.favorite
+            && self.dock_filter == other.dock_filter
+            && (self.today
+                || self.favorite.is_some()
+                || self.dock_filter.is_some()
+                || self.project_id == other.project_id)
     }
+}
 
-    pub fn cycle_task_filter(&mut self) {
-        self.task_filter = self.task_filter.next();
-        if matches!(self.task_filter, TaskFilter::Done | TaskFilter::Both)
-            && let Some(pid) = self
-                .projects
-                .get(self.selected_project)
-                .map(|p| p.id.clone())
-            && !self.completed_cache.contains_key(&pid)
-        {
-            self.spawn_completed_tasks_fetch(pid);
-        }
+/// A jumplist entry: a list and the task selected in it.
+#[derive(Debug, Clone)]
+struct Jump {
+    tab: ViewTab,
+    task_id: Option<String>,
+}
+
+/// Oldest jumplist entries are dropped past this many.
+const MAX_JUMPS: usize = 100;
+
+/// Where the user was on exit, kept in `ui_state.json` for
+/// `default_view = "last"`. Ids rather than indexes, so it survives
+/// projects and tasks moving around in between.
+#[derive(Debug, Default)]
+struct Session {
+    project_id: Option<String>,
+    task_id: Option<String>,
+    pane: Option<Pane>,
+    today: bool,
+    favorite: Option<FavoriteView>,
+    /// [`dock_key`] of the active dock filter.
+    dock: Option<String>,
+}
+
+fn load_session(state: &serde_json::Value) -> Option<Session> {
+    let s = state.get("session")?;
+    let text = |key: &str| s[key].as_str().map(str::to_string);
+    let favorite = match (text("favorite_label"), text("favorite_filter")) {
+        (Some(id), _) => Some(FavoriteView::Label(id)),
+        (None, Some(id)) => Some(FavoriteView::Filter(id)),
+        (None, None) => None,
+    };
+    Some(Session {
+        project_id: text("project"),
+        task_id: text("task"),
+        pane: match s["pane"].as_str() {
+            Some("projects") => Some(Pane::Projects),
+            Some("tasks") => Some(Pane::Tasks),
+            Some("detail") => Some(Pane::Detail),
+            _ => None,
+        },
+        today: s["today"].as_bool().unwrap_or(false),
+        favorite,
+        dock: text("dock"),
+    })
+}
+
+/// Reads `ui_state.json`: per-project views, fold state and the last session.
+fn load_ui_state(config_dir: &std::path::Path) -> serde_json::Value {
+    std::fs::read_to_string(Settings::ui_state_path(config_dir))
+        .ok()
+        .and_then(|src| serde_json::from_str(&src).ok())
+        .unwrap_or_default()
+}
+
+fn load_project_views(state: &serde_json::Value) -> HashMap<String, ProjectView> {
+    let Some(views) = state["project_views"].as_object() else {
+        return HashMap::new();
+    };
+    views
+        .iter()
+        .map(|(pid, v)| {
+            let view = ProjectView {
+                sort: v["sort"]
+                    .as_str()
+                    .and_then(SortMode::from_label)
+                    .unwrap_or(SortMode::Default),
+                descending: v["descending"].as_bool().unwrap_or(false),
+                filter: v["filter"]
+                    .as_str()
+                    .and_then(TaskFilter::from_label)
+                    .unwrap_or(TaskFilter::Active),
+            };
+            (pid.clone(), view)
+        })
+        .collect()
+}
+
+/// Reads a JSON object of strings such as `local_notes.json`; empty when the
+/// file is missing or unreadable.
+fn load_string_map(path: &std::path::Path) -> HashMap<String, String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|src| serde_json::from_str(&src).ok())
+        .unwrap_or_default()
+}
+
+/// Reads a persisted list of ids (fold state) from `ui_state.json`.
+fn load_id_set(state: &serde_json::Value, key: &str) -> HashSet<String> {
+    state[key]
+        .as_array()
+        .map(|ids| {
+            ids.iter()
+                .filter_map(|id| id.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl App {
+    /// The active theme; while the picker is open, the highlighted one, so
+    /// the whole UI previews it.
+    pub fn theme(&self) -> &crate::ui::theme::Theme {
+        let idx = if self.show_theme_picker {
+            self.theme_selection
+        } else {
+            self.theme_idx
+        };
+        &self.themes[idx]
+    }
+
+    pub fn cycle_task_filter(&mut self) {
+        self.task_filter = self.task_filter.next();
+        self.remember_project_view();
+        self.fetch_completed_if_needed();
         let visible_len = self.visible_tasks().len();
         if visible_len == 0 {
             self.selected_task = 0;
@@ -429,6 +1450,28 @@ impl App {
         }
     }
 
+    fn fetch_completed_if_needed(&mut self) {
+        if matches!(self.task_filter, TaskFilter::Done | TaskFilter::Both)
+            && let Some(pid) = self
+                .projects
+                .get(self.selected_project)
+                .map(|p| p.id.clone())
+            && !self.completed_cache.contains_key(&pid)
+        {
+            self.spawn_completed_tasks_fetch(pid);
+        }
+    }
+
+    /// Commands sent to Todoist that haven't been confirmed yet.
+    pub fn pending_op_count(&self) -> usize {
+        self.temp_id_pending.len() + self.pending_commands.len()
+    }
+
+    /// The request budget Todoist last reported, if it has.
+    pub fn rate_budget(&self) -> Option<RateBudget> {
+        self.client.metrics().budget()
+    }
+
     pub fn sync_age_label(&self) -> String {
         match self.last_sync_at {
             Some(at) => at.format("%Y-%m-%d %H:%M").to_string(),
@@ -457,37 +1500,371 @@ impl App {
         self.save_ui_settings();
     }
 
+    pub fn toggle_preview(&mut self) {
+        self.show_preview = !self.show_preview;
+        self.save_ui_settings();
+    }
+
+    pub fn cycle_title_overflow(&mut self) {
+        self.title_overflow = self.title_overflow.next();
+        self.save_ui_settings();
+    }
+
+    pub fn cycle_focus_minutes(&mut self) {
+        let pos = FOCUS_OPTIONS
+            .iter()
+            .position(|&m| m == self.focus_minutes)
+            .unwrap_or(0);
+        self.focus_minutes = FOCUS_OPTIONS[(pos + 1) % FOCUS_OPTIONS.len()];
+        self.save_ui_settings();
+    }
+
+    pub fn toggle_focus_notify(&mut self) {
+        self.focus_notify = !self.focus_notify;
+        self.save_ui_settings();
+    }
+
+    pub fn toggle_plain_mode(&mut self) {
+        self.plain_mode = !self.plain_mode;
+        self.save_ui_settings();
+    }
+
+    pub fn cycle_priority_marker(&mut self) {
+        self.priority_marker = self.priority_marker.next();
+        self.save_ui_settings();
+    }
+
+    pub fn toggle_clock(&mut self) {
+        self.show_clock = !self.show_clock;
+        self.save_ui_settings();
+    }
+
+    pub fn cycle_key_hints(&mut self) {
+        self.key_hints = self.key_hints.next();
+        self.save_ui_settings();
+    }
+
+    pub fn cycle_poll_interval(&mut self) {
+        let pos = POLL_OPTIONS
+            .iter()
+            .position(|&v| v == self.poll_interval_secs)
+            .unwrap_or(0);
+        self.poll_interval_secs = POLL_OPTIONS[(pos + 1) % POLL_OPTIONS.len()];
+        self.save_ui_settings();
+    }
+
+    pub fn toggle_check_updates(&mut self) {
+        self.check_updates = !self.check_updates;
+        self.save_ui_settings();
+        if self.check_updates && self.latest_version.is_none() {
+            self.spawn_update_check();
+        }
+    }
+
+    fn start_focus(&mut self) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        if task.checked {
+            return;
+        }
+        info!(task_id = %task.id, minutes = self.focus_minutes, "focus session started");
+        self.focus = Some(FocusSession {
+            task_id: task.id.clone(),
+            content: task.content.clone(),
+            started_at: Local::now(),
+            started: Instant::now(),
+            length: Duration::from_secs(self.focus_minutes * 60),
+        });
+    }
+
+    /// Ends the session once its countdown runs out.
+    fn tick_focus(&mut self) {
+        if self.focus.as_ref().is_some_and(|f| f.remaining().is_zero()) {
+            self.finish_focus();
+        }
+    }
+
+    /// Ends the focus session and logs the time spent as a comment on the task.
+    fn finish_focus(&mut self) {
+        let Some(session) = self.focus.take() else {
+            return;
+        };
+        let spent = session.started.elapsed().min(session.length);
+        let minutes = spent.as_secs() / 60;
+        info!(task_id = %session.task_id, minutes, "focus session finished");
+        if minutes == 0 {
+            self.flash("Focus session under a minute, not logged");
+            return;
+        }
+
+        let ended_at = session.started_at + chrono::Duration::minutes(minutes as i64);
+        let note = format!(
+            "Focus session: {minutes} min ({}–{})",
+            session.started_at.format("%H:%M"),
+            ended_at.format("%H:%M")
+        );
+        self.add_comment(&session.task_id, note);
+
+        if session.remaining().is_zero() && self.focus_notify {
+            let body = format!("{minutes} min on \"{}\"", session.content);
+            if let Err(e) = crate::notify::send("Focus session complete", &body) {
+                warn!(error = %e, "desktop notification failed");
+            }
+        }
+        self.flash(format!("Logged {minutes} min of focus"));
+    }
+
+    fn abandon_focus(&mut self) {
+        if let Some(session) = self.focus.take() {
+            info!(task_id = %session.task_id, "focus session abandoned");
+            self.flash("Focus session abandoned");
+        }
+    }
+
+    /// Shows a short confirmation in the statusbar for a few seconds.
+    pub fn flash(&mut self, message: impl Into<String>) {
+        self.flash = Some((message.into(), Instant::now()));
+    }
+
+    pub fn flash_message(&self) -> Option<&str> {
+        self.flash
+            .as_ref()
+            .filter(|(_, at)| at.elapsed() < FLASH_DURATION)
+            .map(|(msg, _)| msg.as_str())
+    }
+
+    fn yank_selected_task(&mut self, target: YankTarget) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let url = crate::browser::task_url(&task.id);
+        let (text, what) = match target {
+            YankTarget::Content => (task.content.clone(), "task title"),
+            YankTarget::Url => (url, "task URL"),
+            YankTarget::Markdown => (format!("[{}]({url})", task.content), "markdown link"),
+        };
+        match crate::clipboard::copy(&text) {
+            Ok(()) => self.flash(format!("Copied {what}")),
+            Err(e) => self.set_error(&e, "copying to clipboard"),
+        }
+    }
+
+    fn open_selected_task_in_browser(&mut self) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let url = crate::browser::task_url(&task.id);
+        match crate::browser::open(&url) {
+            Ok(()) => self.flash("Opened in browser"),
+            Err(e) => self.set_error(&e, "opening the browser"),
+        }
+    }
+
+    /// URLs shown in the detail pane, numbered in display order: the
+    /// description first, then each comment's text and attachment.
+    pub fn detail_links(&self) -> Vec<String> {
+        let Some(task) = self.selected_task() else {
+            return Vec::new();
+        };
+        let mut links: Vec<String> = crate::ui::text::extract_urls(&task.description)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        for comment in &self.comments {
+            links.extend(
+                crate::ui::text::extract_urls(&comment.content)
+                    .into_iter()
+                    .map(str::to_string),
+            );
+            if let Some(url) = comment_attachment_url(comment) {
+                links.push(url.to_string());
+            }
+        }
+        links
+    }
+
+    /// Opens the `n`-th (0-based) detail-pane link in the browser.
+    pub fn open_detail_link(&mut self, n: usize) {
+        let Some(url) = self.detail_links().into_iter().nth(n) else {
+            return;
+        };
+        match crate::browser::open(&url) {
+            Ok(()) => self.flash(format!("Opened link [{}]", n + 1)),
+            Err(e) => self.set_error(&e, "opening the browser"),
+        }
+    }
+
+    /// Zen mode hides the Projects pane and StatsDock; focus is pinned to the
+    /// task list (or the detail pane opened from it).
+    pub fn toggle_zen_mode(&mut self) {
+        self.zen_mode = !self.zen_mode;
+        if self.zen_mode {
+            self.dock_focus = None;
+            self.folder_cursor = None;
+            self.show_settings = false;
+            if !matches!(self.active_pane, Pane::Detail) {
+                self.active_pane = Pane::Tasks;
+            }
+        }
+        info!(zen = self.zen_mode, "zen mode toggled");
+    }
+
+    /// Grows (`grow = true`) or shrinks the Projects/Stats column by one step.
+    pub fn resize_sidebar(&mut self, grow: bool) {
+        let pct = if grow {
+            self.sidebar_pct.saturating_add(SIDEBAR_PCT_STEP)
+        } else {
+            self.sidebar_pct.saturating_sub(SIDEBAR_PCT_STEP)
+        };
+        let pct = pct.clamp(SIDEBAR_PCT_MIN, SIDEBAR_PCT_MAX);
+        if pct != self.sidebar_pct {
+            self.sidebar_pct = pct;
+            debug!(sidebar_pct = pct, "sidebar resized");
+            self.save_ui_settings();
+        }
+    }
+
+    /// Writes the user-facing preferences to `config.toml`.
     pub fn save_ui_settings(&self) {
+        if self.ephemeral {
+            return;
+        }
+        let settings = Settings {
+            theme: Some(self.themes[self.theme_idx].name.clone()),
+            input_mode: match self.input_mode {
+                InputMode::Vim(_) => "vim",
+                InputMode::Standard => "standard",
+            }
+            .to_string(),
+            default_view: self.start_view.label().to_string(),
+            default_sort: self.default_project_view.sort.label().to_string(),
+            default_sort_descending: self.default_project_view.descending,
+            idle_timeout_secs: self.idle_timeout_secs,
+            poll_interval_secs: self.poll_interval_secs,
+            sidebar_pct: self.sidebar_pct,
+            show_preview: self.show_preview,
+            title_overflow: self.title_overflow.label().to_string(),
+            focus_minutes: self.focus_minutes,
+            focus_notify: self.focus_notify,
+            // Don't persist what NO_COLOR forced on.
+            plain_mode: self.plain_mode && !crate::ui::plain::no_color_env(),
+            priority_marker: self.priority_marker.label().to_string(),
+            show_clock: self.show_clock,
+            key_hints: self.key_hints.label().to_string(),
+            check_updates: self.check_updates,
+            keymap: None,
+            statusbar: Default::default(),
+            hooks: Default::default(),
+            dock: Default::default(),
+        };
+        let dir = ratatoist_core::config::Config::config_dir();
+        if let Err(e) = settings.save(&dir) {
+            warn!(error = %e, "failed to save settings");
+        }
+    }
+
+    /// Writes per-project views, fold state and the session to `ui_state.json`.
+    pub fn save_ui_state(&self) {
         if self.ephemeral {
             return;
         }
         let dir = ratatoist_core::config::Config::config_dir();
         let _ = std::fs::create_dir_all(&dir);
-        let path = dir.join("ui_settings.json");
-        let name = &self.themes[self.theme_idx].name;
+        // Forget folds on tasks that no longer exist so the list doesn't grow forever.
+        let mut collapsed_tasks: Vec<&String> = self
+            .collapsed
+            .iter()
+            .filter(|id| self.tasks.is_empty() || self.tasks.iter().any(|t| &t.id == *id))
+            .collect();
+        collapsed_tasks.sort();
+        // Pins go once their task is done or gone.
+        let mut pinned: Vec<&String> = self
+            .pinned
+            .iter()
+            .filter(|id| {
+                self.tasks.is_empty() || self.tasks.iter().any(|t| &t.id == *id && !t.checked)
+            })
+            .collect();
+        pinned.sort();
+        let mut collapsed_folders: Vec<&String> = self.collapsed_folders.iter().collect();
+        collapsed_folders.sort();
         let json = serde_json::json!({
-            "theme": name,
-            "idle_timeout_secs": self.idle_timeout_secs,
+            "project_views": self
+                .project_views
+                .iter()
+                .map(|(pid, v)| {
+                    let view = serde_json::json!({
+                        "sort": v.sort.label(),
+                        "descending": v.descending,
+                        "filter": v.filter.label(),
+                    });
+                    (pid.clone(), view)
+                })
+                .collect::<serde_json::Map<_, _>>(),
+            "collapsed_tasks": collapsed_tasks,
+            "pinned_tasks": pinned,
+            "collapsed_folders": collapsed_folders,
+            // Before the first sync there is nothing to record yet; keep the
+            // previous session rather than forget it.
+            "session": if self.projects.is_empty() {
+                load_ui_state(&dir)["session"].take()
+            } else {
+                self.session_json()
+            },
         });
         let _ = std::fs::write(
-            &path,
+            Settings::ui_state_path(&dir),
             serde_json::to_string_pretty(&json).unwrap_or_default(),
         );
     }
 
-    pub fn new(client: TodoistClient, idle_forcer: bool, ephemeral: bool) -> Self {
+    pub fn new(
+        client: TodoistClient,
+        settings: Settings,
+        idle_forcer: bool,
+        ephemeral: bool,
+        debug: bool,
+    ) -> Self {
         let (bg_tx, bg_rx) = mpsc::channel(64);
         let mut themes = crate::ui::theme::Theme::builtin();
         let user_themes_dir = ratatoist_core::config::Config::config_dir().join("themes");
         themes.extend(crate::ui::theme::Theme::load_user_themes(&user_themes_dir));
-        let theme_idx = load_theme_idx(&themes);
         let config_dir = ratatoist_core::config::Config::config_dir();
+        let state = load_ui_state(&config_dir);
+        let theme_idx = settings
+            .theme
+            .as_deref()
+            .and_then(|name| themes.iter().position(|t| t.name == name))
+            .unwrap_or(0);
         let sync_token = if ephemeral {
             "*".to_string()
         } else {
             SyncState::load(&config_dir).sync_token
         };
-        let idle_timeout_secs = load_idle_timeout_secs();
+        let input_mode = if settings.input_mode == "standard" {
+            InputMode::Standard
+        } else {
+            InputMode::Vim(VimState::Normal)
+        };
+        let (remap, remap_error) = match settings.keymap.as_deref() {
+            Some(path) => match crate::keymap::Remap::load(&config_dir.join(path)) {
+                Ok(remap) => (remap, None),
+                Err(e) => {
+                    warn!(error = %e, "failed to load keymap");
+                    (
+                        Default::default(),
+                        Some((format!("Keymap not loaded: {e:#}"), Instant::now())),
+                    )
+                }
+            },
+            None => (Default::default(), None),
+        };
+        let default_project_view = ProjectView {
+            sort: SortMode::from_label(&settings.default_sort).unwrap_or(SortMode::Default),
+            descending: settings.default_sort_descending,
+            filter: TaskFilter::Active,
+        };
 
         Self {
             projects: Vec::new(),
@@ -495,56 +1872,147 @@ impl App {
             folders: Vec::new(),
             tasks: Vec::new(),
             labels: Vec::new(),
+            filters: Vec::new(),
+            productivity: None,
+            completions_since_stats: 0,
             sections: Vec::new(),
             selected_project: 0,
             selected_task: 0,
             active_pane: Pane::Projects,
             running: true,
             error: None,
-            input_mode: InputMode::Vim(VimState::Normal),
+            log_dir: ratatoist_core::logging::default_log_dir(),
+            input_mode,
             show_settings: false,
-            show_help: false,
+            help: None,
+            tour: None,
             show_input: false,
             input_buffer: String::new(),
             settings_selection: 0,
-            collapsed: HashSet::new(),
-            detail_scroll: 0,
-            sort_mode: SortMode::Default,
+            collapsed: load_id_set(&state, "collapsed_tasks"),
+            pinned: load_id_set(&state, "pinned_tasks"),
+            local_notes: load_string_map(&Settings::local_notes_path(&config_dir)),
+            drafts: load_string_map(&Settings::drafts_path(&config_dir)),
+            draft_context: None,
+            draft_base: String::new(),
+            drafts_dirty_since: None,
+            comments_scroll: 0,
+            comments_scroll_max: AtomicU16::new(0),
+            comments_scroll_drawn: AtomicU16::new(0),
+            selected_comment: None,
+            reply_notify: None,
+            sort_mode: default_project_view.sort,
+            sort_descending: default_project_view.descending,
             comments: Vec::new(),
             comment_input: false,
+            quick_add_input: false,
             detail_field: 0,
             show_priority_picker: false,
+            section_picker: None,
+            form_label_picker: None,
             priority_selection: 1,
             editing_field: false,
             task_form: None,
             task_filter: TaskFilter::Active,
+            project_views: load_project_views(&state),
+            default_project_view,
+            start_view: StartView::from_label(&settings.default_view)
+                .unwrap_or(StartView::Projects),
+            last_session: load_session(&state),
             dock_focus: None,
             dock_filter: None,
+            custom_dock_entries: settings.dock.filters.clone(),
+            custom_dock: Vec::new(),
             current_user_id: None,
             user_names: HashMap::new(),
             themes,
             theme_idx,
             show_theme_picker: false,
             theme_selection: theme_idx,
+            theme_watcher: None,
             websocket_connected: false,
             sync_token,
             completed_cache: HashMap::new(),
             comments_by_task: HashMap::new(),
-            idle_timeout_secs,
+            show_history: false,
+            task_history: HashMap::new(),
+            idle_timeout_secs: settings.idle_timeout_secs,
+            poll_interval_secs: settings.poll_interval_secs,
+            last_poll: Instant::now(),
+            sidebar_pct: settings.sidebar_pct.clamp(SIDEBAR_PCT_MIN, SIDEBAR_PCT_MAX),
+            zen_mode: false,
+            flash: remap_error,
+            confirm: None,
+            triage: None,
+            show_recurring_prompt: false,
+            focus: None,
+            focus_minutes: if FOCUS_OPTIONS.contains(&settings.focus_minutes) {
+                settings.focus_minutes
+            } else {
+                FOCUS_OPTIONS[0]
+            },
+            focus_notify: settings.focus_notify,
+            plain_mode: settings.plain_mode || crate::ui::plain::no_color_env(),
+            priority_marker: PriorityMarker::from_label(&settings.priority_marker)
+                .unwrap_or(PriorityMarker::Dot),
+            statusbar: crate::ui::statusbar::StatusbarLayout::from_config(&settings.statusbar),
+            hooks: settings.hooks.clone(),
+            remap,
+            show_clock: settings.show_clock,
+            key_hints: KeyHints::from_label(&settings.key_hints).unwrap_or(KeyHints::Full),
+            show_preview: settings.show_preview,
+            title_overflow: TitleOverflow::from_label(&settings.title_overflow)
+                .unwrap_or(TitleOverflow::Truncate),
             idle_forcer,
             ephemeral,
             last_sync_at: None,
-            collapsed_folders: HashSet::new(),
+            collapsed_folders: load_id_set(&state, "collapsed_folders"),
+            project_filter: None,
+            project_list_cache: RefCell::new(None),
+            project_jumper: None,
+            find_replace: None,
+            label_manager: None,
+            recurrence_editor: None,
+            date_picker: None,
+            notifications: Vec::new(),
+            collaborator_states: Vec::new(),
+            show_project_info: false,
+            show_workspace_overview: false,
+            workspace_overview_selection: 0,
+            workspace_users: HashMap::new(),
+            show_notifications: false,
+            notification_selection: 0,
+            recent: Vec::new(),
+            recent_panel: None,
+            task_search: None,
+            tabs: Vec::new(),
+            jump_here: None,
+            jumps_back: Vec::new(),
+            jumps_forward: Vec::new(),
+            active_tab: 0,
             folder_cursor: None,
             current_user_name: None,
+            account: None,
             today_view_active: false,
+            favorite_view: None,
             overdue_section_collapsed: false,
             last_activity: Instant::now(),
             pending_ws_sync: false,
             comments_fetch_seq: 0,
+            comments_prefetched: HashSet::new(),
             websocket_url: None,
             pending_commands: Vec::new(),
+            commands_queued_since: None,
+            quit_deadline: None,
             temp_id_pending: HashMap::new(),
+            check_updates: settings.check_updates,
+            latest_version: None,
+            debug_overlay: debug,
+            perf: Arc::new(PerfCounters::default()),
+            fetch_slots: Arc::new(tokio::sync::Semaphore::new(MAX_CONCURRENT_FETCHES)),
+            fetches_in_flight: HashSet::new(),
+            frame_time: Duration::ZERO,
+            loop_latency: Duration::ZERO,
             bg_tx,
             bg_rx,
             client: Arc::new(client),
@@ -565,10 +2033,12 @@ impl App {
                 "projects".to_string(),
                 "sections".to_string(),
                 "labels".to_string(),
+                "filters".to_string(),
                 "notes".to_string(),
                 "collaborators".to_string(),
                 "workspaces".to_string(),
                 "folders".to_string(),
+                "live_notifications".to_string(),
                 "user".to_string(),
             ],
             commands: vec![],
@@ -578,12 +2048,40 @@ impl App {
             .draw(|f| ui::splash::render(f, 0.3, "syncing data...", self.theme()))
             .ok();
 
-        match self.client.sync(&req).await {
+        // The body is parsed as it arrives; redraw the splash with the
+        // running size so a large account doesn't look stuck.
+        let started = Instant::now();
+        let (progress_tx, mut progress_rx) = tokio::sync::watch::channel(0);
+        let result = {
+            let sync = self.client.sync_with_progress(&req, &progress_tx);
+            tokio::pin!(sync);
+            loop {
+                tokio::select! {
+                    result = &mut sync => break result,
+                    Ok(()) = progress_rx.changed() => {
+                        let received = *progress_rx.borrow_and_update();
+                        let status = format!(
+                            "syncing data... {:.1} MB",
+                            received as f64 / (1024.0 * 1024.0)
+                        );
+                        terminal
+                            .draw(|f| ui::splash::render(f, 0.3, &status, self.theme()))
+                            .ok();
+                    }
+                }
+            }
+        };
+        self.perf.record_sync(started);
+        match result {
             Ok(resp) => {
                 terminal
                     .draw(|f| ui::splash::render(f, 0.8, "applying sync...", self.theme()))
                     .ok();
                 self.apply_sync_delta(resp);
+                self.restore_project_view();
+                self.prefetch_project_comments();
+                self.open_start_view();
+                self.fetch_productivity_stats();
 
                 terminal
                     .draw(|f| ui::splash::render(f, 1.0, "ready", self.theme()))
@@ -602,6 +2100,7 @@ impl App {
                 }
             }
             Err(e) => {
+                self.fire_sync_error_hook("initial sync", &format!("{e:#}"));
                 self.set_error(&e, "Initial sync");
             }
         }
@@ -609,15 +2108,61 @@ impl App {
 
     pub async fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
         info!("entering main loop");
+        self.spawn_theme_watcher();
+        if self.check_updates {
+            self.spawn_update_check();
+        }
 
+        let mut busy = Duration::ZERO;
+        let mut redraw = true;
+        let mut drawn_idle = false;
+        let mut drawn_minute = 0;
+        let mut drawn_flash = false;
         while self.running {
-            self.drain_bg_results();
-
-            terminal.draw(|frame| ui::draw(frame, self))?;
-
-            if event::poll(Duration::from_millis(16))?
-                && let Event::Key(key) = event::read()?
+            let turn = Instant::now();
+            let idle = self.is_idle();
+            redraw |= self.drain_bg_results();
+            self.tick_commands();
+            self.tick_poll();
+            self.tick_quit();
+            self.tick_drafts();
+            self.tick_focus();
+            self.loop_latency = busy + turn.elapsed();
+
+            // The statusbar shows idleness, so going idle is drawn once; a
+            // running focus timer keeps counting down on screen. The clock,
+            // date, sync age and relative due times move on each minute, and
+            // a flash is drawn until it has expired.
+            let minute = chrono::Utc::now().timestamp() / 60;
+            let flashing = self.flash_message().is_some();
+            if !idle
+                || redraw
+                || !drawn_idle
+                || self.focus.is_some()
+                || minute != drawn_minute
+                || flashing
+                || drawn_flash
             {
+                let draw_start = Instant::now();
+                terminal.draw(|frame| ui::draw(frame, self))?;
+                self.frame_time = draw_start.elapsed();
+                drawn_minute = minute;
+                drawn_flash = flashing;
+            }
+            drawn_idle = idle;
+            redraw = false;
+
+            busy = Duration::ZERO;
+            let poll = if idle && self.commands_queued_since.is_none() {
+                IDLE_POLL
+            } else {
+                ACTIVE_POLL
+            };
+            let ready = event::poll(poll)?;
+            // Keys and resizes alike.
+            redraw |= ready;
+            if ready && let Event::Key(key) = event::read()? {
+                let handled = Instant::now();
                 let was_idle = self.is_idle();
                 self.last_activity = Instant::now();
                 if was_idle && self.pending_ws_sync {
@@ -626,25 +2171,24 @@ impl App {
                 }
 
                 if self.error.is_some() {
-                    self.handle_error_dismiss();
+                    self.handle_error_key(key);
                     continue;
                 }
 
                 let prev_pane = self.active_pane;
                 match keys::handle_key(self, key) {
-                    KeyAction::Quit => {
-                        info!("quit requested");
-                        self.running = false;
-                    }
+                    KeyAction::Quit => self.request_quit(),
                     KeyAction::ProjectChanged => self.switch_to_project_tasks(),
                     KeyAction::TodayViewSelected => self.activate_today_view(),
+                    KeyAction::FavoriteSelected(view) => self.activate_favorite_view(view),
                     KeyAction::ToggleOverdueSection => self.toggle_overdue_section(),
                     KeyAction::OpenDetail => self.open_detail(),
                     KeyAction::CloseDetail => {
                         self.active_pane = Pane::Tasks;
-                        self.detail_scroll = 0;
+                        self.comments_scroll = 0;
                     }
                     KeyAction::ToggleSettings => {
+                        self.zen_mode = false;
                         self.show_settings = !self.show_settings;
                         self.active_pane = if self.show_settings {
                             Pane::Settings
@@ -652,19 +2196,30 @@ impl App {
                             Pane::Projects
                         };
                     }
-                    KeyAction::ToggleHelp => self.show_help = !self.show_help,
+                    KeyAction::ToggleHelp => {
+                        self.help = match self.help {
+                            Some(_) => None,
+                            None => Some(Cheatsheet::default()),
+                        };
+                    }
                     KeyAction::ToggleMode => self.toggle_input_mode(),
                     KeyAction::ToggleCollapse => self.toggle_collapse(),
                     KeyAction::ToggleFolderCollapse => self.toggle_folder_collapse(),
-                    KeyAction::OpenAllFolds => self.collapsed.clear(),
+                    KeyAction::OpenAllFolds => {
+                        self.collapsed.clear();
+                        self.save_ui_state();
+                    }
                     KeyAction::CloseAllFolds => self.close_all_folds(),
                     KeyAction::CompleteTask => self.complete_selected_task(),
+                    KeyAction::OpenParentTask => self.open_parent_task(),
+                    KeyAction::ToggleHistory => self.toggle_history(),
                     KeyAction::OpenPriorityPicker => {
                         if let Some(task) = self.selected_task() {
                             self.priority_selection = task.priority;
                             self.show_priority_picker = true;
                         }
                     }
+                    KeyAction::SetPriority(priority) => self.apply_priority(priority),
                     KeyAction::SelectPriority => {
                         self.show_priority_picker = false;
                         if let Some(form) = &mut self.task_form {
@@ -674,14 +2229,63 @@ impl App {
                         }
                     }
                     KeyAction::StarProject => self.star_selected_project(),
+                    KeyAction::OpenWorkspaceOverview => self.open_workspace_overview(),
+                    KeyAction::ShowProjectInfo => {
+                        self.show_project_info = !self.today_view_active
+                            && self.favorite_view.is_none()
+                            && self.folder_cursor.is_none();
+                    }
+                    KeyAction::ToggleZen => self.toggle_zen_mode(),
+                    KeyAction::YankContent => self.yank_selected_task(YankTarget::Content),
+                    KeyAction::YankUrl => self.yank_selected_task(YankTarget::Url),
+                    KeyAction::YankMarkdown => self.yank_selected_task(YankTarget::Markdown),
+                    KeyAction::Postpone(target) => self.postpone_selected_task(target),
+                    KeyAction::RescheduleOverdue => self.request_reschedule_overdue(),
+                    KeyAction::StartTriage => self.start_triage(),
+                    KeyAction::Triage(action) => self.triage_step(action),
+                    KeyAction::MoveDayOrder(delta) => self.move_day_order(delta),
+                    KeyAction::MoveSection(delta) => self.move_section(delta),
+                    KeyAction::MoveSidebarItem(delta) => self.move_sidebar_item(delta),
+                    KeyAction::StartFocus => self.start_focus(),
+                    KeyAction::FinishFocus => self.finish_focus(),
+                    KeyAction::AbandonFocus => self.abandon_focus(),
+                    KeyAction::ConfirmAccept => self.accept_confirm(),
+                    KeyAction::CompleteRecurring(choice) => self.answer_recurring_prompt(choice),
+                    KeyAction::OpenInBrowser => self.open_selected_task_in_browser(),
+                    KeyAction::SplitTask => self.split_selected_task(),
+                    KeyAction::PromoteTask => self.promote_selected_task(),
+                    KeyAction::StartFindReplace => self.start_find_replace(),
+                    KeyAction::OpenTab => self.open_tab(),
+                    KeyAction::CloseTab => self.close_tab(),
+                    KeyAction::SwitchTab(step) => self.switch_tab(step),
+                    KeyAction::OpenRecent => self.open_recent(),
+                    KeyAction::TogglePin => self.toggle_pin(),
+                    KeyAction::JumpBack => self.jump(true),
+                    KeyAction::JumpForward => self.jump(false),
+                    KeyAction::OpenRecurrence => self.open_recurrence_editor(),
+                    KeyAction::ApplyRecurrence => self.apply_recurrence(),
+                    KeyAction::ApplyFindReplace => self.apply_find_replace(),
+                    KeyAction::ApplyLabelChange => self.apply_label_change(),
+                    KeyAction::PasteInput => self.paste_into_input(),
+                    KeyAction::ShrinkSidebar => self.resize_sidebar(false),
+                    KeyAction::GrowSidebar => self.resize_sidebar(true),
                     KeyAction::ForceResync => self.force_full_resync(),
+                    KeyAction::MarkNotificationRead => self.mark_notifications_read(false),
+                    KeyAction::MarkAllNotificationsRead => self.mark_notifications_read(true),
                     KeyAction::CycleFilter => self.cycle_task_filter(),
                     KeyAction::CycleSort => {
                         self.sort_mode = self.sort_mode.next();
                         info!(sort = self.sort_mode.label(), "sort mode changed");
+                        self.remember_project_view();
+                    }
+                    KeyAction::ReverseSort => {
+                        self.sort_descending = !self.sort_descending;
+                        info!(descending = self.sort_descending, "sort direction changed");
+                        self.remember_project_view();
                     }
                     KeyAction::StartInput => self.start_input(),
                     KeyAction::StartCommentInput => self.start_comment_input(),
+                    KeyAction::StartQuickAdd => self.start_quick_add(),
                     KeyAction::StartFieldEdit => self.start_field_edit(),
                     KeyAction::SubmitInput => self.submit_input(),
                     KeyAction::SubmitForm => self.submit_task_form(),
@@ -694,6 +2298,10 @@ impl App {
                     KeyAction::CancelInput => self.cancel_input(),
                     KeyAction::DetailFieldUp => self.move_detail_field(-1),
                     KeyAction::DetailFieldDown => self.move_detail_field(1),
+                    KeyAction::DetailTask(step) => self.step_detail_task(step),
+                    KeyAction::ScrollComments(rows) => self.scroll_comments(rows),
+                    KeyAction::SelectComment(step) => self.select_comment(step),
+                    KeyAction::ReplyToComment => self.start_reply(),
                     KeyAction::OpenThemePicker => {
                         self.theme_selection = self.theme_idx;
                         self.show_theme_picker = true;
@@ -706,11 +2314,17 @@ impl App {
                     KeyAction::CloseThemePicker => {
                         self.show_theme_picker = false;
                     }
+                    KeyAction::TourStep(by) => self.step_tour(by),
+                    KeyAction::EndTour => self.tour = None,
                     KeyAction::Consumed | KeyAction::None => {}
                 }
                 if matches!(prev_pane, Pane::Tasks) && !matches!(self.active_pane, Pane::Tasks) {
                     self.dock_filter = None;
                 }
+                self.track_jump();
+                self.track_draft();
+                self.advance_tour();
+                busy = handled.elapsed();
             }
         }
 
@@ -724,11 +2338,18 @@ impl App {
             OptimisticOp::TaskUpdated { task_id: id, .. } => id == task_id,
             OptimisticOp::TaskAdded { temp_id } => temp_id == task_id,
             OptimisticOp::TaskRemoved { snapshot } => snapshot.id == task_id,
-            OptimisticOp::CommentAdded { .. } | OptimisticOp::ProjectUpdated { .. } => false,
+            OptimisticOp::DayOrdersUpdated { before } => before.iter().any(|(id, _)| id == task_id),
+            OptimisticOp::TasksChanged { before } => before.iter().any(|t| t.id == task_id),
+            OptimisticOp::CommentAdded { .. }
+            | OptimisticOp::ProjectUpdated { .. }
+            | OptimisticOp::SectionOrdersUpdated { .. }
+            | OptimisticOp::ProjectOrdersUpdated { .. }
+            | OptimisticOp::FavoriteOrdersUpdated { .. } => false,
         })
     }
 
     fn apply_sync_delta(&mut self, resp: SyncResponse) {
+        self.invalidate_project_list();
         if resp.full_sync {
             if let Some(projects) = resp.projects {
                 self.projects = projects
@@ -739,6 +2360,12 @@ impl App {
             }
             if let Some(items) = resp.items {
                 self.tasks = items.into_iter().filter(|t| !t.is_deleted).collect();
+                self.collapsed.extend(
+                    self.tasks
+                        .iter()
+                        .filter(|t| t.is_collapsed)
+                        .map(|t| t.id.clone()),
+                );
             }
             if let Some(labels) = resp.labels {
                 self.labels = labels
@@ -746,6 +2373,10 @@ impl App {
                     .filter(|l| !l.is_deleted.unwrap_or(false))
                     .collect();
             }
+            if let Some(filters) = resp.filters {
+                self.filters = filters.into_iter().filter(|f| !f.is_deleted).collect();
+                self.resolve_custom_dock();
+            }
             if let Some(sections) = resp.sections {
                 self.sections = sections
                     .into_iter()
@@ -765,29 +2396,12 @@ impl App {
                     }
                 }
             }
-            if let Some(collabs) = resp.collaborators {
-                for c in collabs {
-                    self.user_names
-                        .entry(c.id.clone())
-                        .or_insert_with(|| UserRecord::new(c.id, c.name, c.email));
-                }
-            }
             if let Some(workspaces) = resp.workspaces {
                 self.workspaces = workspaces.into_iter().filter(|w| !w.is_deleted).collect();
             }
             if let Some(folders) = resp.folders {
                 self.folders = folders.into_iter().filter(|f| !f.is_deleted).collect();
             }
-            if let Some(user) = resp.user {
-                self.current_user_id = Some(user.id.clone());
-                self.websocket_url = user.websocket_url;
-                if let Some(name) = &user.full_name {
-                    self.current_user_name = Some(name.clone());
-                }
-                self.user_names
-                    .entry(user.id.clone())
-                    .or_insert_with(|| UserRecord::new(user.id, user.full_name, user.email));
-            }
         } else {
             if let Some(projects) = resp.projects {
                 for p in projects {
@@ -808,6 +2422,11 @@ impl App {
                     if self.task_has_pending_op(&item.id) {
                         continue;
                     }
+                    if item.is_collapsed {
+                        self.collapsed.insert(item.id.clone());
+                    } else {
+                        self.collapsed.remove(&item.id);
+                    }
                     if item.is_deleted {
                         self.tasks.retain(|t| t.id != item.id);
                     } else if let Some(e) = self.tasks.iter_mut().find(|t| t.id == item.id) {
@@ -828,6 +2447,18 @@ impl App {
                     }
                 }
             }
+            if let Some(filters) = resp.filters {
+                for f in filters {
+                    if f.is_deleted {
+                        self.filters.retain(|e| e.id != f.id);
+                    } else if let Some(e) = self.filters.iter_mut().find(|e| e.id == f.id) {
+                        *e = f;
+                    } else {
+                        self.filters.push(f);
+                    }
+                }
+                self.resolve_custom_dock();
+            }
             if let Some(sections) = resp.sections {
                 for s in sections {
                     if s.is_deleted.unwrap_or(false) {
@@ -873,6 +2504,47 @@ impl App {
             }
         }
 
+        // The user record also comes with incremental syncs whenever an
+        // account setting such as the timezone changes.
+        if let Some(user) = resp.user {
+            self.current_user_id = Some(user.id.clone());
+            if user.websocket_url.is_some() {
+                self.websocket_url = user.websocket_url.clone();
+            }
+            if let Some(name) = &user.full_name {
+                self.current_user_name = Some(name.clone());
+            }
+            ui::dates::set_account_offset(user.tz_info.as_ref().map(TzInfo::offset_secs));
+            self.user_names.entry(user.id.clone()).or_insert_with(|| {
+                UserRecord::new(user.id.clone(), user.full_name.clone(), user.email.clone())
+            });
+            self.account = Some(user);
+        }
+
+        if let Some(collabs) = resp.collaborators {
+            for c in collabs {
+                self.user_names
+                    .entry(c.id.clone())
+                    .or_insert_with(|| UserRecord::new(c.id, c.name, c.email));
+            }
+        }
+        if let Some(states) = resp.collaborator_states {
+            if resp.full_sync {
+                self.collaborator_states.clear();
+            }
+            for s in states {
+                self.collaborator_states
+                    .retain(|e| !(e.project_id == s.project_id && e.user_id == s.user_id));
+                if !s.is_deleted && s.state != "deleted" {
+                    self.collaborator_states.push(s);
+                }
+            }
+        }
+
+        if let Some(notifications) = resp.live_notifications {
+            self.apply_notifications(notifications, resp.full_sync);
+        }
+
         if !resp.sync_token.is_empty() {
             self.sync_token = resp.sync_token;
             self.save_sync_token();
@@ -888,42 +2560,189 @@ impl App {
         }
     }
 
-    fn flush_commands(&mut self) {
-        if self.pending_commands.is_empty() {
-            return;
+    fn apply_notifications(&mut self, incoming: Vec<LiveNotification>, full_sync: bool) {
+        if full_sync {
+            self.notifications.clear();
+        }
+        for n in incoming {
+            self.notifications.retain(|e| e.id != n.id);
+            if !n.is_deleted && n.is_personal() {
+                self.notifications.push(n);
+            }
         }
+        self.notifications
+            .sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        self.notification_selection = self
+            .notification_selection
+            .min(self.notifications.len().saturating_sub(1));
+    }
 
-        // Callers queue and flush one command at a time. Failure-revert keys off
-        // absolute `before` snapshots, so batching two edits of the same task into
-        // one flush would make the revert order-dependent — keep it one-at-a-time.
-        let commands = std::mem::take(&mut self.pending_commands);
-        let uuids: Vec<String> = commands.iter().map(|c| c.uuid.clone()).collect();
-        let client = Arc::clone(&self.client);
-        let tx = self.bg_tx.clone();
-        let sync_token = self.sync_token.clone();
+    /// Members of the selected project first, then pending invites.
+    pub fn project_collaborators(&self) -> Vec<&CollaboratorState> {
+        let Some(project) = self.projects.get(self.selected_project) else {
+            return Vec::new();
+        };
+        let mut states: Vec<&CollaboratorState> = self
+            .collaborator_states
+            .iter()
+            .filter(|s| s.project_id == project.id)
+            .collect();
+        states.sort_by_key(|s| (s.state == "invited", self.user_display(&s.user_id)));
+        states
+    }
 
-        tokio::spawn(async move {
-            let req = SyncRequest {
-                sync_token,
-                resource_types: vec![],
-                commands,
-            };
-            let result = client.sync(&req).await;
-            match result {
-                Ok(resp) => {
-                    let _ = tx.send(BgResult::CommandResults(Box::new(resp))).await;
-                }
-                Err(e) => {
-                    error!(error = %e, "command flush failed");
-                    let _ = tx.send(BgResult::CommandFailed { uuids }).await;
-                }
-            }
-        });
+    pub fn user_display(&self, user_id: &str) -> String {
+        self.user_names
+            .get(user_id)
+            .map(|r| r.display.clone())
+            .unwrap_or_else(|| user_id.to_string())
     }
 
-    fn apply_temp_id_mapping(&mut self, temp_id: &str, real_id: &str) {
-        if let Some(t) = self.tasks.iter_mut().find(|t| t.id == temp_id) {
-            t.id = real_id.to_string();
+    pub fn unread_notification_count(&self) -> usize {
+        self.notifications.iter().filter(|n| n.is_unread).count()
+    }
+
+    /// Marks the highlighted notification read, or all of them. Not reverted
+    /// on failure: the next sync brings back the server's read state.
+    fn mark_notifications_read(&mut self, all: bool) {
+        let ids: Vec<String> = if all {
+            self.notifications
+                .iter()
+                .filter(|n| n.is_unread)
+                .map(|n| n.id.clone())
+                .collect()
+        } else {
+            self.notifications
+                .get(self.notification_selection)
+                .filter(|n| n.is_unread)
+                .map(|n| vec![n.id.clone()])
+                .unwrap_or_default()
+        };
+        if ids.is_empty() {
+            return;
+        }
+        for n in &mut self.notifications {
+            if ids.contains(&n.id) {
+                n.is_unread = false;
+            }
+        }
+        self.pending_commands.push(SyncCommand {
+            r#type: "live_notifications_mark_read".to_string(),
+            temp_id: None,
+            uuid: new_uuid(),
+            args: serde_json::json!({ "ids": ids }),
+        });
+        self.flush_commands();
+    }
+
+    /// Spawns a one-shot background job, counted in the debug overlay while it runs.
+    fn spawn_job(&self, job: impl Future<Output = ()> + Send + 'static) {
+        let perf = Arc::clone(&self.perf);
+        perf.in_flight.fetch_add(1, Ordering::Relaxed);
+        tokio::spawn(async move {
+            job.await;
+            perf.in_flight.fetch_sub(1, Ordering::Relaxed);
+        });
+    }
+
+    /// Spawns an API fetch unless an identical one is already running. Its
+    /// result handler must `remove` it from `fetches_in_flight`.
+    fn spawn_fetch(&mut self, fetch: Fetch, job: impl Future<Output = ()> + Send + 'static) {
+        if !self.fetches_in_flight.insert(fetch.clone()) {
+            debug!(?fetch, "fetch already in flight");
+            return;
+        }
+        let slots = Arc::clone(&self.fetch_slots);
+        self.spawn_job(async move {
+            let _slot = slots.acquire_owned().await;
+            job.await;
+        });
+    }
+
+    /// Sends queued commands after `COMMAND_FLUSH_DELAY`, with any that
+    /// are queued in the meantime.
+    fn flush_commands(&mut self) {
+        if !self.pending_commands.is_empty() {
+            self.commands_queued_since.get_or_insert_with(Instant::now);
+        }
+    }
+
+    /// Falls back to polling while the websocket is down, e.g. on networks
+    /// that block it; otherwise nothing would refresh. Idle sessions don't
+    /// poll, and a healthy websocket turns it off.
+    fn tick_poll(&mut self) {
+        if self.poll_interval_secs == 0 || self.websocket_connected || self.is_idle() {
+            return;
+        }
+        if self.last_poll.elapsed() < Duration::from_secs(self.poll_interval_secs) {
+            return;
+        }
+        self.last_poll = Instant::now();
+        debug!("websocket down, polling for changes");
+        self.spawn_incremental_sync();
+    }
+
+    fn tick_commands(&mut self) {
+        if self
+            .commands_queued_since
+            .is_some_and(|since| since.elapsed() >= COMMAND_FLUSH_DELAY)
+        {
+            self.send_commands();
+        }
+    }
+
+    /// Sends everything queued now, in one request.
+    fn send_commands(&mut self) {
+        self.commands_queued_since = None;
+        if self.pending_commands.is_empty() {
+            return;
+        }
+
+        // Several commands may touch one task. Failure-revert keys off
+        // absolute `before` snapshots, so their reverts must run newest
+        // first; `CommandResults` carries the order for that.
+        let queued = std::mem::take(&mut self.pending_commands);
+        let commands = coalesce_updates(queued, &mut self.temp_id_pending);
+        let uuids: Vec<String> = commands.iter().map(|c| c.uuid.clone()).collect();
+        let client = Arc::clone(&self.client);
+        let tx = self.bg_tx.clone();
+        let sync_token = self.sync_token.clone();
+        let perf = Arc::clone(&self.perf);
+
+        self.spawn_job(async move {
+            let req = SyncRequest {
+                sync_token,
+                resource_types: vec![],
+                commands,
+            };
+            let started = Instant::now();
+            let result = client.sync(&req).await;
+            perf.record_sync(started);
+            match result {
+                Ok(resp) => {
+                    let _ = tx
+                        .send(BgResult::CommandResults {
+                            resp: Box::new(resp),
+                            uuids,
+                        })
+                        .await;
+                }
+                Err(e) => {
+                    error!(error = %e, "command flush failed");
+                    let _ = tx
+                        .send(BgResult::CommandFailed {
+                            commands: req.commands,
+                            error: format!("{e:#}"),
+                        })
+                        .await;
+                }
+            }
+        });
+    }
+
+    fn apply_temp_id_mapping(&mut self, temp_id: &str, real_id: &str) {
+        if let Some(t) = self.tasks.iter_mut().find(|t| t.id == temp_id) {
+            t.id = real_id.to_string();
         }
         for c in &mut self.comments {
             if c.id == temp_id {
@@ -960,6 +2779,47 @@ impl App {
                 }
                 self.sort_projects();
             }
+            OptimisticOp::DayOrdersUpdated { before } => {
+                for (task_id, order) in before {
+                    if let Some(t) = self.tasks.iter_mut().find(|t| t.id == task_id) {
+                        t.day_order = order;
+                    }
+                }
+            }
+            OptimisticOp::SectionOrdersUpdated { before } => {
+                for (section_id, order) in before {
+                    if let Some(s) = self.sections.iter_mut().find(|s| s.id == section_id) {
+                        s.section_order = order;
+                    }
+                }
+            }
+            OptimisticOp::ProjectOrdersUpdated { before } => {
+                for (project_id, order) in before {
+                    if let Some(p) = self.projects.iter_mut().find(|p| p.id == project_id) {
+                        p.child_order = order;
+                    }
+                }
+                self.sort_projects();
+            }
+            OptimisticOp::FavoriteOrdersUpdated { labels, before } => {
+                self.invalidate_project_list();
+                for (id, order) in before {
+                    if labels {
+                        if let Some(l) = self.labels.iter_mut().find(|l| l.id == id) {
+                            l.item_order = order;
+                        }
+                    } else if let Some(f) = self.filters.iter_mut().find(|f| f.id == id) {
+                        f.item_order = order;
+                    }
+                }
+            }
+            OptimisticOp::TasksChanged { before } => {
+                for snapshot in before {
+                    if let Some(t) = self.tasks.iter_mut().find(|t| t.id == snapshot.id) {
+                        *t = snapshot;
+                    }
+                }
+            }
         }
     }
 
@@ -976,34 +2836,60 @@ impl App {
         }
     }
 
+    fn spawn_update_check(&self) {
+        let tx = self.bg_tx.clone();
+        self.spawn_job(async move {
+            match crate::update::check().await {
+                Ok(Some(release)) => {
+                    let version = release.version().to_string();
+                    let _ = tx.send(BgResult::UpdateAvailable(version)).await;
+                }
+                Ok(None) => debug!("no newer release"),
+                Err(e) => warn!(error = %e, "update check failed"),
+            }
+        });
+    }
+
     fn spawn_websocket(&self, url: String) {
         let tx = self.bg_tx.clone();
         tokio::spawn(run_websocket(url, tx));
     }
 
     fn spawn_incremental_sync(&self) {
+        self.spawn_resource_sync(INCREMENTAL_RESOURCES);
+    }
+
+    /// Syncs just `resources`. A partial sync leaves the sync token where it
+    /// was: the token covers every resource type, so advancing it here would
+    /// skip the others' changes. The next full incremental sync re-sends
+    /// what this one fetched, which applying again leaves unchanged.
+    fn spawn_resource_sync(&self, resources: &[&str]) {
         let client = Arc::clone(&self.client);
         let tx = self.bg_tx.clone();
         let sync_token = self.sync_token.clone();
+        let perf = Arc::clone(&self.perf);
+        let partial = resources.len() < INCREMENTAL_RESOURCES.len();
+        let resource_types = resources.iter().map(|r| r.to_string()).collect();
 
-        tokio::spawn(async move {
+        self.spawn_job(async move {
             let req = SyncRequest {
                 sync_token,
-                resource_types: vec![
-                    "items".to_string(),
-                    "projects".to_string(),
-                    "sections".to_string(),
-                    "labels".to_string(),
-                    "notes".to_string(),
-                ],
+                resource_types,
                 commands: vec![],
             };
-            match client.sync(&req).await {
-                Ok(resp) => {
+            let started = Instant::now();
+            let result = client.sync(&req).await;
+            perf.record_sync(started);
+            match result {
+                Ok(mut resp) => {
+                    if partial {
+                        resp.sync_token.clear();
+                    }
                     let _ = tx.send(BgResult::SyncDelta(Box::new(resp))).await;
                 }
                 Err(e) => {
                     error!(error = %e, "incremental sync failed");
+                    let _ = tx.send(BgResult::SyncFailed(format!("{e:#}"))).await;
                 }
             }
         });
@@ -1015,22 +2901,121 @@ impl App {
     /// result must not revert against it.
     fn force_full_resync(&mut self) {
         self.pending_commands.clear();
+        self.commands_queued_since = None;
         self.temp_id_pending.clear();
         self.sync_token = "*".to_string();
         self.save_sync_token();
         self.spawn_incremental_sync();
+        self.fetch_productivity_stats();
+    }
+
+    fn fetch_productivity_stats(&mut self) {
+        let client = Arc::clone(&self.client);
+        let tx = self.bg_tx.clone();
+        self.spawn_job(async move {
+            let stats = client.get_productivity_stats().await;
+            let _ = tx.send(BgResult::ProductivityStats(stats)).await;
+        });
+    }
+
+    fn fetched_completed_today(&self) -> Option<u32> {
+        let stats = self.productivity.as_ref()?;
+        let today = ui::dates::today().to_string();
+        Some(
+            stats
+                .days_items
+                .iter()
+                .find(|d| d.date == today)
+                .map_or(0, |d| d.total_completed),
+        )
+    }
+
+    /// Completions today per the last stats fetch plus those made since;
+    /// `None` until stats arrive.
+    pub fn completed_today(&self) -> Option<u32> {
+        self.fetched_completed_today()
+            .map(|n| n.saturating_add_signed(self.completions_since_stats))
+    }
+
+    /// From the last stats fetch, else the account settings.
+    pub fn daily_goal(&self) -> Option<u32> {
+        self.productivity
+            .as_ref()
+            .and_then(|s| s.goals.daily_goal)
+            .or_else(|| self.account.as_ref()?.daily_goal)
+            .filter(|g| *g > 0)
+    }
+
+    pub fn weekly_goal(&self) -> Option<u32> {
+        self.productivity
+            .as_ref()
+            .and_then(|s| s.goals.weekly_goal)
+            .or_else(|| self.account.as_ref()?.weekly_goal)
+            .filter(|g| *g > 0)
+    }
+
+    /// Current streak of days meeting the daily goal, counting today once the
+    /// goal is reached here rather than at fetch time.
+    pub fn daily_streak(&self) -> Option<u32> {
+        let stats = self.productivity.as_ref()?;
+        let streak = stats.goals.current_daily_streak.as_ref()?.count;
+        let (Some(goal), Some(fetched), Some(now)) = (
+            self.daily_goal(),
+            self.fetched_completed_today(),
+            self.completed_today(),
+        ) else {
+            return Some(streak);
+        };
+        Some(if fetched < goal && now >= goal {
+            streak + 1
+        } else {
+            streak
+        })
     }
 
-    fn drain_bg_results(&mut self) {
+    /// Completions this week per the last stats fetch plus those made since;
+    /// `None` until stats arrive.
+    pub fn completed_this_week(&self) -> Option<u32> {
+        let stats = self.productivity.as_ref()?;
+        let today = ui::dates::today().to_string();
+        let fetched = stats
+            .week_items
+            .iter()
+            .find(|w| w.from.as_str() <= today.as_str() && today.as_str() <= w.to.as_str())
+            .map_or(0, |w| w.total_completed);
+        Some(fetched.saturating_add_signed(self.completions_since_stats))
+    }
+
+    /// Applies everything the background tasks have sent; `true` if there
+    /// was anything.
+    fn drain_bg_results(&mut self) -> bool {
+        let mut received = false;
+        let mut reload_themes = false;
         while let Ok(result) = self.bg_rx.try_recv() {
+            received = true;
             match result {
+                // An editor save fires a burst of events; reload once per drain.
+                BgResult::ThemesChanged => reload_themes = true,
+                BgResult::UpdateAvailable(version) => {
+                    info!(version = %version, "update available");
+                    self.latest_version = Some(version);
+                }
+
                 BgResult::SyncDelta(resp) => {
                     self.apply_sync_delta(*resp);
                 }
 
-                BgResult::CommandResults(resp) => {
+                BgResult::SyncFailed(error) => {
+                    self.fire_sync_error_hook("incremental sync", &error);
+                }
+
+                BgResult::CommandResults { resp, uuids } => {
                     let mut refresh_comments_for: Option<String> = None;
-                    for (uuid, status) in &resp.sync_status {
+                    let statuses = uuids
+                        .iter()
+                        .rev()
+                        .filter_map(|uuid| Some((uuid, resp.sync_status.get(uuid)?)));
+                    for (uuid, status) in statuses {
                         if status.is_err() {
                             if let Some(op) = self.temp_id_pending.remove(uuid) {
                                 self.revert_optimistic(op);
@@ -1040,11 +3025,16 @@ impl App {
                                 .unwrap_or("unknown error")
                                 .to_string();
                             error!(uuid, error = %msg, "command rejected by server");
-                            self.error = Some(AppError {
+                            self.fire_sync_error_hook("command rejected", &msg);
+                            self.show_error(AppError {
                                 title: "Command failed".to_string(),
                                 message: msg,
                                 suggestion: None,
                                 recoverable: true,
+                                retry: None,
+                                context: "Syncing changes".to_string(),
+                                raw: Some(format!("{uuid}: {status:?}")),
+                                at: Local::now(),
                             });
                         } else if let Some(op) = self.temp_id_pending.remove(uuid)
                             && let OptimisticOp::CommentAdded { task_id, .. } = &op
@@ -1063,49 +3053,80 @@ impl App {
                         self.save_sync_token();
                     }
                     if let Some(tid) = refresh_comments_for {
+                        // The server has the comment now; a fetch sent before
+                        // it did is stale.
+                        self.comments_fetch_seq += 1;
                         self.spawn_comments_fetch(tid);
                     }
                 }
 
-                BgResult::CommandFailed { uuids } => {
-                    let mut reverted = false;
-                    for uuid in &uuids {
-                        if let Some(op) = self.temp_id_pending.remove(uuid) {
-                            self.revert_optimistic(op);
-                            reverted = true;
-                        }
+                BgResult::CommandFailed {
+                    mut commands,
+                    error,
+                } => {
+                    self.fire_sync_error_hook("command flush", &error);
+                    if !commands
+                        .iter()
+                        .any(|c| self.temp_id_pending.contains_key(&c.uuid))
+                    {
+                        continue;
                     }
-                    if reverted {
-                        self.error = Some(AppError {
-                            title: "Sync failed".to_string(),
-                            message: "Couldn't reach Todoist — your change was reverted."
-                                .to_string(),
-                            suggestion: Some("Check your connection and try again.".to_string()),
-                            recoverable: true,
-                        });
+                    // A second failure while the popup is up joins the first,
+                    // so one retry re-sends both and one dismiss reverts both.
+                    if let Some(AppError {
+                        retry: Some(RetryAction::Commands(earlier)),
+                        ..
+                    }) = self.error.take()
+                    {
+                        commands.splice(0..0, earlier);
                     }
+                    self.show_error(sync_failed_error(commands));
                 }
 
+                BgResult::QuickAdded(task) => match task {
+                    Ok(task) => {
+                        let project = self
+                            .projects
+                            .iter()
+                            .find(|p| p.id == task.project_id)
+                            .map_or_else(|| "Todoist".to_string(), |p| p.name.clone());
+                        if !self.tasks.iter().any(|t| t.id == task.id) {
+                            self.fire_task_hook(Hook::TaskAdd, &task);
+                            self.tasks.push(*task);
+                        }
+                        self.flash(format!("Added to {project}"));
+                    }
+                    Err(e) => self.set_error(&e, "Quick add"),
+                },
+
                 BgResult::CompletedTasks {
                     project_id,
                     records,
-                } => match records {
-                    Ok(r) => {
-                        self.completed_cache.insert(project_id, r);
+                } => {
+                    self.fetches_in_flight
+                        .remove(&Fetch::CompletedTasks(project_id.clone()));
+                    match records {
+                        Ok(r) => {
+                            self.completed_cache.insert(project_id, r);
+                        }
+                        Err(e) => self.set_retryable_error(
+                            &e,
+                            "Load completed tasks",
+                            RetryAction::CompletedTasks(project_id),
+                        ),
                     }
-                    Err(e) => self.set_error(&e, "Load completed tasks"),
-                },
+                }
 
                 BgResult::WebSocketConnected => {
                     debug!("websocket connected");
                     self.websocket_connected = true;
                 }
-                BgResult::WebSocketEvent => {
+                BgResult::WebSocketEvent(changed) => {
                     self.websocket_connected = true;
                     if self.is_idle() {
                         self.pending_ws_sync = true;
                     } else {
-                        self.spawn_incremental_sync();
+                        self.spawn_resource_sync(changed.unwrap_or(INCREMENTAL_RESOURCES));
                     }
                 }
                 BgResult::WebSocketDisconnected => {
@@ -1117,24 +3138,135 @@ impl App {
                     task_id,
                     comments,
                     fetch_seq,
-                } => match comments {
+                } => {
+                    self.fetches_in_flight.remove(&Fetch::Comments {
+                        task_id: task_id.clone(),
+                        seq: fetch_seq,
+                    });
+                    match comments {
+                        Ok(c) => {
+                            let count = c.len() as i32;
+                            if let Some(t) = self.tasks.iter_mut().find(|t| t.id == task_id) {
+                                t.note_count = Some(count);
+                            }
+                            self.comments_by_task.insert(task_id.clone(), c.clone());
+                            let current_tid = self.selected_task().map(|t| t.id.clone());
+                            if current_tid.as_deref() == Some(&task_id)
+                                && fetch_seq == self.comments_fetch_seq
+                            {
+                                self.comments = c;
+                            }
+                        }
+                        Err(e) => self.set_retryable_error(
+                            &e,
+                            "Load comments",
+                            RetryAction::Comments(task_id),
+                        ),
+                    }
+                }
+
+                // Shown inline: the activity log is a paid feature, so a
+                // failure is often expected and not worth a popup.
+                BgResult::History { task_id, events } => {
+                    self.fetches_in_flight
+                        .remove(&Fetch::History(task_id.clone()));
+                    if let Err(e) = &events {
+                        warn!(task_id, error = %e, "task activity fetch failed");
+                    }
+                    self.task_history
+                        .insert(task_id, events.map_err(|e| e.to_string()));
+                }
+
+                // Goals are decoration: without stats the dock just hides them.
+                BgResult::ProductivityStats(stats) => match stats {
+                    Ok(s) => {
+                        self.productivity = Some(s);
+                        self.completions_since_stats = 0;
+                    }
+                    Err(e) => warn!(error = %e, "productivity stats fetch failed"),
+                },
+
+                // Without the member list the overview falls back to the
+                // member count from sync.
+                BgResult::WorkspaceUsers {
+                    workspace_id,
+                    users,
+                } => match users {
+                    Ok(u) => {
+                        self.workspace_users.insert(workspace_id, u);
+                    }
+                    Err(e) => warn!(workspace_id, error = %e, "workspace users fetch failed"),
+                },
+
+                // Prefetch is best-effort: a failure just means the detail
+                // view fetches on open as before.
+                BgResult::PrefetchedComments { task_id, comments } => match comments {
                     Ok(c) => {
-                        let count = c.len() as i32;
                         if let Some(t) = self.tasks.iter_mut().find(|t| t.id == task_id) {
-                            t.note_count = Some(count);
-                        }
-                        self.comments_by_task.insert(task_id.clone(), c.clone());
-                        let current_tid = self.selected_task().map(|t| t.id.clone());
-                        if current_tid.as_deref() == Some(&task_id)
-                            && fetch_seq == self.comments_fetch_seq
-                        {
-                            self.comments = c;
+                            t.note_count = Some(c.len() as i32);
                         }
+                        self.comments_by_task.entry(task_id).or_insert(c);
+                    }
+                    Err(e) => {
+                        self.comments_prefetched.remove(&task_id);
+                        debug!(task_id, error = %e, "comment prefetch failed");
                     }
-                    Err(e) => self.set_error(&e, "Load comments"),
                 },
             }
         }
+        if reload_themes {
+            self.reload_themes();
+        }
+        received
+    }
+
+    /// Watches the user theme directory so edits show up without a restart.
+    fn spawn_theme_watcher(&mut self) {
+        use notify::Watcher;
+
+        let dir = ratatoist_core::config::Config::config_dir().join("themes");
+        if !dir.is_dir() {
+            debug!(dir = %dir.display(), "no user theme directory to watch");
+            return;
+        }
+        let tx = self.bg_tx.clone();
+        let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            if let Ok(event) = event
+                && !event.kind.is_access()
+            {
+                let _ = tx.try_send(BgResult::ThemesChanged);
+            }
+        })
+        .and_then(|mut w| {
+            w.watch(&dir, notify::RecursiveMode::NonRecursive)
+                .map(|_| w)
+        });
+        match watcher {
+            Ok(w) => self.theme_watcher = Some(w),
+            Err(e) => warn!(error = %e, "could not watch user themes"),
+        }
+    }
+
+    /// Re-reads builtin and user themes, keeping the active one selected by name.
+    fn reload_themes(&mut self) {
+        let mut themes = crate::ui::theme::Theme::builtin();
+        let user_themes_dir = ratatoist_core::config::Config::config_dir().join("themes");
+        themes.extend(crate::ui::theme::Theme::load_user_themes(&user_themes_dir));
+
+        let name_at = |idx: usize| self.themes.get(idx).map(|t| t.name.clone());
+        let find =
+            |name: Option<String>| name.and_then(|n| themes.iter().position(|t| t.name == n));
+        // A half-written file fails to parse; keep the old set until it's valid again.
+        let Some(theme_idx) = find(name_at(self.theme_idx)) else {
+            debug!("active theme missing after reload, keeping previous themes");
+            return;
+        };
+        let theme_selection = find(name_at(self.theme_selection)).unwrap_or(theme_idx);
+
+        self.themes = themes;
+        self.theme_idx = theme_idx;
+        self.theme_selection = theme_selection;
+        info!(themes = self.themes.len(), "themes reloaded");
     }
 
     fn open_detail(&mut self) {
@@ -1142,16 +3274,18 @@ impl App {
         if let Some(task) = visible.get(self.selected_task) {
             let task_id = task.id.clone();
             let task_project_id = task.project_id.clone();
+            self.remember_recent(&task_id, RecentKind::Opened);
 
-            if self.dock_filter.is_some()
+            if (self.dock_filter.is_some() || self.favorite_view.is_some())
                 && let Some(pos) = self.projects.iter().position(|p| p.id == task_project_id)
             {
                 self.selected_project = pos;
             }
 
             self.active_pane = Pane::Detail;
-            self.detail_scroll = 0;
+            self.comments_scroll = 0;
             self.detail_field = 0;
+            self.selected_comment = None;
 
             // Serve cached comments immediately, refresh in background.
             if let Some(cached) = self.comments_by_task.get(&task_id) {
@@ -1159,18 +3293,46 @@ impl App {
             } else {
                 self.comments.clear();
             }
+            if self.show_history {
+                self.spawn_history_fetch(task_id.clone());
+            }
             self.spawn_comments_fetch(task_id);
         }
     }
 
+    /// Expands or collapses the detail pane's History section, fetching the
+    /// open task's activity on expand.
+    fn toggle_history(&mut self) {
+        self.show_history = !self.show_history;
+        if self.show_history
+            && let Some(task_id) = self.selected_task().map(|t| t.id.clone())
+        {
+            self.spawn_history_fetch(task_id);
+        }
+    }
+
+    fn spawn_history_fetch(&mut self, task_id: String) {
+        let client = Arc::clone(&self.client);
+        let tx = self.bg_tx.clone();
+        self.spawn_fetch(Fetch::History(task_id.clone()), async move {
+            let events = client.get_task_activity(&task_id).await;
+            let _ = tx.send(BgResult::History { task_id, events }).await;
+        });
+    }
+
+    /// Results are kept only while `comments_fetch_seq` is unchanged, so a
+    /// fetch that raced a change to the thread can't overwrite it.
     fn spawn_comments_fetch(&mut self, task_id: String) {
-        self.comments_fetch_seq += 1;
         let fetch_seq = self.comments_fetch_seq;
         let client = Arc::clone(&self.client);
         let tx = self.bg_tx.clone();
         let tid = task_id.clone();
 
-        tokio::spawn(async move {
+        let fetch = Fetch::Comments {
+            task_id,
+            seq: fetch_seq,
+        };
+        self.spawn_fetch(fetch, async move {
             let comments = client.get_comments(&tid).await;
             let _ = tx
                 .send(BgResult::Comments {
@@ -1182,12 +3344,66 @@ impl App {
         });
     }
 
-    fn spawn_completed_tasks_fetch(&self, project_id: String) {
+    /// Warms `comments_by_task` for every commented task in the selected
+    /// project so opening a detail doesn't wait on the network.
+    fn prefetch_project_comments(&mut self) {
+        let Some(pid) = self
+            .projects
+            .get(self.selected_project)
+            .map(|p| p.id.clone())
+        else {
+            return;
+        };
+        // Prefetching is optional; leave a nearly spent budget for edits.
+        // Tasks skipped now are fetched when opened, or on a later visit.
+        if self.rate_budget().is_some_and(|b| b.is_low()) {
+            debug!(project_id = %pid, "request budget low, skipping comment prefetch");
+            return;
+        }
+        let ids: Vec<String> = self
+            .tasks
+            .iter()
+            .filter(|t| t.project_id == pid && t.note_count.unwrap_or(0) > 0)
+            .filter(|t| !self.comments_by_task.contains_key(&t.id))
+            .filter(|t| self.comments_prefetched.insert(t.id.clone()))
+            .map(|t| t.id.clone())
+            .collect();
+        if ids.is_empty() {
+            return;
+        }
+        debug!(project_id = %pid, tasks = ids.len(), "prefetching comments");
+
+        let client = Arc::clone(&self.client);
+        let tx = self.bg_tx.clone();
+        let slots = Arc::clone(&self.fetch_slots);
+        self.spawn_job(async move {
+            use futures_util::StreamExt;
+            futures_util::stream::iter(ids)
+                .for_each_concurrent(COMMENT_PREFETCH_CONCURRENCY, |tid| {
+                    let client = Arc::clone(&client);
+                    let tx = tx.clone();
+                    let slots = Arc::clone(&slots);
+                    async move {
+                        let _slot = slots.acquire_owned().await;
+                        let comments = client.get_comments(&tid).await;
+                        let _ = tx
+                            .send(BgResult::PrefetchedComments {
+                                task_id: tid,
+                                comments,
+                            })
+                            .await;
+                    }
+                })
+                .await;
+        });
+    }
+
+    fn spawn_completed_tasks_fetch(&mut self, project_id: String) {
         let client = Arc::clone(&self.client);
         let tx = self.bg_tx.clone();
         let pid = project_id.clone();
 
-        tokio::spawn(async move {
+        self.spawn_fetch(Fetch::CompletedTasks(project_id), async move {
             let records = client.get_completed_tasks(Some(&pid), None).await;
             let _ = tx
                 .send(BgResult::CompletedTasks {
@@ -1198,59 +3414,535 @@ impl App {
         });
     }
 
+    fn open_workspace_overview(&mut self) {
+        if self.workspaces.is_empty() {
+            self.flash("No workspaces on this account");
+            return;
+        }
+        self.show_workspace_overview = true;
+        self.workspace_overview_selection = 0;
+        let missing: Vec<String> = self
+            .workspaces
+            .iter()
+            .filter(|w| !self.workspace_users.contains_key(&w.id))
+            .map(|w| w.id.clone())
+            .collect();
+        for workspace_id in missing {
+            let client = Arc::clone(&self.client);
+            let tx = self.bg_tx.clone();
+            self.spawn_job(async move {
+                let users = client.get_workspace_users(&workspace_id).await;
+                let _ = tx
+                    .send(BgResult::WorkspaceUsers {
+                        workspace_id,
+                        users,
+                    })
+                    .await;
+            });
+        }
+    }
+
+    /// Each workspace, then its folders with their projects, then projects
+    /// outside any folder. Archived projects are left out.
+    pub fn workspace_overview_rows(&self) -> Vec<OverviewRow> {
+        let mut rows = Vec::new();
+        for (wi, workspace) in self.workspaces.iter().enumerate() {
+            rows.push(OverviewRow::Workspace(wi));
+            let in_workspace = |p: &Project| {
+                p.workspace_id.as_deref() == Some(workspace.id.as_str())
+                    && !p.is_archived.unwrap_or(false)
+            };
+            let mut folders: Vec<usize> = (0..self.folders.len())
+                .filter(|&fi| self.folders[fi].workspace_id == workspace.id)
+                .collect();
+            folders.sort_by_key(|&fi| self.folders[fi].child_order);
+            for fi in folders {
+                rows.push(OverviewRow::Folder(fi));
+                let folder_id = self.folders[fi].id.as_str();
+                rows.extend(
+                    self.projects
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, p)| {
+                            in_workspace(p) && p.folder_id.as_deref() == Some(folder_id)
+                        })
+                        .map(|(i, _)| OverviewRow::Project(i)),
+                );
+            }
+            rows.extend(
+                self.projects
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| in_workspace(p) && p.folder_id.is_none())
+                    .map(|(i, _)| OverviewRow::Project(i)),
+            );
+        }
+        rows
+    }
+
+    /// Active members from the fetched list, else the count from sync.
+    pub fn workspace_member_count(&self, workspace: &Workspace) -> Option<usize> {
+        match self.workspace_users.get(&workspace.id) {
+            Some(users) if !users.is_empty() => {
+                Some(users.iter().filter(|u| !u.is_deleted).count())
+            }
+            _ => workspace.current_member_count.map(|n| n as usize),
+        }
+    }
+
     fn switch_to_project_tasks(&mut self) {
         self.today_view_active = false;
+        self.favorite_view = None;
         self.selected_task = 0;
-        self.detail_scroll = 0;
+        self.comments_scroll = 0;
+        self.restore_project_view();
+        self.prefetch_project_comments();
     }
 
-    pub fn activate_today_view(&mut self) {
-        tracing::debug!("today view activated");
-        self.today_view_active = true;
-        self.overdue_section_collapsed = false;
-        self.selected_task = 0;
-        self.detail_scroll = 0;
+    fn open_start_view(&mut self) {
+        match self.start_view {
+            StartView::Projects => return,
+            StartView::Inbox => {
+                let Some(i) = self.projects.iter().position(|p| p.is_inbox()) else {
+                    return;
+                };
+                self.selected_project = i;
+                self.switch_to_project_tasks();
+            }
+            StartView::Today => self.activate_today_view(),
+            StartView::Last => {
+                if let Some(session) = self.last_session.take() {
+                    self.restore_session(session);
+                }
+                return;
+            }
+        }
+        self.active_pane = Pane::Tasks;
     }
 
-    pub fn toggle_overdue_section(&mut self) {
-        self.overdue_section_collapsed = !self.overdue_section_collapsed;
-        if self.overdue_section_collapsed {
-            self.selected_task = 0;
+    /// Reopens the previous session's list, task and pane. Anything deleted
+    /// since is skipped: a missing project leaves the list where it was, a
+    /// missing task selects the first one.
+    fn restore_session(&mut self, session: Session) {
+        debug!(?session, "restoring last session");
+        if let Some(i) = session
+            .project_id
+            .and_then(|id| self.projects.iter().position(|p| p.id == id))
+        {
+            self.selected_project = i;
+            self.switch_to_project_tasks();
+        }
+        if session.today {
+            self.activate_today_view();
+        } else if let Some(view) = session.favorite
+            && self.favorite_name(&view).is_some()
+        {
+            self.activate_favorite_view(view);
+        }
+        self.dock_filter = session.dock.and_then(|key| {
+            self.dock_items()
+                .into_iter()
+                .find(|item| self.dock_key(*item) == key)
+        });
+        if let Some(pos) = session
+            .task_id
+            .and_then(|id| self.visible_tasks().iter().position(|t| t.id == id))
+        {
+            self.selected_task = pos;
+        }
+        self.active_pane = match session.pane {
+            Some(Pane::Projects) => Pane::Projects,
+            _ => Pane::Tasks,
+        };
+        if session.pane == Some(Pane::Detail) {
+            self.open_detail();
         }
     }
 
-    fn complete_selected_task(&mut self) {
-        let (task_id, was_checked, is_recurring) = {
+    /// A dock entry as persisted: its hint, or `filter:` and the name for
+    /// `[dock] filters`, whose indexes change as the config does.
+    fn dock_key(&self, item: DockItem) -> String {
+        match item {
+            DockItem::Custom(_) => format!("filter:{}", self.dock_hint(item)),
+            _ => item.hint().to_string(),
+        }
+    }
+
+    fn session_json(&self) -> serde_json::Value {
+        let pane = match self.active_pane {
+            Pane::Projects => "projects",
+            Pane::Detail => "detail",
+            _ => "tasks",
+        };
+        let (label, filter) = match &self.favorite_view {
+            Some(FavoriteView::Label(id)) => (Some(id), None),
+            Some(FavoriteView::Filter(id)) => (None, Some(id)),
+            None => (None, None),
+        };
+        serde_json::json!({
+            "project": self.projects.get(self.selected_project).map(|p| &p.id),
+            "task": self.selected_task().map(|t| &t.id),
+            "pane": pane,
+            "today": self.today_view_active,
+            "favorite_label": label,
+            "favorite_filter": filter,
+            "dock": self.dock_filter.map(|item| self.dock_key(item)),
+        })
+    }
+
+    /// Applies the sort and filter last used in the selected project.
+    fn restore_project_view(&mut self) {
+        let view = self
+            .projects
+            .get(self.selected_project)
+            .and_then(|p| self.project_views.get(&p.id))
+            .copied()
+            .unwrap_or(self.default_project_view);
+        self.sort_mode = view.sort;
+        self.sort_descending = view.descending;
+        self.task_filter = view.filter;
+        self.fetch_completed_if_needed();
+    }
+
+    fn remember_project_view(&mut self) {
+        if self.today_view_active || self.favorite_view.is_some() || self.dock_filter.is_some() {
+            return;
+        }
+        let Some(pid) = self
+            .projects
+            .get(self.selected_project)
+            .map(|p| p.id.clone())
+        else {
+            return;
+        };
+        let view = ProjectView {
+            sort: self.sort_mode,
+            descending: self.sort_descending,
+            filter: self.task_filter,
+        };
+        if view == self.default_project_view {
+            self.project_views.remove(&pid);
+        } else {
+            self.project_views.insert(pid, view);
+        }
+        self.save_ui_state();
+    }
+
+    pub fn activate_today_view(&mut self) {
+        tracing::debug!("today view activated");
+        self.today_view_active = true;
+        self.favorite_view = None;
+        self.overdue_section_collapsed = false;
+        self.selected_task = 0;
+        self.comments_scroll = 0;
+    }
+
+    /// Opens a favorite label or filter as a cross-project task list. Filter
+    /// queries outside the supported syntax show an empty list and say why.
+    fn activate_favorite_view(&mut self, view: FavoriteView) {
+        debug!(?view, "favorite view activated");
+        if let FavoriteView::Filter(id) = &view
+            && let Some(filter) = self.filters.iter().find(|f| &f.id == id)
+            && let Err(e) = Query::parse(&filter.query)
+        {
+            self.flash(format!("{}: {e}", filter.name));
+        }
+        self.today_view_active = false;
+        self.dock_filter = None;
+        self.favorite_view = Some(view);
+        self.selected_task = 0;
+        self.comments_scroll = 0;
+    }
+
+    /// Sidebar title of the open favorite view, e.g. `@deep-work`.
+    pub fn favorite_view_name(&self) -> Option<String> {
+        self.favorite_name(self.favorite_view.as_ref()?)
+    }
+
+    fn favorite_name(&self, view: &FavoriteView) -> Option<String> {
+        match view {
+            FavoriteView::Label(id) => self
+                .labels
+                .iter()
+                .find(|l| &l.id == id)
+                .map(|l| format!("@{}", l.name)),
+            FavoriteView::Filter(id) => self
+                .filters
+                .iter()
+                .find(|f| &f.id == id)
+                .map(|f| f.name.clone()),
+        }
+    }
+
+    /// Favorited labels then filters, each in their Todoist order.
+    fn favorite_views(&self) -> Vec<FavoriteView> {
+        let mut labels: Vec<&Label> = self.labels.iter().filter(|l| l.is_favorite).collect();
+        labels.sort_by_key(|l| l.item_order.unwrap_or(i32::MAX));
+        let mut filters: Vec<&Filter> = self.filters.iter().filter(|f| f.is_favorite).collect();
+        filters.sort_by_key(|f| f.item_order.unwrap_or(i32::MAX));
+        labels
+            .into_iter()
+            .map(|l| FavoriteView::Label(l.id.clone()))
+            .chain(
+                filters
+                    .into_iter()
+                    .map(|f| FavoriteView::Filter(f.id.clone())),
+            )
+            .collect()
+    }
+
+    fn current_tab(&self) -> ViewTab {
+        ViewTab {
+            project_id: self
+                .projects
+                .get(self.selected_project)
+                .map(|p| p.id.clone()),
+            today: self.today_view_active,
+            favorite: self.favorite_view.clone(),
+            dock_filter: self.dock_filter,
+            selected_task: self.selected_task,
+            view: ProjectView {
+                sort: self.sort_mode,
+                descending: self.sort_descending,
+                filter: self.task_filter,
+            },
+        }
+    }
+
+    /// Writes the live view back into the active tab, if tabs are open.
+    pub fn save_active_tab(&mut self) {
+        let tab = self.current_tab();
+        if let Some(slot) = self.tabs.get_mut(self.active_tab) {
+            *slot = tab;
+        }
+    }
+
+    fn restore_tab(&mut self, tab: ViewTab) {
+        if let Some(i) = tab
+            .project_id
+            .and_then(|id| self.projects.iter().position(|p| p.id == id))
+        {
+            self.selected_project = i;
+        }
+        self.today_view_active = tab.today;
+        self.favorite_view = tab.favorite;
+        self.dock_filter = tab.dock_filter;
+        self.folder_cursor = None;
+        self.dock_focus = None;
+        self.task_search = None;
+        self.sort_mode = tab.view.sort;
+        self.sort_descending = tab.view.descending;
+        self.task_filter = tab.view.filter;
+        self.fetch_completed_if_needed();
+        let visible_len = self.visible_tasks().len();
+        self.selected_task = tab.selected_task.min(visible_len.saturating_sub(1));
+        self.comments_scroll = 0;
+        if matches!(self.active_pane, Pane::Detail | Pane::StatsDock) {
+            self.active_pane = Pane::Tasks;
+        }
+    }
+
+    /// Opens a new tab on the current view, after the active one.
+    fn open_tab(&mut self) {
+        if self.tabs.is_empty() {
+            self.tabs.push(self.current_tab());
+            self.active_tab = 0;
+        } else {
+            self.save_active_tab();
+        }
+        self.active_tab += 1;
+        self.tabs.insert(self.active_tab, self.current_tab());
+    }
+
+    fn close_tab(&mut self) {
+        if self.tabs.len() < 2 {
+            self.flash("Only one tab open");
+            return;
+        }
+        self.tabs.remove(self.active_tab);
+        self.active_tab = self.active_tab.min(self.tabs.len() - 1);
+        let tab = self.tabs[self.active_tab].clone();
+        self.restore_tab(tab);
+        if self.tabs.len() == 1 {
+            self.tabs.clear();
+            self.active_tab = 0;
+        }
+    }
+
+    fn switch_tab(&mut self, step: isize) {
+        if self.tabs.len() < 2 {
+            return;
+        }
+        self.save_active_tab();
+        let len = self.tabs.len() as isize;
+        self.active_tab = (self.active_tab as isize + step).rem_euclid(len) as usize;
+        let tab = self.tabs[self.active_tab].clone();
+        self.restore_tab(tab);
+    }
+
+    fn current_jump(&self) -> Jump {
+        Jump {
+            tab: self.current_tab(),
+            task_id: self.selected_task().map(|t| t.id.clone()),
+        }
+    }
+
+    /// Runs after every key. Once the task list or detail has focus on a
+    /// different list than before, the one left behind goes on the
+    /// jumplist; browsing projects in the sidebar on the way is not a jump.
+    fn track_jump(&mut self) {
+        if !matches!(self.active_pane, Pane::Tasks | Pane::Detail) {
+            return;
+        }
+        let here = self.current_jump();
+        if let Some(prev) = self.jump_here.take()
+            && !prev.tab.same_list(&here.tab)
+        {
+            self.jumps_back.push(prev);
+            if self.jumps_back.len() > MAX_JUMPS {
+                self.jumps_back.remove(0);
+            }
+            self.jumps_forward.clear();
+        }
+        self.jump_here = Some(here);
+    }
+
+    /// Goes back (`Ctrl-o`) or forward (`Alt-→`) through the jumplist,
+    /// reselecting the task that was selected there.
+    fn jump(&mut self, back: bool) {
+        let target = if back {
+            self.jumps_back.pop()
+        } else {
+            self.jumps_forward.pop()
+        };
+        let Some(target) = target else {
+            self.flash(if back {
+                "Start of jumplist"
+            } else {
+                "End of jumplist"
+            });
+            return;
+        };
+        let here = self.jump_here.take().unwrap_or_else(|| self.current_jump());
+        if back {
+            self.jumps_forward.push(here);
+        } else {
+            self.jumps_back.push(here);
+        }
+        self.restore_tab(target.tab);
+        if let Some(pos) = target
+            .task_id
+            .and_then(|id| self.visible_tasks().iter().position(|t| t.id == id))
+        {
+            self.selected_task = pos;
+        }
+        self.active_pane = Pane::Tasks;
+        self.jump_here = Some(self.current_jump());
+    }
+
+    /// Labels for the tab strip, the active tab's from the live view.
+    pub fn tab_titles(&self) -> Vec<String> {
+        let current = self.current_tab();
+        self.tabs
+            .iter()
+            .enumerate()
+            .map(|(i, tab)| {
+                let tab = if i == self.active_tab { &current } else { tab };
+                if let Some(item) = tab.dock_filter {
+                    self.dock_hint(item)
+                } else if tab.today {
+                    "Today".to_string()
+                } else if let Some(name) = tab.favorite.as_ref().and_then(|v| self.favorite_name(v))
+                {
+                    name
+                } else {
+                    tab.project_id
+                        .as_ref()
+                        .and_then(|id| self.projects.iter().find(|p| &p.id == id))
+                        .map_or_else(|| "Tasks".to_string(), |p| p.name.clone())
+                }
+            })
+            .collect()
+    }
+
+    pub fn toggle_overdue_section(&mut self) {
+        self.overdue_section_collapsed = !self.overdue_section_collapsed;
+        if self.overdue_section_collapsed {
+            self.selected_task = 0;
+        }
+    }
+
+    /// `x` on an open recurring task asks how to complete it instead of
+    /// silently advancing the series.
+    fn complete_selected_task(&mut self) {
+        if let Some(subtask) = self.selected_detail_subtask() {
+            let (task_id, was_checked) = (subtask.id.clone(), subtask.checked);
+            // item_close also moves a recurring subtask to its next occurrence.
+            let cmd_type = if was_checked {
+                "item_reopen"
+            } else {
+                "item_close"
+            };
+            self.queue_completion(task_id, was_checked, cmd_type);
+            self.flush_commands();
+            return;
+        }
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let is_recurring = task.due.as_ref().is_some_and(|d| d.is_recurring);
+        if is_recurring && !task.checked {
+            self.show_recurring_prompt = true;
+            return;
+        }
+        self.complete_selected_task_as(if task.checked {
+            "item_reopen"
+        } else {
+            "item_close"
+        });
+    }
+
+    fn answer_recurring_prompt(&mut self, choice: RecurringChoice) {
+        self.show_recurring_prompt = false;
+        match choice {
+            // item_close advances the series; item_complete completes and
+            // archives it.
+            RecurringChoice::Occurrence => self.complete_selected_task_as("item_close"),
+            RecurringChoice::Forever => self.complete_selected_task_as("item_complete"),
+            RecurringChoice::Skip => self.skip_selected_occurrence(),
+        }
+    }
+
+    fn complete_selected_task_as(&mut self, cmd_type: &str) {
+        let (task_id, was_checked) = {
             let visible = self.visible_tasks();
             let Some(task) = visible.get(self.selected_task) else {
                 return;
             };
-            (
-                task.id.clone(),
-                task.checked,
-                task.due.as_ref().map(|d| d.is_recurring).unwrap_or(false),
-            )
+            (task.id.clone(), task.checked)
         };
+        self.queue_completion(task_id, was_checked, cmd_type);
+        self.flush_commands();
+    }
 
+    /// Toggles a task's checked state locally and queues `cmd_type` for it,
+    /// without flushing.
+    fn queue_completion(&mut self, task_id: String, was_checked: bool, cmd_type: &str) {
         let before = self.tasks.iter().find(|t| t.id == task_id).cloned();
         if let Some(t) = self.tasks.iter_mut().find(|t| t.id == task_id) {
             t.checked = !was_checked;
         }
+        if !was_checked && let Some(t) = self.tasks.iter().find(|t| t.id == task_id) {
+            self.fire_task_hook(Hook::TaskComplete, t);
+            self.remember_recent(&task_id, RecentKind::Completed);
+        }
+        self.completions_since_stats += if was_checked { -1 } else { 1 };
 
         let new_len = self.visible_tasks().len();
         if new_len > 0 && self.selected_task >= new_len {
             self.selected_task = new_len - 1;
         }
 
-        let cmd_type = if was_checked {
-            "item_reopen"
-        } else if is_recurring {
-            // item_complete advances the series; item_close would end it.
-            "item_complete"
-        } else {
-            "item_close"
-        };
-
         let uuid = new_uuid();
         self.pending_commands.push(SyncCommand {
             r#type: cmd_type.to_string(),
@@ -1268,8 +3960,67 @@ impl App {
                 },
             );
         }
+    }
+
+    /// Moves a recurring task to its next occurrence without recording a
+    /// completion. The due date moves and the due string is sent unchanged,
+    /// as postponing a recurring task does, so the series keeps its start.
+    fn skip_selected_occurrence(&mut self) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let Some(due) = task.due.as_ref().filter(|d| d.is_recurring) else {
+            return;
+        };
+        let Some(rule) = due.string.clone().filter(|s| !s.is_empty()) else {
+            return;
+        };
+        let Some(current) = ui::dates::due_date(due) else {
+            return;
+        };
+        let Some(next) = crate::recurrence::next_occurrence(&rule, current) else {
+            self.flash(format!(
+                "Can't tell when \"{rule}\" repeats next; edit the date instead"
+            ));
+            return;
+        };
+        let days = (next - current).num_days();
+        let Some(date) = shift_due_days(&due.date, days) else {
+            return;
+        };
+        let datetime = due
+            .datetime
+            .as_deref()
+            .and_then(|dt| shift_due_days(dt, days));
+        let args = serde_json::json!({
+            "id": task.id,
+            "due": { "date": date, "string": rule, "lang": due.lang },
+        });
+        let task_id = task.id.clone();
+        let before = task.clone();
 
+        if let Some(t) = self.tasks.iter_mut().find(|t| t.id == task_id)
+            && let Some(due) = t.due.as_mut()
+        {
+            due.date = date;
+            due.datetime = datetime;
+        }
+        let uuid = new_uuid();
+        self.temp_id_pending.insert(
+            uuid.clone(),
+            OptimisticOp::TaskUpdated {
+                task_id: task_id.clone(),
+                before,
+            },
+        );
+        self.pending_commands.push(SyncCommand {
+            r#type: "item_update".to_string(),
+            temp_id: None,
+            uuid,
+            args,
+        });
         self.flush_commands();
+        self.flash(format!("Skipped to {}", next.format("%a %d %b")));
     }
 
     fn start_input(&mut self) {
@@ -1288,6 +4039,20 @@ impl App {
 
     fn submit_input(&mut self) {
         let content = self.input_buffer.trim().to_string();
+        // A task form's title stays drafted until the whole form is added.
+        if self.task_form.is_none()
+            && let Some(key) = self.draft_key()
+        {
+            self.set_draft(&key, None);
+        }
+
+        if self.quick_add_input {
+            if !content.is_empty() {
+                self.spawn_quick_add(content);
+            }
+            self.cancel_input();
+            return;
+        }
 
         if self.comment_input {
             if !content.is_empty() {
@@ -1298,7 +4063,8 @@ impl App {
         }
 
         if self.editing_field {
-            if !content.is_empty() {
+            // A private note is the one field that can be cleared.
+            if !content.is_empty() || self.detail_field == LOCAL_NOTE_FIELD {
                 self.submit_field_edit(content);
             }
             self.cancel_input();
@@ -1319,6 +4085,15 @@ impl App {
                     form.content = content;
                 }
                 2 => form.due_string = content,
+                6 => form.description = content,
+                7 if !content.is_empty()
+                    && chrono::NaiveDate::parse_from_str(&content, "%Y-%m-%d").is_err() =>
+                {
+                    self.task_form = Some(form);
+                    self.flash("A deadline is a date: YYYY-MM-DD");
+                    return;
+                }
+                7 => form.deadline = content,
                 _ => {}
             }
             form.editing = false;
@@ -1344,18 +4119,35 @@ impl App {
             return;
         }
 
+        self.set_draft(&format!("task:{}", form.project_id), None);
+        self.queue_task_add(form.content.clone(), &form);
+        self.flush_commands();
+        self.cancel_input();
+    }
+
+    /// Adds a task with `content` and the form's project, priority and due
+    /// date, optimistically and without flushing.
+    fn queue_task_add(&mut self, content: String, form: &TaskForm) {
         let project_id = form.project_id.clone();
 
         let temp_id = new_temp_id();
         let uuid = new_uuid();
 
+        let deadline =
+            (!form.deadline.is_empty()).then(|| serde_json::json!({ "date": form.deadline }));
         let optimistic = Task {
             id: temp_id.clone(),
-            content: form.content.clone(),
+            content: content.clone(),
             project_id: project_id.clone(),
             priority: form.priority,
+            section_id: form.section_id.clone(),
+            labels: form.labels.clone(),
+            description: form.description.clone(),
+            deadline: deadline.clone(),
+            responsible_uid: form.responsible_uid.clone(),
             ..Task::default()
         };
+        self.fire_task_hook(Hook::TaskAdd, &optimistic);
         self.tasks.push(optimistic);
         self.temp_id_pending.insert(
             uuid.clone(),
@@ -1364,16 +4156,19 @@ impl App {
             },
         );
 
-        let mut args = serde_json::json!({
-            "content": form.content,
-            "project_id": project_id,
-        });
-        if !form.due_string.is_empty() {
-            args["due_string"] = serde_json::Value::String(form.due_string);
-        }
-        if form.priority > 1 {
-            args["priority"] = serde_json::Value::Number(serde_json::Number::from(form.priority));
-        }
+        let args = serde_json::to_value(ItemAddArgs {
+            content,
+            project_id,
+            priority: (form.priority > 1).then_some(form.priority),
+            due_string: (!form.due_string.is_empty()).then(|| form.due_string.clone()),
+            labels: (!form.labels.is_empty()).then(|| form.labels.clone()),
+            description: (!form.description.is_empty()).then(|| form.description.clone()),
+            parent_id: None,
+            section_id: form.section_id.clone(),
+            deadline,
+            responsible_uid: form.responsible_uid.clone(),
+        })
+        .unwrap_or_default();
 
         self.pending_commands.push(SyncCommand {
             r#type: "item_add".to_string(),
@@ -1381,14 +4176,31 @@ impl App {
             uuid,
             args,
         });
+    }
 
-        self.flush_commands();
-
-        self.task_form = None;
-        self.show_input = false;
-        self.input_buffer.clear();
-        if let InputMode::Vim(_) = self.input_mode {
-            self.input_mode = InputMode::Vim(VimState::Normal);
+    /// Pasting several lines into a new task's title offers to add one task
+    /// per line; any other paste is flattened onto the current line.
+    fn paste_into_input(&mut self) {
+        let adding_task = !self.comment_input
+            && !self.editing_field
+            && self.task_form.as_ref().is_some_and(|f| f.active_field == 0);
+        if !adding_task {
+            match crate::clipboard::paste() {
+                Ok(text) => self.input_buffer.push_str(&text),
+                Err(e) => self.set_error(&e, "pasting from clipboard"),
+            }
+            return;
+        }
+        match crate::clipboard::paste_lines() {
+            Ok(lines) if lines.len() > 1 => {
+                self.confirm = Some(Confirm {
+                    title: "Add tasks".to_string(),
+                    message: format!("Add {} tasks, one per pasted line?", lines.len()),
+                    action: ConfirmAction::AddTasks(lines),
+                });
+            }
+            Ok(lines) => self.input_buffer.push_str(&lines.concat()),
+            Err(e) => self.set_error(&e, "pasting from clipboard"),
         }
     }
 
@@ -1397,7 +4209,11 @@ impl App {
             return;
         };
         let task_id = task.id.clone();
+        self.add_comment(&task_id, content);
+    }
 
+    fn add_comment(&mut self, task_id: &str, content: String) {
+        let task_id = task_id.to_string();
         let temp_id = new_temp_id();
         let uuid = new_uuid();
 
@@ -1411,8 +4227,11 @@ impl App {
             item_id: Some(task_id.clone()),
             ..Comment::default()
         };
-        self.comments.push(optimistic);
-        self.comments_fetch_seq += 1;
+        // `comments` only holds the thread of the selected task.
+        if self.selected_task().map(|t| t.id.as_str()) == Some(task_id.as_str()) {
+            self.comments.push(optimistic);
+            self.comments_fetch_seq += 1;
+        }
 
         self.temp_id_pending.insert(
             uuid.clone(),
@@ -1425,7 +4244,14 @@ impl App {
             r#type: "note_add".to_string(),
             temp_id: Some(temp_id),
             uuid,
-            args: serde_json::json!({ "item_id": task_id, "content": content }),
+            args: match self.reply_notify.take() {
+                Some(uid) => serde_json::json!({
+                    "item_id": task_id,
+                    "content": content,
+                    "uids_to_notify": [uid],
+                }),
+                None => serde_json::json!({ "item_id": task_id, "content": content }),
+            },
         });
         self.flush_commands();
     }
@@ -1437,6 +4263,10 @@ impl App {
             };
             (task.id.clone(), task.clone())
         };
+        if self.detail_field == LOCAL_NOTE_FIELD {
+            self.set_local_note(task_id, value);
+            return;
+        }
 
         let uuid = new_uuid();
         let args = match self.detail_field {
@@ -1451,7 +4281,7 @@ impl App {
                 // optimistic update possible here.
                 serde_json::json!({ "id": task_id, "due_string": value })
             }
-            3 => {
+            5 => {
                 if let Some(t) = self.tasks.iter_mut().find(|t| t.id == task_id) {
                     t.description = value.clone();
                 }
@@ -1498,6 +4328,58 @@ impl App {
     }
 
     pub fn form_edit_field(&mut self) {
+        let Some((field, project_id)) = self
+            .task_form
+            .as_ref()
+            .map(|f| (f.active_field, f.project_id.clone()))
+        else {
+            return;
+        };
+        match field {
+            3 => {
+                self.project_jumper = Some(ProjectJumper {
+                    purpose: JumperPurpose::Form,
+                    ..ProjectJumper::default()
+                });
+                return;
+            }
+            4 => {
+                let sections: Vec<String> = self
+                    .project_sections(&project_id)
+                    .iter()
+                    .map(|s| s.id.clone())
+                    .collect();
+                if sections.is_empty() {
+                    self.flash("This project has no sections");
+                } else if let Some(form) = &mut self.task_form {
+                    form.section_id = cycle_option(&sections, form.section_id.as_ref());
+                }
+                return;
+            }
+            5 => {
+                if self.labels.is_empty() {
+                    self.flash("No labels yet");
+                } else {
+                    self.form_label_picker = Some(0);
+                }
+                return;
+            }
+            8 => {
+                let members: Vec<String> = self
+                    .collaborator_states
+                    .iter()
+                    .filter(|c| c.project_id == project_id && c.state == "active")
+                    .map(|c| c.user_id.clone())
+                    .collect();
+                if members.is_empty() {
+                    self.flash("Only shared projects have assignees");
+                } else if let Some(form) = &mut self.task_form {
+                    form.responsible_uid = cycle_option(&members, form.responsible_uid.as_ref());
+                }
+                return;
+            }
+            _ => {}
+        }
         if let Some(form) = &mut self.task_form {
             match form.active_field {
                 0 => {
@@ -1520,15 +4402,16 @@ impl App {
                         self.input_mode = InputMode::Vim(VimState::Insert);
                     }
                 }
-                3 => {
-                    let cur = self
-                        .projects
-                        .iter()
-                        .position(|p| p.id == form.project_id)
-                        .unwrap_or(0);
-                    let next = (cur + 1) % self.projects.len().max(1);
-                    if let Some(p) = self.projects.get(next) {
-                        form.project_id = p.id.clone();
+                6 | 7 => {
+                    self.input_buffer = if form.active_field == 6 {
+                        form.description.clone()
+                    } else {
+                        form.deadline.clone()
+                    };
+                    form.editing = true;
+                    self.show_input = true;
+                    if let InputMode::Vim(_) = self.input_mode {
+                        self.input_mode = InputMode::Vim(VimState::Insert);
                     }
                 }
                 _ => {}
@@ -1536,16 +4419,176 @@ impl App {
         }
     }
 
-    fn cancel_input(&mut self) {
-        self.show_input = false;
-        self.comment_input = false;
-        self.editing_field = false;
-        self.task_form = None;
-        self.input_buffer.clear();
-        if let InputMode::Vim(_) = self.input_mode {
-            self.input_mode = InputMode::Vim(VimState::Normal);
-        }
-    }
+    /// Moves the task form to another project, dropping the section and
+    /// assignee that belonged to the old one.
+    pub fn set_form_project(&mut self, index: usize) {
+        let Some(project_id) = self.projects.get(index).map(|p| p.id.clone()) else {
+            return;
+        };
+        if let Some(form) = &mut self.task_form
+            && form.project_id != project_id
+        {
+            form.project_id = project_id;
+            form.section_id = None;
+            form.responsible_uid = None;
+        }
+    }
+
+    /// Moves the task open in the detail pane to the top of the project at
+    /// `index`.
+    pub fn move_detail_task_to_project(&mut self, index: usize) {
+        let Some(project_id) = self.projects.get(index).map(|p| p.id.clone()) else {
+            return;
+        };
+        if let Some(task_id) = self.selected_task().map(|t| t.id.clone()) {
+            self.move_task(&task_id, project_id, None);
+        }
+    }
+
+    /// Rows of the section picker: "no section" (`None`), then the open
+    /// task's project's sections.
+    pub fn section_picker_options(&self) -> Vec<Option<&Section>> {
+        let Some(task) = self.selected_task() else {
+            return Vec::new();
+        };
+        std::iter::once(None)
+            .chain(
+                self.project_sections(&task.project_id)
+                    .into_iter()
+                    .map(Some),
+            )
+            .collect()
+    }
+
+    pub fn pick_section(&mut self) {
+        let Some(row) = self.section_picker.take() else {
+            return;
+        };
+        let Some((task_id, project_id)) = self
+            .selected_task()
+            .map(|t| (t.id.clone(), t.project_id.clone()))
+        else {
+            return;
+        };
+        let Some(section_id) = self
+            .section_picker_options()
+            .get(row)
+            .map(|s| s.map(|s| s.id.clone()))
+        else {
+            return;
+        };
+        self.move_task(&task_id, project_id, section_id);
+    }
+
+    /// Re-homes a task with `item_move`, to a section when one is given and
+    /// to the top of `project_id` otherwise. A subtask leaves its parent;
+    /// its own subtasks come along. The task stays open in the detail pane.
+    fn move_task(&mut self, task_id: &str, project_id: String, section_id: Option<String>) {
+        let Some(task) = self.tasks.iter().find(|t| t.id == task_id).cloned() else {
+            return;
+        };
+        if task.project_id == project_id
+            && task.section_id == section_id
+            && task.parent_id.is_none()
+        {
+            return;
+        }
+        if self.task_has_pending_op(task_id) {
+            return;
+        }
+
+        let mut moved = vec![task.id.clone()];
+        let mut i = 0;
+        while let Some(id) = moved.get(i).cloned() {
+            moved.extend(self.task_children(&id).iter().map(|t| t.id.clone()));
+            i += 1;
+        }
+        let mut before = Vec::new();
+        for t in self.tasks.iter_mut().filter(|t| moved.contains(&t.id)) {
+            before.push(t.clone());
+            t.project_id = project_id.clone();
+            t.section_id = section_id.clone();
+            if t.id == task.id {
+                t.parent_id = None;
+            }
+        }
+
+        let args = match &section_id {
+            Some(section_id) => serde_json::json!({ "id": task.id, "section_id": section_id }),
+            None => serde_json::json!({ "id": task.id, "project_id": project_id }),
+        };
+        let uuid = new_uuid();
+        self.temp_id_pending
+            .insert(uuid.clone(), OptimisticOp::TasksChanged { before });
+        self.pending_commands.push(SyncCommand {
+            r#type: "item_move".to_string(),
+            temp_id: None,
+            uuid,
+            args,
+        });
+        self.flush_commands();
+
+        let field = self.detail_field;
+        self.open_detail_for(&task.id);
+        self.detail_field = field;
+
+        let project = self
+            .projects
+            .iter()
+            .find(|p| p.id == project_id)
+            .map_or("", |p| p.name.as_str());
+        let section = section_id
+            .and_then(|id| self.sections.iter().find(|s| s.id == id))
+            .map(|s| format!(" / {}", s.name))
+            .unwrap_or_default();
+        self.flash(format!("Moved to {project}{section}"));
+    }
+
+    /// The project's live sections in their Todoist order.
+    pub fn project_sections(&self, project_id: &str) -> Vec<&Section> {
+        let mut sections: Vec<&Section> = self
+            .sections
+            .iter()
+            .filter(|s| {
+                s.project_id == project_id
+                    && !s.is_archived.unwrap_or(false)
+                    && !s.is_deleted.unwrap_or(false)
+            })
+            .collect();
+        sections.sort_by_key(|s| s.section_order.unwrap_or(i32::MAX));
+        sections
+    }
+
+    /// Personal labels in their Todoist order, for the task form's picker.
+    pub fn form_label_names(&self) -> Vec<&str> {
+        let mut labels: Vec<&Label> = self.labels.iter().collect();
+        labels.sort_by_key(|l| (l.item_order.unwrap_or(i32::MAX), l.name.to_lowercase()));
+        labels.iter().map(|l| l.name.as_str()).collect()
+    }
+
+    pub fn toggle_form_label(&mut self, name: &str) {
+        let Some(form) = &mut self.task_form else {
+            return;
+        };
+        if let Some(i) = form.labels.iter().position(|l| l == name) {
+            form.labels.remove(i);
+        } else {
+            form.labels.push(name.to_string());
+        }
+    }
+
+    fn cancel_input(&mut self) {
+        self.show_input = false;
+        self.comment_input = false;
+        self.reply_notify = None;
+        self.quick_add_input = false;
+        self.editing_field = false;
+        self.task_form = None;
+        self.input_buffer.clear();
+        if let InputMode::Vim(_) = self.input_mode {
+            self.input_mode = InputMode::Vim(VimState::Normal);
+        }
+    }
 
     fn star_selected_project(&mut self) {
         let Some(project) = self.projects.get(self.selected_project) else {
@@ -1578,6 +4621,7 @@ impl App {
     }
 
     fn sort_projects(&mut self) {
+        self.invalidate_project_list();
         let selected_id = self
             .projects
             .get(self.selected_project)
@@ -1642,19 +4686,81 @@ impl App {
         }
     }
 
-    pub fn project_list_entries(&self) -> Vec<ProjectEntry> {
+    pub fn project_list_entries(&self) -> Arc<[ProjectEntry]> {
+        self.project_list().0
+    }
+
+    pub fn visible_nav_items(&self) -> Arc<[ProjectNavItem]> {
+        self.project_list().1
+    }
+
+    fn invalidate_project_list(&mut self) {
+        *self.project_list_cache.get_mut() = None;
+    }
+
+    /// The sidebar rows and the ones the cursor can land on, rebuilt only
+    /// when invalidated or the filter query has changed since.
+    fn project_list(&self) -> (Arc<[ProjectEntry]>, Arc<[ProjectNavItem]>) {
+        let mut cache = self.project_list_cache.borrow_mut();
+        if let Some(c) = cache.as_ref()
+            && c.query == self.project_filter
+        {
+            return (c.entries.clone(), c.nav.clone());
+        }
+        let entries: Arc<[ProjectEntry]> = self.build_project_list_entries().into();
+        let nav: Arc<[ProjectNavItem]> = entries
+            .iter()
+            .filter_map(|e| match e {
+                ProjectEntry::Favorite(view) => Some(ProjectNavItem::Favorite(view.clone())),
+                ProjectEntry::FolderHeader(fi) => Some(ProjectNavItem::Folder(*fi)),
+                ProjectEntry::Project(i) => Some(ProjectNavItem::Project(*i)),
+                ProjectEntry::TodayView => Some(ProjectNavItem::TodayView),
+                _ => None,
+            })
+            .collect();
+        *cache = Some(ProjectListCache {
+            query: self.project_filter.clone(),
+            entries: entries.clone(),
+            nav: nav.clone(),
+        });
+        (entries, nav)
+    }
+
+    fn build_project_list_entries(&self) -> Vec<ProjectEntry> {
         let mut entries = Vec::new();
         let mut in_personal = false;
         let mut last_ws_id: Option<&str> = None;
         let mut last_folder_id: Option<&str> = None;
+        let query = self
+            .project_filter
+            .as_deref()
+            .filter(|q| !q.is_empty())
+            .map(str::to_lowercase);
+
+        if query.is_none() {
+            let favorites = self.favorite_views();
+            if !favorites.is_empty() {
+                entries.push(ProjectEntry::FavoritesHeader);
+                entries.extend(favorites.into_iter().map(ProjectEntry::Favorite));
+                entries.push(ProjectEntry::Separator);
+            }
+        }
 
         for (i, p) in self.projects.iter().enumerate() {
             let ws_id = p.workspace_id.as_deref();
             let folder_id = p.folder_id.as_deref();
 
-            let folder_collapsed = folder_id
-                .map(|fid| self.collapsed_folders.contains(fid))
-                .unwrap_or(false);
+            if let Some(q) = &query
+                && !self.project_matches_filter(p, q)
+            {
+                continue;
+            }
+
+            // A filter shows every match, even inside collapsed folders.
+            let folder_collapsed = query.is_none()
+                && folder_id
+                    .map(|fid| self.collapsed_folders.contains(fid))
+                    .unwrap_or(false);
 
             if ws_id.is_none() {
                 if !in_personal {
@@ -1687,7 +4793,7 @@ impl App {
             if !folder_collapsed {
                 let is_inbox = self.projects[i].is_inbox();
                 entries.push(ProjectEntry::Project(i));
-                if is_inbox {
+                if is_inbox && query.is_none() {
                     entries.push(ProjectEntry::TodayView);
                 }
             }
@@ -1696,6 +4802,112 @@ impl App {
         entries
     }
 
+    /// Case-insensitive substring match on the project name or its folder name.
+    /// `query` must already be lowercased.
+    fn project_matches_filter(&self, project: &Project, query: &str) -> bool {
+        if project.name.to_lowercase().contains(query) {
+            return true;
+        }
+        project
+            .folder_id
+            .as_deref()
+            .and_then(|fid| self.folders.iter().find(|f| f.id == fid))
+            .is_some_and(|f| f.name.to_lowercase().contains(query))
+    }
+
+    /// Workspace / folder / parent-project breadcrumb ending in the project's
+    /// own name — the haystack the fuzzy jumper matches against.
+    pub fn project_path(&self, project: &Project) -> String {
+        let mut parts: Vec<&str> = vec![project.name.as_str()];
+        let mut parent = project.parent_id.as_deref();
+        while let Some(pid) = parent {
+            let Some(p) = self.projects.iter().find(|p| p.id == pid) else {
+                break;
+            };
+            parts.push(p.name.as_str());
+            parent = p.parent_id.as_deref();
+        }
+        if let Some(folder) = project
+            .folder_id
+            .as_deref()
+            .and_then(|fid| self.folders.iter().find(|f| f.id == fid))
+        {
+            parts.push(folder.name.as_str());
+        }
+        if let Some(ws) = project
+            .workspace_id
+            .as_deref()
+            .and_then(|wid| self.workspaces.iter().find(|w| w.id == wid))
+        {
+            parts.push(ws.name.as_str());
+        }
+        parts.reverse();
+        parts.join(" / ")
+    }
+
+    /// Projects ranked against the jumper query, best first, as
+    /// `(project index, breadcrumb)`. An empty query keeps sidebar order.
+    pub fn project_jumper_matches(&self) -> Vec<(usize, String)> {
+        let query = self
+            .project_jumper
+            .as_ref()
+            .map(|j| j.query.as_str())
+            .unwrap_or("");
+        let mut scored: Vec<(i64, usize, String)> = self
+            .projects
+            .iter()
+            .enumerate()
+            .filter_map(|(i, p)| {
+                let path = self.project_path(p);
+                crate::ui::text::fuzzy_score(query, &path).map(|score| (score, i, path))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored.into_iter().map(|(_, i, path)| (i, path)).collect()
+    }
+
+    /// Whether `task` matches the in-list search (case-insensitive substring).
+    pub fn task_matches_search(&self, task: &Task) -> bool {
+        match &self.task_search {
+            Some(search) if !search.query.is_empty() => task
+                .content
+                .to_lowercase()
+                .contains(&search.query.to_lowercase()),
+            _ => false,
+        }
+    }
+
+    /// Moves the task cursor to the next (or previous) search match,
+    /// wrapping around the list. Returns false when nothing matches.
+    pub fn jump_to_search_match(&mut self, forward: bool) -> bool {
+        let visible = self.visible_tasks();
+        let len = visible.len();
+        let target = (1..=len)
+            .map(|step| {
+                if forward {
+                    (self.selected_task + step) % len
+                } else {
+                    (self.selected_task + len - step % len) % len
+                }
+            })
+            .find(|&i| !self.is_context_task(visible[i]) && self.task_matches_search(visible[i]));
+        match target {
+            Some(i) => {
+                self.selected_task = i;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Index of the first project left in the sidebar by the active filter.
+    pub fn first_filtered_project(&self) -> Option<usize> {
+        self.project_list_entries().iter().find_map(|e| match e {
+            ProjectEntry::Project(i) => Some(*i),
+            _ => None,
+        })
+    }
+
     pub fn project_indent(&self, project: &Project) -> usize {
         let base = if project.folder_id.is_some() { 3 } else { 1 };
         base + self.project_depth(&project.id)
@@ -1716,18 +4928,6 @@ impl App {
         depth
     }
 
-    pub fn visible_nav_items(&self) -> Vec<ProjectNavItem> {
-        self.project_list_entries()
-            .into_iter()
-            .filter_map(|e| match e {
-                ProjectEntry::FolderHeader(fi) => Some(ProjectNavItem::Folder(fi)),
-                ProjectEntry::Project(i) => Some(ProjectNavItem::Project(i)),
-                ProjectEntry::TodayView => Some(ProjectNavItem::TodayView),
-                _ => None,
-            })
-            .collect()
-    }
-
     pub fn toggle_folder_collapse(&mut self) {
         let fid = if let Some(fi) = self.folder_cursor {
             self.folders.get(fi).map(|f| f.id.clone())
@@ -1744,6 +4944,8 @@ impl App {
         } else {
             self.collapsed_folders.insert(fid.clone());
         }
+        self.invalidate_project_list();
+        self.save_ui_state();
         if let Some(fi) = self.folders.iter().position(|f| f.id == fid) {
             self.folder_cursor = Some(fi);
         }
@@ -1782,73 +4984,1423 @@ impl App {
         self.flush_commands();
     }
 
-    fn start_comment_input(&mut self) {
-        self.comment_input = true;
-        self.show_input = true;
-        self.input_buffer.clear();
-        if let InputMode::Vim(_) = self.input_mode {
-            self.input_mode = InputMode::Vim(VimState::Insert);
+    /// Turns the checklist in the selected task's description (or a
+    /// delimited title) into real subtasks, see [`crate::split::plan`].
+    fn split_selected_task(&mut self) {
+        let Some(parent) = self.selected_task().filter(|t| !t.checked).cloned() else {
+            return;
+        };
+        let Some(plan) = crate::split::plan(&parent.content, &parent.description) else {
+            self.flash("Nothing to split: add a list to the description or use a; b");
+            return;
+        };
+
+        let mut args = serde_json::json!({ "id": parent.id });
+        if let Some(content) = &plan.content {
+            args["content"] = serde_json::Value::String(content.clone());
+        }
+        if let Some(description) = &plan.description {
+            args["description"] = serde_json::Value::String(description.clone());
+        }
+        if let Some(t) = self.tasks.iter_mut().find(|t| t.id == parent.id) {
+            if let Some(content) = plan.content {
+                t.content = content;
+            }
+            if let Some(description) = plan.description {
+                t.description = description;
+            }
+        }
+        if args.as_object().is_some_and(|a| a.len() > 1) {
+            let uuid = new_uuid();
+            self.temp_id_pending.insert(
+                uuid.clone(),
+                OptimisticOp::TaskUpdated {
+                    task_id: parent.id.clone(),
+                    before: parent.clone(),
+                },
+            );
+            self.pending_commands.push(SyncCommand {
+                r#type: "item_update".to_string(),
+                temp_id: None,
+                uuid,
+                args,
+            });
+        }
+
+        let count = plan.subtasks.len();
+        let last_order = self
+            .tasks
+            .iter()
+            .filter(|t| t.parent_id.as_deref() == Some(parent.id.as_str()))
+            .map(|t| t.child_order)
+            .max()
+            .unwrap_or(0);
+        for (i, content) in plan.subtasks.into_iter().enumerate() {
+            let temp_id = new_temp_id();
+            let uuid = new_uuid();
+            let optimistic = Task {
+                id: temp_id.clone(),
+                content: content.clone(),
+                project_id: parent.project_id.clone(),
+                section_id: parent.section_id.clone(),
+                parent_id: Some(parent.id.clone()),
+                child_order: last_order + i as i32 + 1,
+                ..Task::default()
+            };
+            self.fire_task_hook(Hook::TaskAdd, &optimistic);
+            self.tasks.push(optimistic);
+            self.temp_id_pending.insert(
+                uuid.clone(),
+                OptimisticOp::TaskAdded {
+                    temp_id: temp_id.clone(),
+                },
+            );
+            self.pending_commands.push(SyncCommand {
+                r#type: "item_add".to_string(),
+                temp_id: Some(temp_id),
+                uuid,
+                args: serde_json::json!({
+                    "content": content,
+                    "project_id": parent.project_id,
+                    "section_id": parent.section_id,
+                    "parent_id": parent.id,
+                }),
+            });
         }
+
+        self.collapsed.remove(&parent.id);
+        self.flush_commands();
+        self.flash(format!("Split into {count} subtasks"));
     }
 
-    fn start_field_edit(&mut self) {
-        let Some(task) = self.selected_task() else {
+    /// Detaches the selected subtask from its parent, leaving it at the top
+    /// level of the same section (or project) and still selected.
+    fn promote_selected_task(&mut self) {
+        let Some(task) = self.selected_task().cloned() else {
+            return;
+        };
+        if task.parent_id.is_none() {
+            self.flash("Not a subtask");
+            return;
+        }
+        if self.task_has_pending_op(&task.id) {
+            return;
+        }
+
+        if let Some(t) = self.tasks.iter_mut().find(|t| t.id == task.id) {
+            t.parent_id = None;
+        }
+        // Moving into the task's own section/project is how the API detaches
+        // it; there is no "parent_id: null" form of item_move.
+        let args = match &task.section_id {
+            Some(section_id) => serde_json::json!({ "id": task.id, "section_id": section_id }),
+            None => serde_json::json!({ "id": task.id, "project_id": task.project_id }),
+        };
+        let uuid = new_uuid();
+        self.temp_id_pending.insert(
+            uuid.clone(),
+            OptimisticOp::TaskUpdated {
+                task_id: task.id.clone(),
+                before: task.clone(),
+            },
+        );
+        self.pending_commands.push(SyncCommand {
+            r#type: "item_move".to_string(),
+            temp_id: None,
+            uuid,
+            args,
+        });
+        self.flush_commands();
+
+        if let Some(pos) = self.visible_tasks().iter().position(|t| t.id == task.id) {
+            self.selected_task = pos;
+        }
+        self.flash("Moved to top level");
+    }
+
+    fn postpone_selected_task(&mut self, target: Postpone) {
+        let Some(task_id) = self
+            .selected_task()
+            .filter(|t| !t.checked)
+            .map(|t| t.id.clone())
+        else {
             return;
         };
+        self.queue_postpone(&task_id, target);
+        self.flush_commands();
+        self.flash(format!("Postponed to {}", target.due_string()));
+    }
 
-        if self.detail_field == 1 {
-            self.priority_selection = task.priority;
-            self.show_priority_picker = true;
+    /// Reschedules a task via `due_string`, updating the local due date right
+    /// away. Recurring tasks instead get an explicit `due` object that keeps
+    /// their recurrence string, so snoozing doesn't end the series. Queues the
+    /// command without flushing.
+    fn queue_postpone(&mut self, task_id: &str, target: Postpone) {
+        let Some(task) = self.tasks.iter_mut().find(|t| t.id == task_id) else {
             return;
+        };
+        let before = task.clone();
+
+        let new_date = target
+            .target_date(ui::dates::today())
+            .format("%Y-%m-%d")
+            .to_string();
+        let args = match before.due.as_ref().filter(|d| d.is_recurring) {
+            Some(due) => serde_json::json!({
+                "id": task_id,
+                "due": { "date": new_date, "string": due.string, "lang": due.lang },
+            }),
+            None => serde_json::json!({ "id": task_id, "due_string": target.due_string() }),
+        };
+
+        let mut due = task.due.clone().unwrap_or_default();
+        due.date = new_date;
+        due.datetime = None;
+        if !due.is_recurring {
+            due.string = Some(target.due_string().to_string());
         }
+        task.due = Some(due);
 
-        let prefill = match self.detail_field {
-            0 => task.content.clone(),
-            2 => task
-                .due
-                .as_ref()
-                .and_then(|d| d.string.clone())
-                .unwrap_or_default(),
-            3 => task.description.clone(),
-            _ => return,
+        let uuid = new_uuid();
+        self.temp_id_pending.insert(
+            uuid.clone(),
+            OptimisticOp::TaskUpdated {
+                task_id: task_id.to_string(),
+                before,
+            },
+        );
+        self.pending_commands.push(SyncCommand {
+            r#type: "item_update".to_string(),
+            temp_id: None,
+            uuid,
+            args,
+        });
+    }
+
+    /// Moves the selected task within today's agenda, renumbering the whole
+    /// day so the order matches the official Today view.
+    fn move_day_order(&mut self, delta: i32) {
+        if !self.today_view_active {
+            return;
+        }
+        let today = ui::dates::today();
+        let mut ids: Vec<String> = self
+            .visible_tasks()
+            .into_iter()
+            .filter(|t| t.due.as_ref().and_then(ui::dates::due_date) == Some(today))
+            .map(|t| t.id.clone())
+            .collect();
+        let Some(selected_id) = self.selected_task().map(|t| t.id.clone()) else {
+            return;
         };
-        self.editing_field = true;
-        self.show_input = true;
-        self.input_buffer = prefill;
-        if let InputMode::Vim(_) = self.input_mode {
-            self.input_mode = InputMode::Vim(VimState::Insert);
+        let Some(from) = ids.iter().position(|id| *id == selected_id) else {
+            self.flash("Only tasks due today can be reordered");
+            return;
+        };
+        let to = from as i32 + delta;
+        if to < 0 || to as usize >= ids.len() {
+            return;
+        }
+        if ids.iter().any(|id| self.task_has_pending_op(id)) {
+            return;
         }
+        ids.swap(from, to as usize);
+
+        let mut before = Vec::new();
+        let mut ids_to_orders = serde_json::Map::new();
+        for (order, id) in ids.iter().enumerate() {
+            let order = order as i32 + 1;
+            if let Some(t) = self.tasks.iter_mut().find(|t| t.id == *id) {
+                before.push((id.clone(), t.day_order));
+                t.day_order = Some(order);
+            }
+            ids_to_orders.insert(id.clone(), order.into());
+        }
+
+        let uuid = new_uuid();
+        self.temp_id_pending
+            .insert(uuid.clone(), OptimisticOp::DayOrdersUpdated { before });
+        self.pending_commands.push(SyncCommand {
+            r#type: "item_update_day_orders".to_string(),
+            temp_id: None,
+            uuid,
+            args: serde_json::json!({ "ids_to_orders": ids_to_orders }),
+        });
+        self.flush_commands();
+
+        if let Some(pos) = self
+            .visible_tasks()
+            .iter()
+            .position(|t| t.id == selected_id)
+        {
+            self.selected_task = pos;
+        }
+    }
+
+    /// Moves the section holding the selected task up or down among the
+    /// project's sections, renumbering them all in one `section_reorder`.
+    fn move_section(&mut self, delta: i32) {
+        if self.today_view_active || self.favorite_view.is_some() || self.dock_filter.is_some() {
+            return;
+        }
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let Some(section_id) = task.section_id.clone() else {
+            self.flash("Select a task in a section to move the section");
+            return;
+        };
+        let project_id = task.project_id.clone();
+        let selected_id = task.id.clone();
+
+        let mut ids: Vec<String> = self
+            .project_sections(&project_id)
+            .iter()
+            .map(|s| s.id.clone())
+            .collect();
+        let Some(from) = ids.iter().position(|id| *id == section_id) else {
+            return;
+        };
+        let to = from as i32 + delta;
+        if to < 0 || to as usize >= ids.len() {
+            return;
+        }
+        ids.swap(from, to as usize);
+
+        let mut before = Vec::new();
+        let mut orders = Vec::new();
+        for (order, id) in ids.iter().enumerate() {
+            let order = order as i32 + 1;
+            if let Some(s) = self.sections.iter_mut().find(|s| s.id == *id) {
+                before.push((id.clone(), s.section_order));
+                s.section_order = Some(order);
+            }
+            orders.push(serde_json::json!({ "id": id, "section_order": order }));
+        }
+
+        let uuid = new_uuid();
+        self.temp_id_pending
+            .insert(uuid.clone(), OptimisticOp::SectionOrdersUpdated { before });
+        self.pending_commands.push(SyncCommand {
+            r#type: "section_reorder".to_string(),
+            temp_id: None,
+            uuid,
+            args: serde_json::json!({ "sections": orders }),
+        });
+        self.flush_commands();
+
+        if let Some(pos) = self
+            .visible_tasks()
+            .iter()
+            .position(|t| t.id == selected_id)
+        {
+            self.selected_task = pos;
+        }
+    }
+
+    /// Moves the sidebar entry under the cursor: a project among its
+    /// siblings, or a favorite label or filter among the favorites of its kind.
+    fn move_sidebar_item(&mut self, delta: i32) {
+        if self.folder_cursor.is_some() || self.today_view_active {
+            return;
+        }
+        match self.favorite_view.clone() {
+            Some(view) => self.move_favorite(view, delta),
+            None => self.move_project(delta),
+        }
+    }
+
+    /// Siblings share a parent, workspace and folder. They are renumbered in
+    /// the order the sidebar shows them, favorites first, so other apps
+    /// agree with it afterwards; the Inbox always stays on top.
+    fn move_project(&mut self, delta: i32) {
+        let Some(project) = self.projects.get(self.selected_project).cloned() else {
+            return;
+        };
+        if project.is_inbox() {
+            self.flash("The Inbox always comes first");
+            return;
+        }
+        // `projects` is already in sidebar order.
+        let mut ids: Vec<String> = self
+            .projects
+            .iter()
+            .filter(|p| {
+                !p.is_inbox()
+                    && p.parent_id == project.parent_id
+                    && p.workspace_id == project.workspace_id
+                    && p.folder_id == project.folder_id
+            })
+            .map(|p| p.id.clone())
+            .collect();
+        let Some(from) = ids.iter().position(|id| *id == project.id) else {
+            return;
+        };
+        let to = from as i32 + delta;
+        if to < 0 || to as usize >= ids.len() {
+            return;
+        }
+        let neighbor = self.projects.iter().find(|p| p.id == ids[to as usize]);
+        if neighbor.is_some_and(|n| n.is_favorite != project.is_favorite) {
+            self.flash("Favorite projects stay above the rest");
+            return;
+        }
+        ids.swap(from, to as usize);
+
+        let mut before = Vec::new();
+        let mut orders = Vec::new();
+        for (order, id) in ids.iter().enumerate() {
+            let order = order as i32 + 1;
+            if let Some(p) = self.projects.iter_mut().find(|p| p.id == *id) {
+                before.push((id.clone(), p.child_order));
+                p.child_order = order;
+            }
+            orders.push(serde_json::json!({ "id": id, "child_order": order }));
+        }
+        self.sort_projects();
+
+        let uuid = new_uuid();
+        self.temp_id_pending
+            .insert(uuid.clone(), OptimisticOp::ProjectOrdersUpdated { before });
+        self.pending_commands.push(SyncCommand {
+            r#type: "project_reorder".to_string(),
+            temp_id: None,
+            uuid,
+            args: serde_json::json!({ "projects": orders }),
+        });
+        self.flush_commands();
+    }
+
+    /// Favorites keep the `item_order` slots they already had, reshuffled,
+    /// so non-favorite labels and filters don't move.
+    fn move_favorite(&mut self, view: FavoriteView, delta: i32) {
+        let labels = matches!(view, FavoriteView::Label(_));
+        let mut entries: Vec<(String, Option<i32>)> = self
+            .favorite_views()
+            .into_iter()
+            .filter_map(|v| match v {
+                FavoriteView::Label(id) if labels => {
+                    let order = self.labels.iter().find(|l| l.id == id)?.item_order;
+                    Some((id, order))
+                }
+                FavoriteView::Filter(id) if !labels => {
+                    let order = self.filters.iter().find(|f| f.id == id)?.item_order;
+                    Some((id, order))
+                }
+                _ => None,
+            })
+            .collect();
+        let id = match &view {
+            FavoriteView::Label(id) | FavoriteView::Filter(id) => id,
+        };
+        let Some(from) = entries.iter().position(|(e, _)| e == id) else {
+            return;
+        };
+        let to = from as i32 + delta;
+        if to < 0 || to as usize >= entries.len() {
+            return;
+        }
+
+        let mut slots: Vec<i32> = entries.iter().filter_map(|(_, o)| *o).collect();
+        slots.sort_unstable();
+        slots.dedup();
+        if slots.len() != entries.len() {
+            slots = (1..=entries.len() as i32).collect();
+        }
+        let before = entries.clone();
+        entries.swap(from, to as usize);
+
+        self.invalidate_project_list();
+        let mut mapping = serde_json::Map::new();
+        for ((id, _), order) in entries.iter().zip(slots) {
+            if labels {
+                if let Some(l) = self.labels.iter_mut().find(|l| &l.id == id) {
+                    l.item_order = Some(order);
+                }
+            } else if let Some(f) = self.filters.iter_mut().find(|f| &f.id == id) {
+                f.item_order = Some(order);
+            }
+            mapping.insert(id.clone(), order.into());
+        }
+
+        let uuid = new_uuid();
+        self.temp_id_pending.insert(
+            uuid.clone(),
+            OptimisticOp::FavoriteOrdersUpdated { labels, before },
+        );
+        self.pending_commands.push(SyncCommand {
+            r#type: if labels {
+                "label_update_orders"
+            } else {
+                "filter_update_orders"
+            }
+            .to_string(),
+            temp_id: None,
+            uuid,
+            args: serde_json::json!({ "id_order_mapping": mapping }),
+        });
+        self.flush_commands();
+    }
+
+    /// Asks for confirmation before moving every task listed under the
+    /// Overdue dock filter to today.
+    fn request_reschedule_overdue(&mut self) {
+        if self.dock_filter != Some(DockItem::DueOverdue) {
+            return;
+        }
+        let ids: Vec<String> = self
+            .visible_tasks()
+            .into_iter()
+            .filter(|t| !t.checked && !self.is_context_task(t))
+            .map(|t| t.id.clone())
+            .collect();
+        if ids.is_empty() {
+            self.flash("No overdue tasks");
+            return;
+        }
+        let noun = if ids.len() == 1 { "task" } else { "tasks" };
+        self.confirm = Some(Confirm {
+            title: "Reschedule overdue".to_string(),
+            message: format!("Move {} overdue {noun} to today?", ids.len()),
+            action: ConfirmAction::RescheduleOverdue(ids),
+        });
+    }
+
+    /// Open overdue tasks in the selected project, subtasks included.
+    fn project_overdue_tasks(&self) -> Vec<&Task> {
+        let Some(pid) = self.projects.get(self.selected_project).map(|p| &p.id) else {
+            return Vec::new();
+        };
+        let today = ui::dates::today();
+        let mut tasks: Vec<&Task> = self
+            .tasks
+            .iter()
+            .filter(|t| {
+                !t.is_deleted
+                    && !t.checked
+                    && &t.project_id == pid
+                    && t.due
+                        .as_ref()
+                        .and_then(ui::dates::due_date)
+                        .is_some_and(|d| d < today)
+            })
+            .collect();
+        tasks.sort_by_key(|t| t.due.as_ref().map(ui::dates::due_sort_key));
+        tasks
+    }
+
+    fn open_recurrence_editor(&mut self) {
+        let Some(task) = self.selected_task().filter(|t| !t.checked) else {
+            return;
+        };
+        let rule = Recurrence::starting(ui::dates::today());
+        self.recurrence_editor = Some(RecurrenceEditor {
+            task_id: task.id.clone(),
+            day_cursor: rule.weekdays.iter().position(|on| *on).unwrap_or(0),
+            rule,
+            row: 0,
+        });
+    }
+
+    /// Sends the built rule as the task's `due_string`. Like a typed due
+    /// string, the server parses it and returns the new due date.
+    fn apply_recurrence(&mut self) {
+        let Some(editor) = self.recurrence_editor.take() else {
+            return;
+        };
+        let Some(before) = self.tasks.iter().find(|t| t.id == editor.task_id).cloned() else {
+            return;
+        };
+        let due_string = editor.rule.due_string();
+        let uuid = new_uuid();
+        self.temp_id_pending.insert(
+            uuid.clone(),
+            OptimisticOp::TaskUpdated {
+                task_id: editor.task_id.clone(),
+                before,
+            },
+        );
+        self.pending_commands.push(SyncCommand {
+            r#type: "item_update".to_string(),
+            temp_id: None,
+            uuid,
+            args: serde_json::json!({ "id": editor.task_id, "due_string": due_string }),
+        });
+        self.flush_commands();
+        self.flash(format!("Repeats {due_string}"));
+    }
+
+    fn start_find_replace(&mut self) {
+        if self.today_view_active
+            || self.favorite_view.is_some()
+            || self.dock_filter.is_some()
+            || self.folder_cursor.is_some()
+        {
+            self.flash("Open a project to find & replace in it");
+            return;
+        }
+        self.find_replace = Some(FindReplace::default());
+    }
+
+    /// Open tasks in the selected project whose title contains the search
+    /// text (case-sensitive), each with its title after the replacement.
+    pub fn find_replace_matches(&self) -> Vec<(&Task, String)> {
+        let Some(fr) = &self.find_replace else {
+            return Vec::new();
+        };
+        let Some(pid) = self.projects.get(self.selected_project).map(|p| &p.id) else {
+            return Vec::new();
+        };
+        if fr.find.is_empty() {
+            return Vec::new();
+        }
+        self.tasks
+            .iter()
+            .filter(|t| {
+                !t.is_deleted && !t.checked && &t.project_id == pid && t.content.contains(&fr.find)
+            })
+            .map(|t| (t, t.content.replace(&fr.find, &fr.replace)))
+            .filter(|(t, renamed)| !renamed.trim().is_empty() && *renamed != t.content)
+            .collect()
+    }
+
+    /// Sends one `item_update` per previewed title, in a single batch.
+    fn apply_find_replace(&mut self) {
+        let renames: Vec<(Task, String)> = self
+            .find_replace_matches()
+            .into_iter()
+            .map(|(t, renamed)| (t.clone(), renamed))
+            .collect();
+        if renames.is_empty() {
+            return;
+        }
+        self.find_replace = None;
+
+        let count = renames.len();
+        for (before, renamed) in renames {
+            if let Some(t) = self.tasks.iter_mut().find(|t| t.id == before.id) {
+                t.content = renamed.clone();
+            }
+            let uuid = new_uuid();
+            let args = serde_json::json!({ "id": before.id, "content": renamed });
+            self.temp_id_pending.insert(
+                uuid.clone(),
+                OptimisticOp::TaskUpdated {
+                    task_id: before.id.clone(),
+                    before,
+                },
+            );
+            self.pending_commands.push(SyncCommand {
+                r#type: "item_update".to_string(),
+                temp_id: None,
+                uuid,
+                args,
+            });
+        }
+        self.flush_commands();
+        let noun = if count == 1 { "task" } else { "tasks" };
+        self.flash(format!("Renamed {count} {noun}"));
+    }
+
+    /// Labels on tasks that aren't personal labels, with how many tasks carry
+    /// each, by name.
+    pub fn shared_labels(&self) -> Vec<(String, usize)> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for task in self.tasks.iter().filter(|t| !t.is_deleted) {
+            for name in &task.labels {
+                if !self
+                    .labels
+                    .iter()
+                    .any(|l| l.name.eq_ignore_ascii_case(name))
+                {
+                    *counts.entry(name.as_str()).or_default() += 1;
+                }
+            }
+        }
+        counts
+            .into_iter()
+            .map(|(name, count)| (name.to_string(), count))
+            .collect()
+    }
+
+    /// Sends the confirmed `shared_label_rename` or `shared_label_remove`,
+    /// updating every task carrying the label right away.
+    fn apply_label_change(&mut self) {
+        let Some(manager) = self.label_manager.take() else {
+            return;
+        };
+        let Some(change) = manager.confirm else {
+            return;
+        };
+        let Some((name, _)) = self.shared_labels().into_iter().nth(manager.selection) else {
+            return;
+        };
+
+        let mut before = Vec::new();
+        for task in self
+            .tasks
+            .iter_mut()
+            .filter(|t| !t.is_deleted && t.labels.contains(&name))
+        {
+            before.push(task.clone());
+            task.labels.retain(|l| l != &name);
+            if let LabelChange::Rename(new_name) = &change
+                && !task.labels.contains(new_name)
+            {
+                task.labels.push(new_name.clone());
+            }
+        }
+        let count = before.len();
+        let noun = if count == 1 { "task" } else { "tasks" };
+
+        let (r#type, args, message) = match &change {
+            LabelChange::Rename(new_name) => (
+                "shared_label_rename",
+                serde_json::json!({ "name_old": name, "name_new": new_name }),
+                format!("Renamed @{name} to @{new_name} on {count} {noun}"),
+            ),
+            LabelChange::Remove => (
+                "shared_label_remove",
+                serde_json::json!({ "name": name }),
+                format!("Removed @{name} from {count} {noun}"),
+            ),
+        };
+        let uuid = new_uuid();
+        self.temp_id_pending
+            .insert(uuid.clone(), OptimisticOp::TasksChanged { before });
+        self.pending_commands.push(SyncCommand {
+            r#type: r#type.to_string(),
+            temp_id: None,
+            uuid,
+            args,
+        });
+        self.flush_commands();
+        self.flash(message);
+    }
+
+    /// Overdue count behind the project's triage banner; zero outside plain
+    /// project views.
+    pub fn project_overdue_count(&self) -> usize {
+        if self.today_view_active || self.favorite_view.is_some() || self.dock_filter.is_some() {
+            return 0;
+        }
+        self.project_overdue_tasks().len()
+    }
+
+    fn start_triage(&mut self) {
+        if self.project_overdue_count() == 0 {
+            self.flash("No overdue tasks");
+            return;
+        }
+        let task_ids = self
+            .project_overdue_tasks()
+            .into_iter()
+            .map(|t| t.id.clone())
+            .collect();
+        self.triage = Some(Triage {
+            task_ids,
+            index: 0,
+            handled: 0,
+            confirm_delete: false,
+        });
+    }
+
+    /// The task under review; tasks that vanished or were completed elsewhere
+    /// since triage began are stepped over.
+    pub fn triage_task(&self) -> Option<&Task> {
+        let triage = self.triage.as_ref()?;
+        let id = triage.task_ids.get(triage.index)?;
+        self.tasks.iter().find(|t| &t.id == id)
+    }
+
+    fn triage_step(&mut self, action: TriageAction) {
+        let Some(task) = self.triage_task() else {
+            self.finish_triage();
+            return;
+        };
+        let task_id = task.id.clone();
+        let Some(triage) = self.triage.as_mut() else {
+            return;
+        };
+        if action == TriageAction::Delete && !triage.confirm_delete {
+            triage.confirm_delete = true;
+            return;
+        }
+        triage.confirm_delete = false;
+        if action != TriageAction::Skip {
+            triage.handled += 1;
+        }
+        triage.index += 1;
+
+        match action {
+            TriageAction::Postpone(target) => self.queue_postpone(&task_id, target),
+            // item_close moves overdue recurring tasks on to their next
+            // occurrence and completes the rest.
+            TriageAction::Complete => self.queue_completion(task_id, false, "item_close"),
+            TriageAction::Delete => self.queue_delete(&task_id),
+            TriageAction::Skip => {}
+        }
+        self.flush_commands();
+
+        let tasks = &self.tasks;
+        if let Some(triage) = self.triage.as_mut() {
+            while triage
+                .task_ids
+                .get(triage.index)
+                .is_some_and(|id| !tasks.iter().any(|t| &t.id == id && !t.checked))
+            {
+                triage.index += 1;
+            }
+        }
+        if self.triage_task().is_none() {
+            self.finish_triage();
+        }
+        let visible_len = self.visible_tasks().len();
+        self.selected_task = self.selected_task.min(visible_len.saturating_sub(1));
+    }
+
+    fn finish_triage(&mut self) {
+        let Some(triage) = self.triage.take() else {
+            return;
+        };
+        let noun = if triage.handled == 1 { "task" } else { "tasks" };
+        self.flash(format!("Triaged {} overdue {noun}", triage.handled));
+    }
+
+    /// Removes a task locally and queues `item_delete`, without flushing.
+    fn queue_delete(&mut self, task_id: &str) {
+        let Some(pos) = self.tasks.iter().position(|t| t.id == task_id) else {
+            return;
+        };
+        let snapshot = self.tasks.remove(pos);
+        let uuid = new_uuid();
+        self.temp_id_pending
+            .insert(uuid.clone(), OptimisticOp::TaskRemoved { snapshot });
+        self.pending_commands.push(SyncCommand {
+            r#type: "item_delete".to_string(),
+            temp_id: None,
+            uuid,
+            args: serde_json::json!({ "id": task_id }),
+        });
+    }
+
+    fn accept_confirm(&mut self) {
+        let Some(confirm) = self.confirm.take() else {
+            return;
+        };
+        match confirm.action {
+            ConfirmAction::RescheduleOverdue(ids) => {
+                for id in &ids {
+                    self.queue_postpone(id, Postpone::Today);
+                }
+                // Every command targets a different task, so one batched
+                // flush keeps failure-revert order-independent.
+                self.flush_commands();
+                let visible_len = self.visible_tasks().len();
+                self.selected_task = self.selected_task.min(visible_len.saturating_sub(1));
+                self.flash(format!("Rescheduled {} tasks to today", ids.len()));
+            }
+            ConfirmAction::AddTasks(lines) => {
+                let Some(form) = self.task_form.take() else {
+                    return;
+                };
+                let count = lines.len();
+                for content in lines {
+                    self.queue_task_add(content, &form);
+                }
+                self.flush_commands();
+                self.cancel_input();
+                self.flash(format!("Added {count} tasks"));
+            }
+            ConfirmAction::RestoreDraft(text) => self.input_buffer = text,
+            ConfirmAction::Quit(_) => {
+                warn!(
+                    pending = self.pending_op_count(),
+                    "quitting with unsynced changes"
+                );
+                self.running = false;
+            }
+        }
+    }
+
+    pub fn decline_confirm(&mut self) {
+        match self.confirm.take().map(|c| c.action) {
+            Some(ConfirmAction::Quit(commands)) if !commands.is_empty() => {
+                self.show_error(sync_failed_error(commands));
+            }
+            Some(ConfirmAction::RestoreDraft(_)) => {
+                if let Some(key) = self.draft_context.clone() {
+                    self.set_draft(&key, None);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Quits once every change has synced. With some still in flight, sends
+    /// them and waits up to [`QUIT_SYNC_WAIT`]; quitting again, or `Ctrl-c`
+    /// at the prompt, doesn't wait.
+    fn request_quit(&mut self) {
+        info!("quit requested");
+        self.save_ui_state();
+        self.save_drafts();
+        let asked = matches!(
+            self.confirm,
+            Some(Confirm {
+                action: ConfirmAction::Quit(_),
+                ..
+            })
+        );
+        if asked || self.quit_deadline.is_some() || self.pending_op_count() == 0 {
+            self.running = false;
+            return;
+        }
+        info!(
+            pending = self.pending_op_count(),
+            "waiting for changes to sync"
+        );
+        self.send_commands();
+        self.quit_deadline = Some(Instant::now() + QUIT_SYNC_WAIT);
+        self.flash("Syncing changes before quitting…");
+    }
+
+    /// Finishes a waiting quit, or asks before dropping changes that failed
+    /// to send or weren't acknowledged in time.
+    fn tick_quit(&mut self) {
+        let Some(deadline) = self.quit_deadline else {
+            return;
+        };
+        if self.pending_op_count() == 0 {
+            self.running = false;
+            return;
+        }
+        let failed = matches!(
+            &self.error,
+            Some(AppError {
+                retry: Some(RetryAction::Commands(_)),
+                ..
+            })
+        );
+        if !failed && Instant::now() < deadline {
+            return;
+        }
+        self.quit_deadline = None;
+        let commands = match self.error.take() {
+            Some(AppError {
+                retry: Some(RetryAction::Commands(commands)),
+                ..
+            }) => commands,
+            other => {
+                self.error = other;
+                Vec::new()
+            }
+        };
+        let count = self.pending_op_count();
+        let noun = if count == 1 { "change" } else { "changes" };
+        self.confirm = Some(Confirm {
+            title: "Unsynced changes".to_string(),
+            message: format!("{count} {noun} not yet synced — quit anyway?"),
+            action: ConfirmAction::Quit(commands),
+        });
+    }
+
+    fn start_quick_add(&mut self) {
+        self.quick_add_input = true;
+        self.show_input = true;
+        self.input_buffer.clear();
+        if let InputMode::Vim(_) = self.input_mode {
+            self.input_mode = InputMode::Vim(VimState::Insert);
+        }
+    }
+
+    /// Sends a quick-add line to Todoist's own parser. Nothing is added
+    /// optimistically: the project, labels and date aren't known until the
+    /// server has read them.
+    fn spawn_quick_add(&mut self, text: String) {
+        let client = Arc::clone(&self.client);
+        let tx = self.bg_tx.clone();
+        self.spawn_job(async move {
+            let task = client.quick_add(&text).await.map(Box::new);
+            let _ = tx.send(BgResult::QuickAdded(task)).await;
+        });
+    }
+
+    fn start_comment_input(&mut self) {
+        self.comment_input = true;
+        self.show_input = true;
+        self.input_buffer.clear();
+        if let InputMode::Vim(_) = self.input_mode {
+            self.input_mode = InputMode::Vim(VimState::Insert);
+        }
+    }
+
+    fn start_field_edit(&mut self) {
+        if let Some(subtask) = self.selected_detail_subtask() {
+            let task_id = subtask.id.clone();
+            self.open_detail_for(&task_id);
+            return;
+        }
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+
+        match self.detail_field {
+            1 => {
+                self.priority_selection = task.priority;
+                self.show_priority_picker = true;
+                return;
+            }
+            3 => {
+                self.project_jumper = Some(ProjectJumper {
+                    purpose: JumperPurpose::MoveTask,
+                    ..ProjectJumper::default()
+                });
+                return;
+            }
+            4 => {
+                let current = self
+                    .project_sections(&task.project_id)
+                    .iter()
+                    .position(|s| task.section_id.as_deref() == Some(s.id.as_str()))
+                    .map_or(0, |i| i + 1);
+                self.section_picker = Some(current);
+                return;
+            }
+            _ => {}
+        }
+
+        let prefill = match self.detail_field {
+            0 => task.content.clone(),
+            2 => task
+                .due
+                .as_ref()
+                .and_then(|d| d.string.clone())
+                .unwrap_or_default(),
+            5 => task.description.clone(),
+            LOCAL_NOTE_FIELD => self.local_notes.get(&task.id).cloned().unwrap_or_default(),
+            _ => return,
+        };
+        self.editing_field = true;
+        self.show_input = true;
+        self.input_buffer = prefill;
+        if let InputMode::Vim(_) = self.input_mode {
+            self.input_mode = InputMode::Vim(VimState::Insert);
+        }
+    }
+
+    /// True while typing a date: the task form's due date or deadline, or
+    /// the detail pane's due date.
+    pub fn editing_due_field(&self) -> bool {
+        match &self.task_form {
+            Some(form) => form.editing && matches!(form.active_field, 2 | 7),
+            None => self.show_input && self.editing_field && self.detail_field == 2,
+        }
+    }
+
+    /// Opens the calendar on the date being typed, else the task's current
+    /// due date, else today.
+    pub fn open_date_picker(&mut self) {
+        let typed = chrono::NaiveDate::parse_from_str(self.input_buffer.trim(), "%Y-%m-%d").ok();
+        let current = match self.task_form {
+            Some(_) => None,
+            None => self
+                .selected_task()
+                .and_then(|t| t.due.as_ref())
+                .and_then(ui::dates::due_date),
+        };
+        self.date_picker = Some(typed.or(current).unwrap_or_else(ui::dates::today));
+    }
+
+    /// Puts the picked day into the due-date input, which stays open so a
+    /// time can still be added before submitting.
+    pub fn pick_date(&mut self) {
+        if let Some(date) = self.date_picker.take() {
+            self.input_buffer = date.format("%Y-%m-%d").to_string();
+        }
+    }
+
+    fn set_local_note(&mut self, task_id: String, note: String) {
+        if note.is_empty() {
+            self.local_notes.remove(&task_id);
+        } else {
+            self.local_notes.insert(task_id, note);
+        }
+        if self.ephemeral {
+            return;
+        }
+        let dir = ratatoist_core::config::Config::config_dir();
+        let _ = std::fs::create_dir_all(&dir);
+        let json = serde_json::to_string_pretty(&self.local_notes).unwrap_or_default();
+        if let Err(e) = std::fs::write(Settings::local_notes_path(&dir), json) {
+            warn!(error = %e, "failed to save local notes");
+            self.flash("Couldn't save the private note");
+        }
+    }
+
+    /// What the open input is typing into, for keeping its draft: a comment
+    /// or field edit on a task, a new task's title per project, or quick add.
+    fn draft_key(&self) -> Option<String> {
+        if !self.show_input {
+            return None;
+        }
+        if self.quick_add_input {
+            return Some("quick_add".to_string());
+        }
+        if let Some(form) = &self.task_form {
+            return (form.editing && form.active_field == 0)
+                .then(|| format!("task:{}", form.project_id));
+        }
+        let task_id = &self.selected_task()?.id;
+        if self.comment_input {
+            Some(format!("comment:{task_id}"))
+        } else if self.editing_field {
+            Some(format!("edit:{task_id}:{}", self.detail_field))
+        } else {
+            None
+        }
+    }
+
+    /// Keeps the open input's text as a draft, and offers a saved draft back
+    /// when an input opens in a context that has one.
+    fn track_draft(&mut self) {
+        if self.confirm.is_some() {
+            return;
+        }
+        let key = self.draft_key();
+        if key != self.draft_context {
+            self.draft_context = key;
+            self.draft_base = self.input_buffer.clone();
+            let saved = self
+                .draft_context
+                .as_ref()
+                .and_then(|k| self.drafts.get(k))
+                .filter(|d| **d != self.input_buffer)
+                .cloned();
+            if let (Some(key), Some(draft)) = (&self.draft_context, saved) {
+                let what = match key.split(':').next() {
+                    Some("comment") => "unsent comment",
+                    Some("edit") => "unsaved edit",
+                    Some("task") => "unfinished task",
+                    _ => "unsent quick add",
+                };
+                let preview: String = draft.chars().take(24).collect();
+                let ellipsis = if draft.chars().count() > 24 {
+                    "…"
+                } else {
+                    ""
+                };
+                self.confirm = Some(Confirm {
+                    title: "Restore draft".to_string(),
+                    message: format!("Restore your {what} “{preview}{ellipsis}”?"),
+                    action: ConfirmAction::RestoreDraft(draft),
+                });
+            }
+            return;
+        }
+        let Some(key) = key else {
+            return;
+        };
+        let text = self.input_buffer.trim();
+        let draft = (!text.is_empty() && self.input_buffer != self.draft_base)
+            .then(|| self.input_buffer.clone());
+        self.set_draft(&key, draft);
+    }
+
+    fn set_draft(&mut self, key: &str, text: Option<String>) {
+        let changed = match text {
+            Some(text) => self.drafts.insert(key.to_string(), text.clone()) != Some(text),
+            None => self.drafts.remove(key).is_some(),
+        };
+        if changed && self.drafts_dirty_since.is_none() {
+            self.drafts_dirty_since = Some(Instant::now());
+        }
+    }
+
+    fn tick_drafts(&mut self) {
+        if self
+            .drafts_dirty_since
+            .is_some_and(|since| since.elapsed() >= DRAFT_SAVE_DELAY)
+        {
+            self.save_drafts();
+        }
+    }
+
+    fn save_drafts(&mut self) {
+        if self.drafts_dirty_since.take().is_none() || self.ephemeral {
+            return;
+        }
+        let dir = ratatoist_core::config::Config::config_dir();
+        let _ = std::fs::create_dir_all(&dir);
+        let json = serde_json::to_string_pretty(&self.drafts).unwrap_or_default();
+        if let Err(e) = std::fs::write(Settings::drafts_path(&dir), json) {
+            warn!(error = %e, "failed to save drafts");
+        }
+    }
+
+    fn move_detail_field(&mut self, delta: i32) {
+        let subtasks = self
+            .selected_task()
+            .map_or(0, |t| self.task_children(&t.id).len());
+        let max_fields = (SUBTASKS_FIELD + subtasks) as i32;
+        let current = self.detail_field as i32;
+        self.detail_field = (current + delta).rem_euclid(max_fields) as usize;
+    }
+
+    fn scroll_comments(&mut self, rows: i32) {
+        let max = self.comments_scroll_max.load(Ordering::Relaxed) as i32;
+        let from = self.comments_scroll_drawn.load(Ordering::Relaxed) as i32;
+        self.comments_scroll = (from + rows).clamp(0, max) as u16;
+        self.selected_comment = None;
+    }
+
+    /// Moves the comment cursor; from no selection, forward starts at the
+    /// first comment and back at the last.
+    fn select_comment(&mut self, step: isize) {
+        let len = self.comments.len();
+        if len == 0 {
+            return;
+        }
+        self.selected_comment = Some(match self.selected_comment {
+            None if step > 0 => 0,
+            None => len - 1,
+            Some(i) => (i as isize + step).clamp(0, len as isize - 1) as usize,
+        });
+    }
+
+    /// Opens the comment composer quoting the selected comment, or the
+    /// latest one, and mentioning its author when that's someone else.
+    fn start_reply(&mut self) {
+        let Some(comment) = self
+            .selected_comment
+            .and_then(|i| self.comments.get(i))
+            .or(self.comments.last())
+            .cloned()
+        else {
+            self.start_comment_input();
+            return;
+        };
+        let mut prefill: String = comment
+            .content
+            .lines()
+            .map(|line| format!("> {line}\n"))
+            .collect();
+        prefill.push('\n');
+        let author = comment
+            .posted_by_uid
+            .filter(|uid| self.current_user_id.as_ref() != Some(uid));
+        if let Some(name) = author
+            .as_ref()
+            .and_then(|uid| self.user_names.get(uid))
+            .map(|r| r.display.clone())
+        {
+            prefill.push_str(&format!("@{name} "));
+        }
+        self.start_comment_input();
+        self.input_buffer = prefill;
+        self.reply_notify = author;
+    }
+
+    /// Shows the next or previous task of the list in the detail pane,
+    /// skipping dimmed context rows.
+    fn step_detail_task(&mut self, step: isize) {
+        let target = {
+            let visible = self.visible_tasks();
+            let mut i = self.selected_task as isize + step;
+            while visible
+                .get(i as usize)
+                .is_some_and(|t| self.is_context_task(t))
+            {
+                i += step;
+            }
+            (i >= 0 && (i as usize) < visible.len()).then_some(i as usize)
+        };
+        match target {
+            Some(i) => {
+                self.selected_task = i;
+                self.open_detail();
+            }
+            None if step > 0 => self.flash("Last task"),
+            None => self.flash("First task"),
+        }
+    }
+
+    fn toggle_collapse(&mut self) {
+        let visible = self.visible_tasks();
+        let Some(task) = visible.get(self.selected_task) else {
+            return;
+        };
+        let task_id = task.id.clone();
+        let parent_id = task.parent_id.clone();
+
+        if self.has_children(&task_id) {
+            let fold = !self.collapsed.contains(&task_id);
+            self.set_task_collapsed(&task_id, fold);
+            return;
+        }
+
+        if let Some(pid) = parent_id {
+            self.set_task_collapsed(&pid, true);
+            if let Some(pos) = self.visible_tasks().iter().position(|t| t.id == pid) {
+                self.selected_task = pos;
+            }
+        }
+    }
+
+    /// Starts the guided tour; offered once, right after onboarding.
+    pub fn offer_tour(&mut self) {
+        self.tour = Some(Tour::new(self.tasks.len()));
+    }
+
+    fn step_tour(&mut self, by: isize) {
+        let tasks = self.tasks.len();
+        let Some(tour) = &mut self.tour else {
+            return;
+        };
+        if !tour.go(by, tasks) {
+            self.tour = None;
+            return;
+        }
+        match tour.current().target {
+            Some(TourTarget::Projects) => self.active_pane = Pane::Projects,
+            Some(TourTarget::Tasks) => self.active_pane = Pane::Tasks,
+            Some(TourTarget::Dock) | None => {}
+        }
+    }
+
+    /// Moves past a tour card once the user has done what it asks.
+    fn advance_tour(&mut self) {
+        let Some(tour) = &self.tour else {
+            return;
+        };
+        let done = match tour.current().goal {
+            TourGoal::Read => false,
+            TourGoal::AddTask => self.tasks.len() > tour.tasks_before,
+            TourGoal::CompleteTask => self
+                .recent
+                .first()
+                .is_some_and(|r| r.kind == RecentKind::Completed && r.at >= tour.since),
+            TourGoal::DockFilter => self.dock_filter.is_some(),
+        };
+        if done {
+            self.step_tour(1);
+        }
+    }
+
+    /// The pane the current tour card is about.
+    pub fn tour_target(&self) -> Option<TourTarget> {
+        self.tour.as_ref().and_then(|t| t.current().target)
+    }
+
+    fn remember_recent(&mut self, task_id: &str, kind: RecentKind) {
+        self.recent.retain(|r| r.task_id != task_id);
+        self.recent.insert(
+            0,
+            RecentTask {
+                task_id: task_id.to_string(),
+                kind,
+                at: Local::now(),
+            },
+        );
+        self.recent.truncate(MAX_RECENT);
     }
 
-    fn move_detail_field(&mut self, delta: i32) {
-        let max_fields = 4;
-        let current = self.detail_field as i32;
-        self.detail_field = (current + delta).rem_euclid(max_fields) as usize;
+    /// Recent panel rows whose task is still around.
+    pub fn recent_tasks(&self) -> Vec<(&RecentTask, &Task)> {
+        self.recent
+            .iter()
+            .filter_map(|r| {
+                let task = self
+                    .tasks
+                    .iter()
+                    .find(|t| t.id == r.task_id && !t.is_deleted)?;
+                Some((r, task))
+            })
+            .collect()
     }
 
-    fn toggle_collapse(&mut self) {
-        let visible = self.visible_tasks();
-        let Some(task) = visible.get(self.selected_task) else {
+    fn open_recent(&mut self) {
+        let Some(selection) = self.recent_panel.take() else {
             return;
         };
-        let task_id = task.id.clone();
-        let parent_id = task.parent_id.clone();
+        let Some(task_id) = self
+            .recent_tasks()
+            .get(selection)
+            .map(|(r, _)| r.task_id.clone())
+        else {
+            return;
+        };
+        self.reveal_task(&task_id);
+    }
 
-        if self.has_children(&task_id) {
-            if self.collapsed.contains(&task_id) {
-                self.collapsed.remove(&task_id);
-            } else {
-                self.collapsed.insert(task_id);
-            }
+    /// Shows a task in its project's list and selects it, unfolding its
+    /// parents and, for a completed task, showing done tasks too.
+    fn reveal_task(&mut self, task_id: &str) {
+        let Some(task) = self.tasks.iter().find(|t| t.id == task_id).cloned() else {
+            return;
+        };
+        let Some(i) = self.projects.iter().position(|p| p.id == task.project_id) else {
             return;
+        };
+        self.folder_cursor = None;
+        self.dock_filter = None;
+        self.selected_project = i;
+        self.switch_to_project_tasks();
+        if task.checked && self.task_filter == TaskFilter::Active {
+            self.task_filter = TaskFilter::Both;
+            self.fetch_completed_if_needed();
+        }
+        let mut parent = task.parent_id.clone();
+        while let Some(pid) = parent {
+            self.collapsed.remove(&pid);
+            parent = self
+                .tasks
+                .iter()
+                .find(|t| t.id == pid)
+                .and_then(|t| t.parent_id.clone());
         }
+        if let Some(pos) = self.visible_tasks().iter().position(|t| t.id == task.id) {
+            self.selected_task = pos;
+        }
+        self.active_pane = Pane::Tasks;
+    }
 
-        if let Some(pid) = parent_id {
-            self.collapsed.insert(pid.clone());
-            if let Some(pos) = self.visible_tasks().iter().position(|t| t.id == pid) {
-                self.selected_task = pos;
-            }
+    /// Folds or unfolds one task, persisting the fold locally and mirroring it
+    /// to Todoist's `is_collapsed` so the official apps agree.
+    fn set_task_collapsed(&mut self, task_id: &str, fold: bool) {
+        if fold {
+            self.collapsed.insert(task_id.to_string());
+        } else {
+            self.collapsed.remove(task_id);
+        }
+        self.save_ui_state();
+
+        if self.task_has_pending_op(task_id) {
+            return;
+        }
+        let Some(task) = self.tasks.iter_mut().find(|t| t.id == task_id) else {
+            return;
+        };
+        if task.is_collapsed == fold {
+            return;
         }
+        let before = task.clone();
+        task.is_collapsed = fold;
+
+        let uuid = new_uuid();
+        self.temp_id_pending.insert(
+            uuid.clone(),
+            OptimisticOp::TaskUpdated {
+                task_id: task_id.to_string(),
+                before,
+            },
+        );
+        self.pending_commands.push(SyncCommand {
+            r#type: "item_update".to_string(),
+            temp_id: None,
+            uuid,
+            args: serde_json::json!({ "id": task_id, "is_collapsed": fold }),
+        });
+        self.flush_commands();
     }
 
     fn close_all_folds(&mut self) {
@@ -1862,6 +6414,7 @@ impl App {
                 self.collapsed.insert(task.id.clone());
             }
         }
+        self.save_ui_state();
     }
 
     pub fn toggle_input_mode(&mut self) {
@@ -1870,21 +6423,91 @@ impl App {
             InputMode::Standard => InputMode::Vim(VimState::Normal),
         };
         info!(mode = self.input_mode.label(), "input mode toggled");
+        self.save_ui_settings();
     }
 
     fn set_error(&mut self, err: &anyhow::Error, context: &str) {
         let app_err = AppError::from_api(err, context);
         error!(context, error = %app_err.message, "app error");
-        self.error = Some(app_err);
+        self.show_error(app_err);
     }
 
-    fn handle_error_dismiss(&mut self) {
-        if let Some(err) = self.error.take() {
-            if !err.recoverable {
-                info!("unrecoverable error dismissed, exiting");
-                self.running = false;
-            } else {
-                debug!("error dismissed, continuing");
+    /// Hooks see the task as it is locally, so a newly added task still has
+    /// its temporary id.
+    fn fire_task_hook(&self, hook: Hook, task: &Task) {
+        match serde_json::to_value(task) {
+            Ok(payload) => hooks::fire(&self.hooks, hook, &payload),
+            Err(e) => warn!(error = %e, "failed to serialize task for hook"),
+        }
+    }
+
+    fn fire_sync_error_hook(&self, context: &str, error: &str) {
+        let payload = serde_json::json!({ "context": context, "error": error });
+        hooks::fire(&self.hooks, Hook::SyncError, &payload);
+    }
+
+    fn set_retryable_error(&mut self, err: &anyhow::Error, context: &str, retry: RetryAction) {
+        let mut app_err = AppError::from_api(err, context);
+        error!(context, error = %app_err.message, "app error");
+        app_err.retry = Some(retry);
+        self.show_error(app_err);
+    }
+
+    /// Replaces the current popup. Unsent commands held by the old one are
+    /// reverted first so their optimistic edits don't linger.
+    fn show_error(&mut self, err: AppError) {
+        if let Some(old) = self.error.take()
+            && let Some(RetryAction::Commands(commands)) = old.retry
+        {
+            self.revert_commands(&commands);
+        }
+        self.error = Some(err);
+    }
+
+    fn handle_error_key(&mut self, key: KeyEvent) {
+        let Some(err) = self.error.take() else {
+            return;
+        };
+        if key.code == KeyCode::Char('c') {
+            match crate::clipboard::copy(&err.report(&self.log_dir)) {
+                Ok(()) => self.flash("Copied error details"),
+                Err(e) => self.flash(format!("Couldn't copy error details: {e}")),
+            }
+            self.error = Some(err);
+            return;
+        }
+        if key.code == KeyCode::Char('r')
+            && let Some(retry) = err.retry
+        {
+            info!(?retry, "retrying after error");
+            match retry {
+                RetryAction::Commands(commands) => {
+                    self.pending_commands.extend(commands);
+                    self.send_commands();
+                }
+                RetryAction::Comments(task_id) => self.spawn_comments_fetch(task_id),
+                RetryAction::CompletedTasks(project_id) => {
+                    self.spawn_completed_tasks_fetch(project_id)
+                }
+            }
+            return;
+        }
+        if let Some(RetryAction::Commands(commands)) = &err.retry {
+            self.revert_commands(commands);
+        }
+        if !err.recoverable {
+            info!("unrecoverable error dismissed, exiting");
+            self.running = false;
+        } else {
+            debug!("error dismissed, continuing");
+        }
+    }
+
+    /// Newest first, so two edits of one task unwind to the oldest snapshot.
+    fn revert_commands(&mut self, commands: &[SyncCommand]) {
+        for cmd in commands.iter().rev() {
+            if let Some(op) = self.temp_id_pending.remove(&cmd.uuid) {
+                self.revert_optimistic(op);
             }
         }
     }
@@ -1901,9 +6524,91 @@ impl App {
         visible.get(self.selected_task).copied()
     }
 
+    fn query_context(&self, today: chrono::NaiveDate) -> filter_query::Context<'_> {
+        filter_query::Context {
+            today,
+            projects: &self.projects,
+            current_user_id: self.current_user_id.as_deref(),
+        }
+    }
+
+    /// The fixed dock entries followed by the user's `[dock] filters`.
+    pub fn dock_items(&self) -> Vec<DockItem> {
+        DOCK_ITEMS
+            .into_iter()
+            .chain((!self.pinned.is_empty()).then_some(DockItem::Pinned))
+            .chain((0..self.custom_dock.len()).map(DockItem::Custom))
+            .collect()
+    }
+
+    /// Whether the dock has a row for pins and `[dock] filters`.
+    pub fn has_pinned_row(&self) -> bool {
+        !self.pinned.is_empty() || !self.custom_dock.is_empty()
+    }
+
+    fn toggle_pin(&mut self) {
+        let Some(task_id) = self.selected_task().map(|t| t.id.clone()) else {
+            return;
+        };
+        if self.pinned.remove(&task_id) {
+            self.flash("Unpinned");
+            if self.pinned.is_empty() && self.dock_filter == Some(DockItem::Pinned) {
+                self.dock_filter = None;
+            }
+        } else {
+            self.pinned.insert(task_id);
+            self.flash("Pinned to the dock");
+        }
+        let visible_len = self.visible_tasks().len();
+        self.selected_task = self.selected_task.min(visible_len.saturating_sub(1));
+        self.save_ui_state();
+    }
+
+    pub fn dock_hint(&self, item: DockItem) -> String {
+        match item {
+            DockItem::Custom(i) => self
+                .custom_dock
+                .get(i)
+                .map_or_else(|| item.hint().to_string(), |d| d.name.clone()),
+            _ => item.hint().to_string(),
+        }
+    }
+
+    /// Each `[dock] filters` entry is a saved filter's name, or else a query
+    /// of its own. Entries that resolve to nothing parseable are left out.
+    fn resolve_custom_dock(&mut self) {
+        self.custom_dock = self
+            .custom_dock_entries
+            .iter()
+            .filter_map(|entry| {
+                let (name, src) = match self
+                    .filters
+                    .iter()
+                    .find(|f| f.name.eq_ignore_ascii_case(entry.trim()))
+                {
+                    Some(filter) => (filter.name.clone(), filter.query.as_str()),
+                    None => (entry.trim().to_string(), entry.as_str()),
+                };
+                match Query::parse(src) {
+                    Ok(query) => Some(CustomDock { name, query }),
+                    Err(e) => {
+                        warn!(entry, error = %e, "skipping dock filter");
+                        None
+                    }
+                }
+            })
+            .take(MAX_DOCK_FILTERS)
+            .collect();
+        if let Some(DockItem::Custom(i)) = self.dock_filter
+            && i >= self.custom_dock.len()
+        {
+            self.dock_filter = None;
+        }
+    }
+
     pub fn overview_stats(&self) -> OverviewStats {
-        let today = crate::ui::dates::today_str();
-        let week_end = crate::ui::dates::offset_days_str(7);
+        let today = crate::ui::dates::today();
+        let week_end = today + chrono::Duration::days(7);
 
         let mut due_today = 0u32;
         let mut due_week = 0u32;
@@ -1920,26 +6625,76 @@ impl App {
                     by_priority[p] += 1;
                 }
             }
-            if let Some(due) = &task.due {
-                let due_date = crate::ui::dates::date_part(&due.date);
-                if due_date == today.as_str() && !task.checked {
+            if let Some(due_date) = task.due.as_ref().and_then(crate::ui::dates::due_date) {
+                if due_date == today && !task.checked {
                     due_today += 1;
                 }
-                if due_date < today.as_str() && !task.checked {
+                if due_date < today && !task.checked {
                     overdue += 1;
                 }
-                if due_date >= today.as_str() && due_date <= week_end.as_str() {
+                if due_date >= today && due_date <= week_end {
                     due_week += 1;
                 }
             }
         }
 
+        let query_ctx = self.query_context(today);
+        let custom = self
+            .custom_dock
+            .iter()
+            .map(|dock| {
+                self.tasks
+                    .iter()
+                    .filter(|t| !t.is_deleted && !t.checked && dock.query.matches(t, &query_ctx))
+                    .count() as u32
+            })
+            .collect();
+
+        let pinned = self
+            .tasks
+            .iter()
+            .filter(|t| !t.is_deleted && !t.checked && self.pinned.contains(&t.id))
+            .count() as u32;
+
+        let week_done = self.completed_this_week().unwrap_or(0);
+        let week_total = self.weekly_goal().unwrap_or(0);
+        let week_progress = (week_done * 100)
+            .checked_div(week_total)
+            .map_or(0, |p| p.min(100));
+
         OverviewStats {
             due_today,
             due_week,
             overdue,
             by_priority,
+            pinned,
+            custom,
+            week_done,
+            week_total,
+            week_progress,
+        }
+    }
+
+    /// Active and overdue task counts per project id, from the in-memory model.
+    pub fn project_task_counts(&self) -> HashMap<&str, ProjectCounts> {
+        let today = crate::ui::dates::today();
+        let mut counts: HashMap<&str, ProjectCounts> = HashMap::new();
+        for task in &self.tasks {
+            if task.is_deleted || task.checked {
+                continue;
+            }
+            let entry = counts.entry(task.project_id.as_str()).or_default();
+            entry.active += 1;
+            if task
+                .due
+                .as_ref()
+                .and_then(crate::ui::dates::due_date)
+                .is_some_and(|d| d < today)
+            {
+                entry.overdue += 1;
+            }
         }
+        counts
     }
 
     pub fn has_children(&self, task_id: &str) -> bool {
@@ -1954,77 +6709,104 @@ impl App {
 
     pub fn visible_tasks(&self) -> Vec<&Task> {
         if self.today_view_active {
-            let today = crate::ui::dates::today_str();
-            let mut tasks: Vec<&Task> =
-                self.tasks
-                    .iter()
-                    .filter(|t| {
-                        if t.is_deleted || t.checked || t.parent_id.is_some() {
-                            return false;
-                        }
-                        let is_today_or_overdue = t.due.as_ref().is_some_and(|d| {
-                            crate::ui::dates::date_part(&d.date) <= today.as_str()
-                        });
-                        if !is_today_or_overdue {
-                            return false;
-                        }
-                        match &t.responsible_uid {
-                            None => true,
-                            Some(uid) => self.current_user_id.as_deref() == Some(uid.as_str()),
-                        }
-                    })
-                    .collect();
+            let today = crate::ui::dates::today();
+            let due_date = |t: &Task| t.due.as_ref().and_then(crate::ui::dates::due_date);
+            let mut tasks: Vec<&Task> = self
+                .tasks
+                .iter()
+                .filter(|t| {
+                    if t.is_deleted || t.checked || t.parent_id.is_some() {
+                        return false;
+                    }
+                    let is_today_or_overdue = due_date(t).is_some_and(|d| d <= today);
+                    if !is_today_or_overdue {
+                        return false;
+                    }
+                    match &t.responsible_uid {
+                        None => true,
+                        Some(uid) => self.current_user_id.as_deref() == Some(uid.as_str()),
+                    }
+                })
+                .collect();
+            // Todoist reports -1 for tasks never placed in the Today view.
+            let day_order = |t: &Task| t.day_order.filter(|o| *o >= 0).unwrap_or(i32::MAX);
             tasks.sort_by(|a, b| {
-                let a_date = a
-                    .due
-                    .as_ref()
-                    .map(|d| crate::ui::dates::date_part(&d.date))
-                    .unwrap_or("");
-                let b_date = b
-                    .due
-                    .as_ref()
-                    .map(|d| crate::ui::dates::date_part(&d.date))
-                    .unwrap_or("");
-                a_date.cmp(b_date).then(a.child_order.cmp(&b.child_order))
+                due_date(a)
+                    .cmp(&due_date(b))
+                    .then(day_order(a).cmp(&day_order(b)))
+                    .then(a.child_order.cmp(&b.child_order))
             });
             if self.overdue_section_collapsed {
-                tasks.retain(|t| {
-                    t.due
-                        .as_ref()
-                        .is_some_and(|d| crate::ui::dates::date_part(&d.date) == today.as_str())
-                });
+                tasks.retain(|t| due_date(t) == Some(today));
             }
             return tasks;
         }
 
-        let today = crate::ui::dates::today_str();
-        let week_end = crate::ui::dates::offset_days_str(7);
+        let today = crate::ui::dates::today();
+        let week_end = today + chrono::Duration::days(7);
+        let due_date = |t: &Task| t.due.as_ref().and_then(crate::ui::dates::due_date);
 
         let current_project_id = self
             .projects
             .get(self.selected_project)
             .map(|p| p.id.as_str());
 
+        let favorite_label = match &self.favorite_view {
+            Some(FavoriteView::Label(id)) => self.labels.iter().find(|l| &l.id == id),
+            _ => None,
+        };
+        let favorite_query = match &self.favorite_view {
+            Some(FavoriteView::Filter(id)) => self
+                .filters
+                .iter()
+                .find(|f| &f.id == id)
+                .and_then(|f| Query::parse(&f.query).ok()),
+            _ => None,
+        };
+        let custom_query = match self.dock_filter {
+            Some(DockItem::Custom(i)) => self.custom_dock.get(i).map(|d| &d.query),
+            _ => None,
+        };
+        let query_ctx = self.query_context(today);
+        let cross_project = self.dock_filter.is_some() || self.favorite_view.is_some();
+
         let mut top_level: Vec<&Task> = self
             .tasks
             .iter()
             .filter(|t| {
-                if t.is_deleted || t.parent_id.is_some() {
+                // Pins are the one list that shows subtasks on their own.
+                if t.is_deleted
+                    || (t.parent_id.is_some() && self.dock_filter != Some(DockItem::Pinned))
+                {
                     return false;
                 }
                 if let Some(dock) = self.dock_filter {
                     return match dock {
                         DockItem::DueOverdue => {
-                            t.due.as_ref().is_some_and(|d| d.date < today) && !t.checked
+                            due_date(t).is_some_and(|d| d < today) && !t.checked
+                        }
+                        DockItem::DueToday => due_date(t) == Some(today),
+                        DockItem::DueWeek => {
+                            due_date(t).is_some_and(|d| d >= today && d <= week_end)
                         }
-                        DockItem::DueToday => t.due.as_ref().is_some_and(|d| d.date == today),
-                        DockItem::DueWeek => t
-                            .due
-                            .as_ref()
-                            .is_some_and(|d| d.date >= today && d.date <= week_end),
                         DockItem::Priority(p) => t.priority == p && !t.checked,
+                        DockItem::Pinned => !t.checked && self.pinned.contains(&t.id),
+                        DockItem::Custom(_) => {
+                            !t.checked && custom_query.is_some_and(|q| q.matches(t, &query_ctx))
+                        }
                     };
                 }
+                if self.favorite_view.is_some() {
+                    if t.checked {
+                        return false;
+                    }
+                    if let Some(label) = favorite_label {
+                        return t.labels.iter().any(|l| l == &label.name);
+                    }
+                    return favorite_query
+                        .as_ref()
+                        .is_some_and(|q| q.matches(t, &query_ctx));
+                }
                 Some(t.project_id.as_str()) == current_project_id
                     && match self.task_filter {
                         TaskFilter::Active => !t.checked,
@@ -2034,9 +6816,10 @@ impl App {
             })
             .collect();
 
+        let desc = self.sort_descending;
         match self.sort_mode {
             SortMode::Default => {
-                if self.dock_filter.is_none() {
+                if !cross_project {
                     let so = |sid: Option<&str>| {
                         sid.and_then(|id| self.sections.iter().find(|s| s.id == id))
                             .and_then(|s| s.section_order)
@@ -2050,21 +6833,47 @@ impl App {
                 } else {
                     top_level.sort_by_key(|t| t.child_order);
                 }
+                if desc {
+                    top_level.reverse();
+                }
+            }
+            SortMode::Priority => {
+                top_level.sort_by(|a, b| directed(b.priority.cmp(&a.priority), desc))
             }
-            SortMode::Priority => top_level.sort_by_key(|b| std::cmp::Reverse(b.priority)),
             SortMode::DueDate => top_level.sort_by(|a, b| {
-                let a_due = a.due.as_ref().map(|d| d.date.as_str()).unwrap_or("9999");
-                let b_due = b.due.as_ref().map(|d| d.date.as_str()).unwrap_or("9999");
-                a_due.cmp(b_due)
+                let key = |t: &Task| t.due.as_ref().map(crate::ui::dates::due_sort_key);
+                missing_last(key(a), key(b), desc)
             }),
             SortMode::Created => top_level.sort_by(|a, b| {
-                let a_at = a.added_at.as_deref().unwrap_or("");
-                let b_at = b.added_at.as_deref().unwrap_or("");
-                b_at.cmp(a_at)
+                // Newest first unless reversed.
+                missing_last(b.added_at.as_deref(), a.added_at.as_deref(), desc)
+            }),
+            // Lowercased once per task rather than once per comparison.
+            SortMode::Alphabetical => {
+                let mut keyed: Vec<(String, &Task)> = top_level
+                    .into_iter()
+                    .map(|t| (t.content.to_lowercase(), t))
+                    .collect();
+                keyed.sort_by(|a, b| directed(a.0.cmp(&b.0), desc));
+                top_level = keyed.into_iter().map(|(_, t)| t).collect();
+            }
+            SortMode::Label => {
+                let mut keyed: Vec<(Option<String>, &Task)> = top_level
+                    .into_iter()
+                    .map(|t| (t.labels.iter().map(|l| l.to_lowercase()).min(), t))
+                    .collect();
+                keyed.sort_by(|a, b| missing_last(a.0.as_deref(), b.0.as_deref(), desc));
+                top_level = keyed.into_iter().map(|(_, t)| t).collect();
+            }
+            SortMode::Deadline => top_level.sort_by(|a, b| {
+                fn key(t: &Task) -> Option<&str> {
+                    t.deadline.as_ref().and_then(|d| d["date"].as_str())
+                }
+                missing_last(key(a), key(b), desc)
             }),
         }
 
-        if self.dock_filter.is_some() {
+        if cross_project {
             return top_level;
         }
 
@@ -2081,12 +6890,9 @@ impl App {
         }
 
         if matches!(self.task_filter, TaskFilter::Done | TaskFilter::Both)
-            && let Some(pid) = self
-                .projects
-                .get(self.selected_project)
-                .map(|p| p.id.clone())
+            && let Some(pid) = current_project_id
         {
-            self.append_cached_completed(&pid, &mut result);
+            self.append_cached_completed(pid, &mut result);
         }
 
         result
@@ -2118,13 +6924,13 @@ impl App {
     }
 
     fn is_descendant_of(&self, task_id: &str, ancestor_id: &str) -> bool {
-        let mut current = task_id.to_string();
+        let mut current = task_id;
         loop {
             let parent = self
                 .tasks
                 .iter()
                 .find(|t| t.id == current)
-                .and_then(|t| t.parent_id.clone());
+                .and_then(|t| t.parent_id.as_deref());
             match parent {
                 None => return false,
                 Some(pid) if pid == ancestor_id => return true,
@@ -2134,7 +6940,11 @@ impl App {
     }
 
     pub fn is_context_task(&self, task: &Task) -> bool {
-        if !(self.task_filter == TaskFilter::Done && self.dock_filter.is_none() && !task.checked) {
+        if !(self.task_filter == TaskFilter::Done
+            && self.dock_filter.is_none()
+            && self.favorite_view.is_none()
+            && !task.checked)
+        {
             return false;
         }
         if self.has_completed_descendant(&task.id) {
@@ -2169,6 +6979,62 @@ impl App {
         }
     }
 
+    /// The task's parents, outermost first.
+    pub fn task_ancestors(&self, task: &Task) -> Vec<&Task> {
+        let mut ancestors = Vec::new();
+        let mut current_parent = task.parent_id.as_deref();
+        while let Some(parent) =
+            current_parent.and_then(|pid| self.tasks.iter().find(|t| t.id == pid))
+        {
+            ancestors.push(parent);
+            current_parent = parent.parent_id.as_deref();
+        }
+        ancestors.reverse();
+        ancestors
+    }
+
+    /// Direct subtasks of `task_id` in list order, completed ones included.
+    pub fn task_children(&self, task_id: &str) -> Vec<&Task> {
+        let mut children: Vec<&Task> = self
+            .tasks
+            .iter()
+            .filter(|t| !t.is_deleted && t.parent_id.as_deref() == Some(task_id))
+            .collect();
+        children.sort_by_key(|t| t.child_order);
+        children
+    }
+
+    /// The subtask row under the detail pane's cursor, if it is on one.
+    fn selected_detail_subtask(&self) -> Option<&Task> {
+        if !matches!(self.active_pane, Pane::Detail) {
+            return None;
+        }
+        let row = self.detail_field.checked_sub(SUBTASKS_FIELD)?;
+        let task = self.selected_task()?;
+        self.task_children(&task.id).get(row).copied()
+    }
+
+    /// Moves the detail pane to the open task's parent.
+    fn open_parent_task(&mut self) {
+        let Some(parent_id) = self.selected_task().and_then(|t| t.parent_id.clone()) else {
+            self.flash("Not a subtask");
+            return;
+        };
+        self.open_detail_for(&parent_id);
+    }
+
+    /// Selects `task_id`, in the current list when it is there and in its
+    /// project otherwise, and shows it in the detail pane.
+    fn open_detail_for(&mut self, task_id: &str) {
+        match self.visible_tasks().iter().position(|t| t.id == task_id) {
+            Some(pos) => self.selected_task = pos,
+            None => self.reveal_task(task_id),
+        }
+        if self.selected_task().is_some_and(|t| t.id == task_id) {
+            self.open_detail();
+        }
+    }
+
     pub fn task_depth(&self, task: &Task) -> usize {
         let mut depth = 0;
         let mut current_parent = task.parent_id.as_deref();
@@ -2267,9 +7133,20 @@ fn collect_project_subtree(parent_id: Option<&str>, all: &[Project], out: &mut V
     }
 }
 
+/// The resources a websocket message says changed. Most messages are a
+/// bare `sync_needed`, which could be anything, so `None` means sync all.
+fn ws_changed_resources(message: &str) -> Option<&'static [&'static str]> {
+    let message: serde_json::Value = serde_json::from_str(message).ok()?;
+    match message["type"].as_str()? {
+        "live_notifications_added" => Some(&["live_notifications"]),
+        _ => None,
+    }
+}
+
 async fn run_websocket(url: String, tx: mpsc::Sender<BgResult>) {
     use futures_util::StreamExt;
     use tokio_tungstenite::connect_async_tls_with_config;
+    use tokio_tungstenite::tungstenite::Message;
     use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 
     let mut backoff_secs = 5u64;
@@ -2288,8 +7165,12 @@ async fn run_websocket(url: String, tx: mpsc::Sender<BgResult>) {
                 let _ = tx.send(BgResult::WebSocketConnected).await;
 
                 let (_, mut read) = ws_stream.split();
-                while read.next().await.is_some() {
-                    let _ = tx.send(BgResult::WebSocketEvent).await;
+                while let Some(message) = read.next().await {
+                    let changed = match &message {
+                        Ok(Message::Text(text)) => ws_changed_resources(text),
+                        _ => None,
+                    };
+                    let _ = tx.send(BgResult::WebSocketEvent(changed)).await;
                 }
                 let _ = tx.send(BgResult::WebSocketDisconnected).await;
                 // Clean disconnect — reconnect quickly without growing backoff.
@@ -2304,3 +7185,162 @@ async fn run_websocket(url: String, tx: mpsc::Sender<BgResult>) {
         backoff_secs = (backoff_secs * 2).min(60);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(kind: &str, uuid: &str, args: serde_json::Value) -> SyncCommand {
+        SyncCommand {
+            r#type: kind.to_string(),
+            temp_id: None,
+            uuid: uuid.to_string(),
+            args,
+        }
+    }
+
+    fn updated(task_id: &str) -> OptimisticOp {
+        OptimisticOp::TaskUpdated {
+            task_id: task_id.to_string(),
+            before: Task {
+                id: task_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn fold_update_merges_same_task_updates_with_later_values_winning() {
+        let mut prev = command(
+            "item_update",
+            "u1",
+            serde_json::json!({ "id": "t1", "content": "a", "priority": 2 }),
+        );
+        let next = command(
+            "item_update",
+            "u2",
+            serde_json::json!({ "id": "t1", "content": "b" }),
+        );
+        assert!(fold_update(&mut prev, &next));
+        assert_eq!(
+            prev.args,
+            serde_json::json!({ "id": "t1", "content": "b", "priority": 2 })
+        );
+        assert_eq!(prev.uuid, "u1");
+    }
+
+    #[test]
+    fn fold_update_replaces_every_form_of_due_date() {
+        let mut prev = command(
+            "item_update",
+            "u1",
+            serde_json::json!({ "id": "t1", "due_string": "tomorrow" }),
+        );
+        let next = command(
+            "item_update",
+            "u2",
+            serde_json::json!({ "id": "t1", "due": { "date": "2026-05-01" } }),
+        );
+        assert!(fold_update(&mut prev, &next));
+        assert_eq!(
+            prev.args,
+            serde_json::json!({ "id": "t1", "due": { "date": "2026-05-01" } })
+        );
+    }
+
+    #[test]
+    fn fold_update_leaves_other_tasks_and_command_types_alone() {
+        let args = serde_json::json!({ "id": "t1", "content": "a" });
+        let mut prev = command("item_update", "u1", args.clone());
+        let other_task = command(
+            "item_update",
+            "u2",
+            serde_json::json!({ "id": "t2", "content": "b" }),
+        );
+        let close = command("item_close", "u3", serde_json::json!({ "id": "t1" }));
+        assert!(!fold_update(&mut prev, &other_task));
+        assert!(!fold_update(&mut prev, &close));
+        assert_eq!(prev.args, args);
+
+        let mut move_cmd = command(
+            "item_move",
+            "u4",
+            serde_json::json!({ "id": "t1", "project_id": "p1" }),
+        );
+        let update = command(
+            "item_update",
+            "u5",
+            serde_json::json!({ "id": "t1", "content": "c" }),
+        );
+        assert!(!fold_update(&mut move_cmd, &update));
+    }
+
+    #[test]
+    fn coalesce_merges_only_consecutive_updates_of_one_task() {
+        let update = |uuid: &str, id: &str, content: &str| {
+            command(
+                "item_update",
+                uuid,
+                serde_json::json!({ "id": id, "content": content }),
+            )
+        };
+        let commands = vec![
+            update("u1", "t1", "a"),
+            update("u2", "t1", "b"),
+            command("item_close", "u3", serde_json::json!({ "id": "t1" })),
+            update("u4", "t1", "c"),
+            update("u5", "t2", "d"),
+            update("u6", "t1", "e"),
+        ];
+        let mut pending: HashMap<String, OptimisticOp> = ["u1", "u2", "u4", "u6"]
+            .into_iter()
+            .map(|uuid| (uuid.to_string(), updated("t1")))
+            .chain([("u5".to_string(), updated("t2"))])
+            .collect();
+
+        let out = coalesce_updates(commands, &mut pending);
+        let uuids: Vec<&str> = out.iter().map(|c| c.uuid.as_str()).collect();
+        assert_eq!(uuids, ["u1", "u3", "u4", "u5", "u6"]);
+        assert_eq!(out[0].args["content"], "b");
+        assert!(pending.contains_key("u1"));
+        assert!(
+            !pending.contains_key("u2"),
+            "the folded snapshot is dropped"
+        );
+    }
+
+    #[test]
+    fn coalesce_keeps_updates_whose_snapshot_is_not_a_task_update() {
+        let commands = vec![
+            command(
+                "item_update",
+                "u1",
+                serde_json::json!({ "id": "t1", "content": "a" }),
+            ),
+            command(
+                "item_update",
+                "u2",
+                serde_json::json!({ "id": "t1", "content": "b" }),
+            ),
+        ];
+        let mut pending = HashMap::from([(
+            "u1".to_string(),
+            OptimisticOp::TasksChanged { before: Vec::new() },
+        )]);
+        let out = coalesce_updates(commands, &mut pending);
+        assert_eq!(out.len(), 2);
+    }
+
+    #[test]
+    fn shifting_a_due_date_keeps_its_time() {
+        assert_eq!(
+            shift_due_days("2026-05-01", 7).as_deref(),
+            Some("2026-05-08")
+        );
+        assert_eq!(
+            shift_due_days("2026-05-31T09:00:00Z", 1).as_deref(),
+            Some("2026-06-01T09:00:00Z")
+        );
+        assert_eq!(shift_due_days("soon", 1), None);
+    }
+}
@@ -0,0 +1,55 @@
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+
+/// Web URL of a task in the Todoist app.
+pub fn task_url(task_id: &str) -> String {
+    format!("https://app.todoist.com/app/task/{task_id}")
+}
+
+/// Opens `url` in the user's default browser without blocking the UI.
+/// Only http(s) URLs are opened: links come from task descriptions and
+/// other people's comments, and other schemes can launch local programs.
+pub fn open(url: &str) -> Result<()> {
+    anyhow::ensure!(
+        is_web_url(url),
+        "not opening {url}: only http(s) links open"
+    );
+    let mut cmd = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        // Not `cmd /C start`: cmd.exe would run whatever follows a `&` in
+        // the URL.
+        let mut c = Command::new("rundll32");
+        c.arg("url.dll,FileProtocolHandler");
+        c
+    } else {
+        Command::new("xdg-open")
+    };
+    cmd.arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("could not launch a browser for {url}"))?;
+    Ok(())
+}
+
+fn is_web_url(url: &str) -> bool {
+    let lower = url.get(..8).unwrap_or(url).to_ascii_lowercase();
+    lower.starts_with("https://") || lower.starts_with("http://")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_web_urls_open() {
+        assert!(is_web_url("https://example.com/a?b=1&c=2"));
+        assert!(is_web_url("HTTP://example.com"));
+        assert!(!is_web_url("file:///etc/passwd"));
+        assert!(!is_web_url("javascript:alert(1)"));
+        assert!(!is_web_url("http:"));
+    }
+}
@@ -0,0 +1,121 @@
+use std::io::Write as _;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use base64::Engine as _;
+use tracing::debug;
+
+use crate::ui::text::strip_list_marker;
+
+// Kept alive for the whole session: on X11 the clipboard contents vanish when
+// the owning `Clipboard` is dropped.
+static CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
+
+/// Copies `text` to the system clipboard.
+///
+/// Over SSH the local clipboard is the wrong one (and usually unreachable),
+/// so the text is sent to the user's terminal as an OSC 52 escape instead.
+/// The same path is used as a fallback when no clipboard provider is available.
+pub fn copy(text: &str) -> Result<()> {
+    if is_ssh_session() {
+        return copy_osc52(text);
+    }
+    match copy_native(text) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            debug!(error = %e, "native clipboard unavailable, falling back to OSC 52");
+            copy_osc52(text)
+        }
+    }
+}
+
+/// Reads text from the system clipboard, flattened to a single line since
+/// every input it feeds is single-line.
+pub fn paste() -> Result<String> {
+    read_text().map(|text| single_line(&text))
+}
+
+/// Reads the clipboard as a list: one entry per non-blank line, with any
+/// bullet or checkbox marker in front of it removed.
+pub fn paste_lines() -> Result<Vec<String>> {
+    read_text().map(|text| list_lines(&text))
+}
+
+fn read_text() -> Result<String> {
+    let mut guard = CLIPBOARD.lock().unwrap();
+    if guard.is_none() {
+        *guard = Some(arboard::Clipboard::new().context("no system clipboard available")?);
+    }
+    guard
+        .as_mut()
+        .expect("clipboard initialised above")
+        .get_text()
+        .context("clipboard does not contain text")
+}
+
+fn single_line(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn list_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| strip_list_marker(line).unwrap_or(line).trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+/// Ctrl-V, or Cmd-V on terminals that report the super modifier.
+pub fn is_paste_key(key: &crossterm::event::KeyEvent) -> bool {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    key.code == KeyCode::Char('v')
+        && key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::SUPER)
+}
+
+fn copy_native(text: &str) -> Result<()> {
+    let mut guard = CLIPBOARD.lock().unwrap();
+    if guard.is_none() {
+        *guard = Some(arboard::Clipboard::new()?);
+    }
+    guard
+        .as_mut()
+        .expect("clipboard initialised above")
+        .set_text(text)?;
+    Ok(())
+}
+
+fn copy_osc52(text: &str) -> Result<()> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut out = std::io::stdout();
+    write!(out, "\x1b]52;c;{encoded}\x07")?;
+    out.flush()?;
+    Ok(())
+}
+
+fn is_ssh_session() -> bool {
+    std::env::var_os("SSH_TTY").is_some() || std::env::var_os("SSH_CONNECTION").is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_line_joins_and_trims() {
+        assert_eq!(single_line("  abc123  \n"), "abc123");
+        assert_eq!(single_line("buy milk\r\n\r\nand eggs"), "buy milk and eggs");
+    }
+
+    #[test]
+    fn list_lines_strips_markers() {
+        assert_eq!(
+            list_lines("- buy milk\n\n* [ ] call mom\r\n  plain line  \n- [x] done"),
+            vec!["buy milk", "call mom", "plain line", "done"]
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use chrono::{Duration, NaiveDate};
+
+use ratatoist_core::api::models::{Project, Task};
+
+use crate::ui::dates;
+
+/// A parsed Todoist filter query. Covers the everyday subset of the syntax:
+/// `&`, `|`, `!`, parentheses and `,` (separate lists, shown here as one)
+/// over dates, priorities, labels, projects, assignment and text search.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    Or(Vec<Query>),
+    And(Vec<Query>),
+    Not(Box<Query>),
+    Term(Term),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+    All,
+    Today,
+    Tomorrow,
+    Overdue,
+    NoDate,
+    /// Due from today through this many days ahead.
+    Days(i64),
+    Priority(u8),
+    /// Label name, lowercased; `*` matches any run of characters.
+    Label(String),
+    NoLabels,
+    /// Project name, lowercased; `with_children` for `##`.
+    Project {
+        name: String,
+        with_children: bool,
+    },
+    Recurring,
+    Subtask,
+    AssignedToMe,
+    AssignedToOthers,
+    Assigned,
+    Search(String),
+}
+
+/// What a query needs beyond the task itself.
+pub struct Context<'a> {
+    pub today: NaiveDate,
+    pub projects: &'a [Project],
+    pub current_user_id: Option<&'a str>,
+}
+
+impl Query {
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            chars: src.chars().collect(),
+            pos: 0,
+        };
+        let lists = parser.lists()?;
+        if parser.pos < parser.chars.len() {
+            return Err(format!("unexpected '{}'", parser.chars[parser.pos]));
+        }
+        Ok(lists)
+    }
+
+    pub fn matches(&self, task: &Task, ctx: &Context) -> bool {
+        match self {
+            Query::Or(parts) => parts.iter().any(|q| q.matches(task, ctx)),
+            Query::And(parts) => parts.iter().all(|q| q.matches(task, ctx)),
+            Query::Not(inner) => !inner.matches(task, ctx),
+            Query::Term(term) => term.matches(task, ctx),
+        }
+    }
+}
+
+impl Term {
+    fn parse(raw: &str) -> Result<Self, String> {
+        let text = raw.trim().to_lowercase();
+        if let Some(rest) = text.strip_prefix("##") {
+            return Ok(Term::Project {
+                name: rest.trim().to_string(),
+                with_children: true,
+            });
+        }
+        if let Some(rest) = text.strip_prefix('#') {
+            return Ok(Term::Project {
+                name: rest.trim().to_string(),
+                with_children: false,
+            });
+        }
+        if let Some(rest) = text.strip_prefix('@') {
+            return Ok(Term::Label(rest.trim().to_string()));
+        }
+        if let Some(rest) = text.strip_prefix("search:") {
+            return Ok(Term::Search(rest.trim().to_string()));
+        }
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let term = match words.as_slice() {
+            ["all"] | ["view", "all"] => Term::All,
+            ["today"] => Term::Today,
+            ["tomorrow"] => Term::Tomorrow,
+            ["overdue"] | ["od"] => Term::Overdue,
+            ["no", "date"] | ["no", "due", "date"] => Term::NoDate,
+            ["no", "labels"] => Term::NoLabels,
+            ["no", "priority"] => Term::Priority(1),
+            ["recurring"] => Term::Recurring,
+            ["subtask"] | ["subtasks"] => Term::Subtask,
+            ["assigned"] => Term::Assigned,
+            ["assigned", "to:", "me"] | ["assigned", "to", "me"] => Term::AssignedToMe,
+            ["assigned", "to:", "others"] | ["assigned", "to", "others"] => Term::AssignedToOthers,
+            [p] if p.len() == 2 && p.starts_with('p') => match p[1..].parse::<u8>() {
+                // Todoist's p1 is the API's priority 4.
+                Ok(n @ 1..=4) => Term::Priority(5 - n),
+                _ => return Err(format!("unsupported term '{}'", raw.trim())),
+            },
+            [n, "days"] | ["next", n, "days"] => match n.parse::<i64>() {
+                Ok(days) => Term::Days(days),
+                Err(_) => return Err(format!("unsupported term '{}'", raw.trim())),
+            },
+            _ => return Err(format!("unsupported term '{}'", raw.trim())),
+        };
+        Ok(term)
+    }
+
+    fn matches(&self, task: &Task, ctx: &Context) -> bool {
+        let due = task.due.as_ref().and_then(dates::due_date);
+        match self {
+            Term::All => true,
+            Term::Today => due == Some(ctx.today),
+            Term::Tomorrow => due == Some(ctx.today + Duration::days(1)),
+            Term::Overdue => due.is_some_and(|d| d < ctx.today),
+            Term::NoDate => task.due.is_none(),
+            Term::Days(n) => {
+                due.is_some_and(|d| d >= ctx.today && d <= ctx.today + Duration::days(*n))
+            }
+            Term::Priority(p) => task.priority == *p,
+            Term::Label(pattern) => task
+                .labels
+                .iter()
+                .any(|l| wildcard_match(pattern, &l.to_lowercase())),
+            Term::NoLabels => task.labels.is_empty(),
+            Term::Project {
+                name,
+                with_children,
+            } => project_matches(ctx, &task.project_id, name, *with_children),
+            Term::Recurring => task.due.as_ref().is_some_and(|d| d.is_recurring),
+            Term::Subtask => task.parent_id.is_some(),
+            Term::Assigned => task.responsible_uid.is_some(),
+            Term::AssignedToMe => {
+                ctx.current_user_id.is_some()
+                    && task.responsible_uid.as_deref() == ctx.current_user_id
+            }
+            Term::AssignedToOthers => task
+                .responsible_uid
+                .as_deref()
+                .is_some_and(|uid| Some(uid) != ctx.current_user_id),
+            Term::Search(text) => task.content.to_lowercase().contains(text.as_str()),
+        }
+    }
+}
+
+/// True if the task's project is `name`, or (for `##`) one of its ancestors is.
+fn project_matches(ctx: &Context, project_id: &str, name: &str, with_children: bool) -> bool {
+    let mut current = ctx.projects.iter().find(|p| p.id == project_id);
+    while let Some(project) = current {
+        if wildcard_match(name, &project.name.to_lowercase()) {
+            return true;
+        }
+        if !with_children {
+            return false;
+        }
+        current = project
+            .parent_id
+            .as_deref()
+            .and_then(|pid| ctx.projects.iter().find(|p| p.id == pid));
+    }
+    false
+}
+
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let tail: Vec<&str> = parts.collect();
+    let Some((last, middle)) = tail.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&mut self) -> Option<char> {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+        self.chars.get(self.pos).copied()
+    }
+
+    fn lists(&mut self) -> Result<Query, String> {
+        let mut parts = vec![self.or()?];
+        while self.peek() == Some(',') {
+            self.pos += 1;
+            parts.push(self.or()?);
+        }
+        Ok(flatten(parts, Query::Or))
+    }
+
+    fn or(&mut self) -> Result<Query, String> {
+        let mut parts = vec![self.and()?];
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            parts.push(self.and()?);
+        }
+        Ok(flatten(parts, Query::Or))
+    }
+
+    fn and(&mut self) -> Result<Query, String> {
+        let mut parts = vec![self.unary()?];
+        while self.peek() == Some('&') {
+            self.pos += 1;
+            parts.push(self.unary()?);
+        }
+        Ok(flatten(parts, Query::And))
+    }
+
+    fn unary(&mut self) -> Result<Query, String> {
+        match self.peek() {
+            Some('!') => {
+                self.pos += 1;
+                Ok(Query::Not(Box::new(self.unary()?)))
+            }
+            Some('(') => {
+                self.pos += 1;
+                let inner = self.or()?;
+                if self.peek() != Some(')') {
+                    return Err("missing ')'".to_string());
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            Some(_) => {
+                let start = self.pos;
+                while self
+                    .chars
+                    .get(self.pos)
+                    .is_some_and(|c| !matches!(c, '&' | '|' | '(' | ')' | ','))
+                {
+                    self.pos += 1;
+                }
+                let raw: String = self.chars[start..self.pos].iter().collect();
+                if raw.trim().is_empty() {
+                    return Err("empty term".to_string());
+                }
+                Term::parse(&raw).map(Query::Term)
+            }
+            None => Err("query ends early".to_string()),
+        }
+    }
+}
+
+fn flatten(mut parts: Vec<Query>, wrap: fn(Vec<Query>) -> Query) -> Query {
+    if parts.len() == 1 {
+        parts.remove(0)
+    } else {
+        wrap(parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatoist_core::api::models::Due;
+
+    fn task(project: &str, priority: u8, labels: &[&str], due: Option<&str>) -> Task {
+        Task {
+            project_id: project.to_string(),
+            priority,
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+            due: due.map(|d| Due {
+                date: d.to_string(),
+                ..Due::default()
+            }),
+            ..Task::default()
+        }
+    }
+
+    #[test]
+    fn evaluates_common_filters() {
+        let projects = vec![
+            Project {
+                id: "work".to_string(),
+                name: "Work".to_string(),
+                ..Project::default()
+            },
+            Project {
+                id: "launch".to_string(),
+                name: "Launch".to_string(),
+                parent_id: Some("work".to_string()),
+                ..Project::default()
+            },
+        ];
+        let ctx = Context {
+            today: NaiveDate::from_ymd_opt(2026, 3, 10).unwrap(),
+            projects: &projects,
+            current_user_id: None,
+        };
+        let urgent = task("launch", 4, &["deep-work"], Some("2026-03-09"));
+        let later = task("work", 1, &[], Some("2026-03-20"));
+
+        let q = |src: &str| Query::parse(src).unwrap();
+        assert!(q("(today | overdue) & p1").matches(&urgent, &ctx));
+        assert!(!q("(today | overdue) & p1").matches(&later, &ctx));
+        assert!(q("@deep*").matches(&urgent, &ctx));
+        assert!(q("##work & !#work").matches(&urgent, &ctx));
+        assert!(!q("#work").matches(&urgent, &ctx));
+        assert!(q("no labels, 7 days").matches(&later, &ctx));
+        assert!(!q("7 days").matches(&later, &ctx));
+        assert!(Query::parse("shared").is_err());
+        assert!(Query::parse("(p1").is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use std::io::Write as _;
+use std::process::{Command, Stdio};
+
+use ratatoist_core::settings::HooksConfig;
+use tracing::{debug, warn};
+
+/// Events a `[hooks]` command can be attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hook {
+    TaskComplete,
+    TaskAdd,
+    SyncError,
+}
+
+impl Hook {
+    pub fn label(self) -> &'static str {
+        match self {
+            Hook::TaskComplete => "task_complete",
+            Hook::TaskAdd => "task_add",
+            Hook::SyncError => "sync_error",
+        }
+    }
+
+    fn command(self, hooks: &HooksConfig) -> Option<&str> {
+        match self {
+            Hook::TaskComplete => hooks.on_task_complete.as_deref(),
+            Hook::TaskAdd => hooks.on_task_add.as_deref(),
+            Hook::SyncError => hooks.on_sync_error.as_deref(),
+        }
+        .filter(|c| !c.trim().is_empty())
+    }
+}
+
+/// Runs the command configured for `hook` through the shell, with `payload` as
+/// JSON on stdin and `RATATOIST_EVENT` set to the event name. Fire-and-forget:
+/// the command runs on its own thread and only its exit status is logged.
+pub fn fire(hooks: &HooksConfig, hook: Hook, payload: &serde_json::Value) {
+    let Some(command) = hook.command(hooks) else {
+        return;
+    };
+    let command = command.to_string();
+    let input = payload.to_string();
+    let event = hook.label();
+    std::thread::spawn(move || {
+        let mut child = match shell(&command)
+            .env("RATATOIST_EVENT", event)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(e) => {
+                warn!(event, error = %e, "could not run hook");
+                return;
+            }
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            // A hook that ignores stdin closes the pipe early; that's fine.
+            let _ = stdin.write_all(input.as_bytes());
+        }
+        match child.wait() {
+            Ok(status) if status.success() => debug!(event, "hook finished"),
+            Ok(status) => warn!(event, %status, "hook failed"),
+            Err(e) => warn!(event, error = %e, "could not wait for hook"),
+        }
+    });
+}
+
+fn shell(command: &str) -> Command {
+    if cfg!(target_os = "windows") {
+        let mut c = Command::new("cmd");
+        c.args(["/C", command]);
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.args(["-c", command]);
+        c
+    }
+}
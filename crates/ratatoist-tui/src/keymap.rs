@@ -0,0 +1,504 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::InputMode;
+
+/// One row of the `?` cheatsheet: the keys and what they do.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Binding {
+    pub keys: &'static str,
+    pub action: &'static str,
+}
+
+/// Bindings shown together under a heading.
+#[derive(Debug)]
+pub struct Group {
+    pub title: &'static str,
+    pub bindings: &'static [Binding],
+}
+
+const fn b(keys: &'static str, action: &'static str) -> Binding {
+    Binding { keys, action }
+}
+
+/// The bindings of `mode`, grouped as the cheatsheet shows them. Kept next
+/// to the key handlers in `keys.rs`: a binding added or changed there
+/// belongs here too, and the tests feed every row here through
+/// [`handle_key`](crate::keys::handle_key) to catch one that no longer
+/// does anything.
+pub fn groups(mode: &InputMode) -> &'static [Group] {
+    match mode {
+        InputMode::Vim(_) => VIM,
+        InputMode::Standard => STANDARD,
+    }
+}
+
+/// The groups of `mode` cut down to the bindings whose keys or description
+/// contain `query`, ignoring case; a group whose title matches stays whole.
+/// Groups left empty are dropped.
+pub fn search(mode: &InputMode, query: &str) -> Vec<(&'static str, Vec<&'static Binding>)> {
+    let query = query.to_lowercase();
+    let hit = |text: &str| text.to_lowercase().contains(&query);
+    groups(mode)
+        .iter()
+        .filter_map(|group| {
+            let bindings: Vec<&Binding> = if hit(group.title) {
+                group.bindings.iter().collect()
+            } else {
+                group
+                    .bindings
+                    .iter()
+                    .filter(|b| hit(b.keys) || hit(b.action))
+                    .collect()
+            };
+            (!bindings.is_empty()).then_some((group.title, bindings))
+        })
+        .collect()
+}
+
+/// Keys moved by the file the `keymap` setting names. Each entry makes the
+/// key on the left act as the one on the right, both written as the
+/// cheatsheet writes them:
+///
+/// ```toml
+/// d = "x"
+/// "Ctrl-s" = "/"
+/// ```
+///
+/// Remaps apply in the panes, not in inputs, pickers or prompts.
+#[derive(Debug, Default)]
+pub struct Remap(HashMap<(KeyCode, KeyModifiers), (KeyCode, KeyModifiers)>);
+
+impl Remap {
+    pub fn load(path: &Path) -> Result<Self> {
+        let src = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Self::parse(&src).with_context(|| format!("invalid keymap {}", path.display()))
+    }
+
+    fn parse(src: &str) -> Result<Self> {
+        let entries: HashMap<String, String> = toml::from_str(src)?;
+        let key = |name: &str| {
+            parse_key(name)
+                .map(|k| (k.code, k.modifiers))
+                .with_context(|| format!("unknown key {name:?}"))
+        };
+        let mut map = HashMap::new();
+        for (from, to) in &entries {
+            map.insert(key(from)?, key(to)?);
+        }
+        Ok(Self(map))
+    }
+
+    /// The key `key` stands for. Shift is dropped first: it's already in
+    /// the character, or in `BackTab`.
+    pub fn apply(&self, key: KeyEvent) -> KeyEvent {
+        let mut modifiers = key.modifiers;
+        if matches!(key.code, KeyCode::Char(_) | KeyCode::BackTab) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+        match self.0.get(&(key.code, modifiers)) {
+            Some(&(code, modifiers)) => KeyEvent::new(code, modifiers),
+            None => key,
+        }
+    }
+}
+
+/// A key as the cheatsheet writes it: one character or a name such as
+/// `Enter`, `PgDn` or `↑`, either behind `Ctrl-` or `Alt-`.
+pub fn parse_key(name: &str) -> Option<KeyEvent> {
+    let (modifiers, name) = if let Some(rest) = name.strip_prefix("Ctrl-") {
+        (KeyModifiers::CONTROL, rest)
+    } else if let Some(rest) = name.strip_prefix("Alt-") {
+        (KeyModifiers::ALT, rest)
+    } else {
+        (KeyModifiers::NONE, name)
+    };
+    let code = match name {
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" => KeyCode::Tab,
+        "Shift-Tab" => KeyCode::BackTab,
+        "Space" => KeyCode::Char(' '),
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PgDn" => KeyCode::PageDown,
+        "PgUp" => KeyCode::PageUp,
+        "↑" => KeyCode::Up,
+        "↓" => KeyCode::Down,
+        "←" => KeyCode::Left,
+        "→" => KeyCode::Right,
+        _ => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => return None,
+            }
+        }
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// Everything vim mode binds, as the cheatsheet lists it.
+const VIM: &[Group] = &[
+    Group {
+        title: "Navigation",
+        bindings: &[
+            b("j / k", "Move down / up"),
+            b("h / l", "Switch pane left / right"),
+            b("gg / G", "Jump to top / bottom"),
+            b("5j / 3k", "Move with a count"),
+            b("10G", "Jump to row 10"),
+            b("gi", "Jump to Inbox"),
+            b("g1 … g9", "Jump to favorite project 1–9"),
+            b("Ctrl-p", "Go to project (fuzzy)"),
+            b("Ctrl-n", "Notifications"),
+            b("H", "Recently opened / completed"),
+            b("@", "Rename / remove shared labels"),
+            b("Ctrl-t / Ctrl-w", "Open / close tab"),
+            b("gt / gT", "Next / previous tab"),
+            b("Ctrl-o / Alt-→", "Jump back / forward"),
+            b("Tab / Shift-Tab", "Next / previous pane"),
+            b("Enter", "Open project / toggle fold"),
+            b("Esc", "Go back"),
+        ],
+    },
+    Group {
+        title: "Tasks",
+        bindings: &[
+            b("x", "Complete / uncomplete"),
+            b("a", "Add task (form)"),
+            b("A", "Quick add, parsed by Todoist"),
+            b("o", "Cycle sort key"),
+            b("r", "Reverse sort direction"),
+            b("f", "Cycle filter (active/done/both)"),
+            b("Enter", "Open detail / toggle fold"),
+            b("Space", "Toggle fold / overdue section"),
+            b("/", "Search this list"),
+            b("n / N", "Next / previous match"),
+            b("yy / yu / ym", "Copy title / URL / markdown link"),
+            b("!1 – !4", "Toggle P1–P4 filter"),
+            b("1p – 4p", "Set priority P1–P4 (p: picker)"),
+            b("!!", "Triage overdue tasks in the project"),
+            b("O", "Open in browser"),
+            b("S", "Split checklist into subtasks"),
+            b("<", "Promote subtask to top level"),
+            b("*", "Pin / unpin to the dock"),
+            b("%", "Find & replace in project titles"),
+            b("E", "Set a repeating schedule"),
+            b("F", "Focus (pomodoro) on task"),
+            b(
+                "t / + / w / W",
+                "Postpone: today / tomorrow / next week / weekend",
+            ),
+        ],
+    },
+    Group {
+        title: "Today view",
+        bindings: &[
+            b("Space", "Toggle Overdue section"),
+            b("J / K", "Move task down / up in today's order"),
+            b(
+                "J / K",
+                "Move the selected task's section down / up (projects)",
+            ),
+            b("J / K", "Reorder the project or favorite (sidebar)"),
+        ],
+    },
+    Group {
+        title: "Detail pane",
+        bindings: &[
+            b("j / k", "Navigate fields"),
+            b("J / K", "Next / previous task"),
+            b("Ctrl-d / Ctrl-u", "Scroll comments (also PgDn / PgUp)"),
+            b("i / Enter", "Edit selected field"),
+            b("Tab", "Calendar (editing due date)"),
+            b("P", "Priority picker"),
+            b("p", "Jump to the parent task"),
+            b("H", "Show / hide activity history"),
+            b("c", "Add comment"),
+            b("[ / ]", "Select previous / next comment"),
+            b("r", "Reply quoting the selected comment"),
+            b("O", "Open in browser"),
+            b("E", "Set a repeating schedule"),
+            b("1 … 9", "Open numbered link"),
+            b("x", "Complete task or selected subtask"),
+            b("Esc / h", "Back to tasks"),
+        ],
+    },
+    Group {
+        title: "Projects",
+        bindings: &[
+            b("s", "Star / unstar"),
+            b("i", "Members and invites"),
+            b("w", "Workspace overview"),
+            b("/", "Filter by name (Enter jumps)"),
+        ],
+    },
+    Group {
+        title: "Folding",
+        bindings: &[
+            b("za", "Toggle fold at cursor"),
+            b("zR", "Open all folds"),
+            b("zM", "Close all folds"),
+        ],
+    },
+    Group {
+        title: "General",
+        bindings: &[
+            b(",", "Open settings"),
+            b("R", "Force full re-sync"),
+            b("Ctrl-h / Ctrl-l", "Shrink / grow sidebar"),
+            b("Z", "Toggle zen mode"),
+            b("?", "This help"),
+            b("q", "Quit"),
+            b("Ctrl-c", "Force quit"),
+        ],
+    },
+];
+
+/// Everything standard mode binds, as the cheatsheet lists it.
+const STANDARD: &[Group] = &[
+    Group {
+        title: "Navigation",
+        bindings: &[
+            b("↑ / ↓", "Move up / down"),
+            b("← / →", "Switch pane"),
+            b("Home / End", "Jump to top / bottom"),
+            b("Ctrl-p", "Go to project (fuzzy)"),
+            b("Ctrl-n", "Notifications"),
+            b("H", "Recently opened / completed"),
+            b("@", "Rename / remove shared labels"),
+            b("Ctrl-t / Ctrl-w", "Open / close tab"),
+            b("Ctrl-PgDn / Ctrl-PgUp", "Next / previous tab"),
+            b("Alt-← / Alt-→", "Jump back / forward"),
+            b("Tab / Shift-Tab", "Next / previous pane"),
+            b("Enter", "Open detail / toggle fold"),
+            b("Esc", "Go back"),
+        ],
+    },
+    Group {
+        title: "Tasks",
+        bindings: &[
+            b("Ctrl-x", "Complete / uncomplete"),
+            b("Ctrl-a", "Add task (form)"),
+            b("A", "Quick add, parsed by Todoist"),
+            b("f", "Cycle filter (active/done/both)"),
+            b("/", "Search this list"),
+            b("n / N", "Next / previous match"),
+            b("yy / yu / ym", "Copy title / URL / markdown link"),
+            b("!1 – !4", "Toggle P1–P4 filter"),
+            b("1 – 4", "Set priority P1–P4"),
+            b("!!", "Triage overdue tasks in the project"),
+            b("O", "Open in browser"),
+            b("S", "Split checklist into subtasks"),
+            b("<", "Promote subtask to top level"),
+            b("*", "Pin / unpin to the dock"),
+            b("%", "Find & replace in project titles"),
+            b("E", "Set a repeating schedule"),
+            b("F", "Focus (pomodoro) on task"),
+            b(
+                "t / + / w / W",
+                "Postpone: today / tomorrow / next week / weekend",
+            ),
+        ],
+    },
+    Group {
+        title: "Today view",
+        bindings: &[
+            b("J / K", "Move task down / up in today's order"),
+            b(
+                "J / K",
+                "Move the selected task's section down / up (projects)",
+            ),
+            b("J / K", "Reorder the project or favorite (sidebar)"),
+        ],
+    },
+    Group {
+        title: "Projects",
+        bindings: &[
+            b("i", "Members and invites"),
+            b("w", "Workspace overview"),
+            b("/", "Filter by name (Enter jumps)"),
+        ],
+    },
+    Group {
+        title: "Detail pane",
+        bindings: &[
+            b("↑ / ↓", "Navigate fields"),
+            b("J / K", "Next / previous task"),
+            b("Ctrl-d / Ctrl-u", "Scroll comments (also PgDn / PgUp)"),
+            b("Enter", "Edit selected field"),
+            b("Tab", "Calendar (editing due date)"),
+            b("p", "Jump to the parent task"),
+            b("H", "Show / hide activity history"),
+            b("[ / ] / r", "Select a comment / reply quoting it"),
+            b("x", "Complete task or selected subtask"),
+        ],
+    },
+    Group {
+        title: "General",
+        bindings: &[
+            b(",", "Open settings"),
+            b("R", "Force full re-sync"),
+            b("Ctrl-h / Ctrl-l", "Shrink / grow sidebar"),
+            b("Z", "Toggle zen mode"),
+            b("?", "This help"),
+            b("q", "Quit"),
+            b("Ctrl-c", "Force quit"),
+        ],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{App, Pane, TaskSearch, VimState};
+    use crate::keys::{KeyAction, handle_key};
+    use ratatoist_core::api::client::TodoistClient;
+    use ratatoist_core::settings::Settings;
+
+    #[test]
+    fn search_matches_keys_descriptions_and_headings() {
+        let vim = InputMode::Vim(VimState::Normal);
+        assert_eq!(search(&vim, "").len(), VIM.len());
+
+        let found = search(&vim, "ZEN");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, "General");
+        assert_eq!(found[0].1, vec![&b("Z", "Toggle zen mode")]);
+
+        let folding = search(&vim, "folding");
+        assert_eq!(folding[0].1.len(), 3);
+
+        assert!(search(&InputMode::Standard, "no such binding").is_empty());
+    }
+
+    /// The key presses each alternative in `keys` stands for: `j / k` is two
+    /// one-key sequences, `yy` one two-key sequence, and both ends of a
+    /// `1 – 4` or `g1 … g9` range are tried.
+    fn sequences(keys: &str) -> Vec<Vec<KeyEvent>> {
+        keys.split(" / ")
+            .flat_map(|alt| alt.split(['…', '–']))
+            .map(|alt| {
+                let alt = alt.trim();
+                match parse_key(alt) {
+                    Some(key) => vec![key],
+                    None => alt
+                        .chars()
+                        .map(|c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// An app in the pane `group` describes, with a search open so `n` / `N`
+    /// have matches to move between.
+    fn app_for(mode: &InputMode, group: &str) -> App {
+        let mut app = App::new(
+            TodoistClient::demo(),
+            Settings::default(),
+            false,
+            true,
+            false,
+        );
+        app.input_mode = *mode;
+        app.active_pane = match group {
+            "Projects" => Pane::Projects,
+            "Detail pane" => Pane::Detail,
+            _ => Pane::Tasks,
+        };
+        app.today_view_active = group == "Today view";
+        app.task_search = Some(TaskSearch {
+            query: "a".to_string(),
+            editing: false,
+        });
+        app
+    }
+
+    #[test]
+    fn remap_makes_a_key_act_as_another() {
+        let remap = Remap::parse("d = \"x\"\n\"Ctrl-s\" = \"/\"\nD = \"Shift-Tab\"\n").unwrap();
+        let press = |code, modifiers| remap.apply(KeyEvent::new(code, modifiers));
+        assert_eq!(
+            press(KeyCode::Char('d'), KeyModifiers::NONE),
+            KeyEvent::from(KeyCode::Char('x'))
+        );
+        assert_eq!(
+            press(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            KeyEvent::from(KeyCode::Char('/'))
+        );
+        assert_eq!(
+            press(KeyCode::Char('D'), KeyModifiers::SHIFT),
+            KeyEvent::from(KeyCode::BackTab)
+        );
+        assert_eq!(
+            press(KeyCode::Char('x'), KeyModifiers::NONE),
+            KeyEvent::from(KeyCode::Char('x'))
+        );
+        assert!(Remap::parse("Hyper-x = \"y\"").is_err());
+    }
+
+    /// Key handling keeps chord prefixes in statics, so tests that press
+    /// keys take turns.
+    static PRESSING: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    fn a_g_prefix_only_reaches_the_next_key() {
+        let _turn = PRESSING.lock().unwrap();
+        let mut app = app_for(&InputMode::Vim(VimState::Normal), "Tasks");
+        let mut press = |c| handle_key(&mut app, KeyEvent::from(KeyCode::Char(c)));
+        assert!(matches!(press('g'), KeyAction::Consumed));
+        assert!(matches!(press('g'), KeyAction::Consumed));
+        assert!(matches!(press('t'), KeyAction::Postpone(_)));
+        assert!(matches!(press('g'), KeyAction::Consumed));
+        assert!(matches!(press('t'), KeyAction::SwitchTab(1)));
+    }
+
+    #[test]
+    fn keys_after_a_finished_g_chord_keep_their_meaning() {
+        let _turn = PRESSING.lock().unwrap();
+        let mut app = app_for(&InputMode::Vim(VimState::Normal), "Projects");
+        let mut press = |c| handle_key(&mut app, KeyEvent::from(KeyCode::Char(c)));
+        press('g');
+        press('x');
+        assert!(matches!(press('i'), KeyAction::ShowProjectInfo));
+        press('g');
+        press('g');
+        assert!(matches!(press('i'), KeyAction::ShowProjectInfo));
+    }
+
+    #[test]
+    fn every_listed_binding_reaches_a_handler() {
+        let _turn = PRESSING.lock().unwrap();
+        // Only bound while editing a due date, which needs a task open.
+        let contextual = [("Detail pane", "Tab")];
+        let mut unbound = Vec::new();
+        for mode in [InputMode::Vim(VimState::Normal), InputMode::Standard] {
+            for group in groups(&mode) {
+                for binding in group.bindings {
+                    if contextual.contains(&(group.title, binding.keys)) {
+                        continue;
+                    }
+                    for sequence in sequences(binding.keys) {
+                        let mut app = app_for(&mode, group.title);
+                        let mut last = KeyAction::None;
+                        for key in &sequence {
+                            last = handle_key(&mut app, *key);
+                        }
+                        // Drops any chord or count left pending.
+                        handle_key(&mut app, KeyEvent::from(KeyCode::Esc));
+                        if matches!(last, KeyAction::None) {
+                            unbound.push(format!("{mode:?} {}: {}", group.title, binding.keys));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(unbound.is_empty(), "listed but not handled: {unbound:#?}");
+    }
+}
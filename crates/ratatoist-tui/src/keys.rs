@@ -1,8 +1,14 @@
 use std::sync::Mutex;
 
+use chrono::{Days, Months};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-use crate::app::{App, DOCK_ITEMS, InputMode, Pane, ProjectNavItem, VimState};
+use crate::app::{
+    App, DockItem, FavoriteView, InputMode, JumperPurpose, LabelChange, LabelManager, OverviewRow,
+    Pane, ProjectJumper, ProjectNavItem, RecurringChoice, TaskSearch, TriageAction, VimState,
+};
+use crate::tour::TourGoal;
+use crate::ui::dates::Postpone;
 
 pub enum KeyAction {
     Quit,
@@ -17,15 +23,56 @@ pub enum KeyAction {
     OpenAllFolds,
     CloseAllFolds,
     CompleteTask,
-    #[allow(dead_code)]
     OpenPriorityPicker,
     SelectPriority,
+    /// API priority (4 is Todoist's P1).
+    SetPriority(u8),
     StarProject,
+    ShowProjectInfo,
+    OpenWorkspaceOverview,
+    ToggleZen,
+    YankContent,
+    YankUrl,
+    YankMarkdown,
+    PasteInput,
+    OpenInBrowser,
+    SplitTask,
+    PromoteTask,
+    StartFindReplace,
+    OpenTab,
+    CloseTab,
+    SwitchTab(isize),
+    OpenRecent,
+    TogglePin,
+    JumpBack,
+    JumpForward,
+    ApplyFindReplace,
+    ApplyLabelChange,
+    OpenRecurrence,
+    OpenParentTask,
+    ToggleHistory,
+    ApplyRecurrence,
+    Postpone(Postpone),
+    MoveDayOrder(i32),
+    MoveSection(i32),
+    MoveSidebarItem(i32),
+    StartFocus,
+    FinishFocus,
+    AbandonFocus,
+    RescheduleOverdue,
+    ConfirmAccept,
+    CompleteRecurring(RecurringChoice),
+    ShrinkSidebar,
+    GrowSidebar,
     CycleFilter,
     CycleSort,
+    ReverseSort,
     ForceResync,
+    MarkNotificationRead,
+    MarkAllNotificationsRead,
     StartInput,
     StartCommentInput,
+    StartQuickAdd,
     StartFieldEdit,
     SubmitInput,
     SubmitForm,
@@ -36,10 +83,19 @@ pub enum KeyAction {
     CancelInput,
     DetailFieldUp,
     DetailFieldDown,
+    DetailTask(isize),
+    ScrollComments(i32),
+    SelectComment(isize),
+    ReplyToComment,
+    TourStep(isize),
+    EndTour,
     OpenThemePicker,
     SelectTheme,
     CloseThemePicker,
     TodayViewSelected,
+    FavoriteSelected(FavoriteView),
+    StartTriage,
+    Triage(TriageAction),
     ToggleOverdueSection,
     Consumed,
     None,
@@ -58,22 +114,169 @@ fn set_pending_z() {
     *PENDING_Z.lock().unwrap() = true;
 }
 
+static PENDING_Y: Mutex<bool> = Mutex::new(false);
+
+fn take_pending_y() -> bool {
+    let mut pending = PENDING_Y.lock().unwrap();
+    let was = *pending;
+    *pending = false;
+    was
+}
+
+fn set_pending_y() {
+    *PENDING_Y.lock().unwrap() = true;
+}
+
+/// Second key of a `y` chord: `yy` title, `yu` URL, `ym` markdown link.
+fn yank_chord(code: KeyCode) -> KeyAction {
+    match code {
+        KeyCode::Char('y') => KeyAction::YankContent,
+        KeyCode::Char('u') => KeyAction::YankUrl,
+        KeyCode::Char('m') => KeyAction::YankMarkdown,
+        _ => KeyAction::Consumed,
+    }
+}
+
+static PENDING_G: Mutex<bool> = Mutex::new(false);
+
+fn take_pending_g() -> bool {
+    let mut pending = PENDING_G.lock().unwrap();
+    let was = *pending;
+    *pending = false;
+    was
+}
+
+fn set_pending_g() {
+    *PENDING_G.lock().unwrap() = true;
+}
+
+static PENDING_BANG: Mutex<bool> = Mutex::new(false);
+
+fn take_pending_bang() -> bool {
+    let mut pending = PENDING_BANG.lock().unwrap();
+    let was = *pending;
+    *pending = false;
+    was
+}
+
+fn set_pending_bang() {
+    *PENDING_BANG.lock().unwrap() = true;
+}
+
+/// Second key of a `!` chord: `!1`–`!4` toggle the matching priority filter,
+/// `!!` starts overdue triage.
+fn priority_chord(app: &mut App, code: KeyCode) -> KeyAction {
+    if code == KeyCode::Char('!') {
+        return KeyAction::StartTriage;
+    }
+    let KeyCode::Char(c @ '1'..='4') = code else {
+        return KeyAction::Consumed;
+    };
+    // Todoist's p1 is the API's priority 4.
+    let item = DockItem::Priority(5 - (c as u8 - b'0'));
+    app.dock_filter = if app.dock_filter == Some(item) {
+        None
+    } else {
+        Some(item)
+    };
+    let visible_len = app.visible_tasks().len();
+    app.selected_task = app.selected_task.min(visible_len.saturating_sub(1));
+    KeyAction::Consumed
+}
+
+// Vim count prefix (`5j`, `10G`); capped so a held digit can't overflow.
+static PENDING_COUNT: Mutex<Option<usize>> = Mutex::new(None);
+const MAX_COUNT: usize = 9999;
+
+/// Rows Ctrl-d / Ctrl-u and PageDown / PageUp move the comment thread.
+const COMMENTS_PAGE: i32 = 5;
+
+/// Rows Ctrl-d / Ctrl-u and PageDown / PageUp move the cheatsheet.
+const HELP_PAGE: i32 = 10;
+
+fn take_pending_count() -> Option<usize> {
+    PENDING_COUNT.lock().unwrap().take()
+}
+
+fn push_count_digit(digit: usize) {
+    let mut pending = PENDING_COUNT.lock().unwrap();
+    *pending = Some((pending.unwrap_or(0) * 10 + digit).min(MAX_COUNT));
+}
+
 pub fn handle_key(app: &mut App, key: KeyEvent) -> KeyAction {
+    // A `g` prefix is for the very next key, whichever handler gets it.
+    let after_g = take_pending_g();
+
     if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
         return KeyAction::Quit;
     }
 
-    if app.show_help {
+    if app.focus.is_some() {
         return match key.code {
-            KeyCode::Char('?') | KeyCode::Esc | KeyCode::Char('q') => KeyAction::ToggleHelp,
+            KeyCode::Enter => KeyAction::FinishFocus,
+            KeyCode::Esc | KeyCode::Char('q') => KeyAction::AbandonFocus,
             _ => KeyAction::Consumed,
         };
     }
 
+    if app.help.is_some() {
+        return handle_cheatsheet(app, key);
+    }
+
+    if app.confirm.is_some() {
+        return match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => KeyAction::ConfirmAccept,
+            KeyCode::Char('n') | KeyCode::Esc => {
+                app.decline_confirm();
+                KeyAction::Consumed
+            }
+            _ => KeyAction::Consumed,
+        };
+    }
+
+    if app.show_recurring_prompt {
+        return match key.code {
+            KeyCode::Char('c') | KeyCode::Enter => {
+                KeyAction::CompleteRecurring(RecurringChoice::Occurrence)
+            }
+            KeyCode::Char('f') => KeyAction::CompleteRecurring(RecurringChoice::Forever),
+            KeyCode::Char('s') => KeyAction::CompleteRecurring(RecurringChoice::Skip),
+            KeyCode::Esc | KeyCode::Char('q') => {
+                app.show_recurring_prompt = false;
+                KeyAction::Consumed
+            }
+            _ => KeyAction::Consumed,
+        };
+    }
+
+    if app.triage.is_some() {
+        return handle_triage(app, key);
+    }
+
+    if app.recurrence_editor.is_some() {
+        return handle_recurrence_editor(app, key);
+    }
+
     if app.show_priority_picker {
         return handle_priority_picker(app, key);
     }
 
+    if app.section_picker.is_some() {
+        return handle_section_picker(app, key);
+    }
+
+    if app.date_picker.is_some() {
+        return handle_date_picker(app, key);
+    }
+
+    if app.project_jumper.is_some() {
+        return handle_project_jumper(app, key);
+    }
+
+    if app.form_label_picker.is_some() {
+        return handle_form_label_picker(app, key);
+    }
+
     if let Some(form) = &app.task_form {
         if form.editing {
             return handle_input(app, key);
@@ -89,6 +292,83 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> KeyAction {
         return handle_theme_picker(app, key);
     }
 
+    if app.show_notifications {
+        return handle_notifications(app, key);
+    }
+
+    if app.show_workspace_overview {
+        return handle_workspace_overview(app, key);
+    }
+
+    if app.recent_panel.is_some() {
+        return handle_recent_panel(app, key);
+    }
+
+    if app.show_project_info {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Char('q' | 'i')) {
+            app.show_project_info = false;
+        }
+        return KeyAction::Consumed;
+    }
+
+    if app.project_filter.is_some() {
+        return handle_project_filter(app, key);
+    }
+
+    if app.find_replace.is_some() {
+        return handle_find_replace(app, key);
+    }
+
+    if app.label_manager.is_some() {
+        return handle_label_manager(app, key);
+    }
+
+    if app.task_search.as_ref().is_some_and(|s| s.editing) {
+        return handle_task_search(app, key);
+    }
+
+    // Nothing is being typed from here on, so user remaps apply.
+    let key = app.remap.apply(key);
+
+    if let Some(tour) = &app.tour
+        && let Some(action) = handle_tour(tour.current().goal, key)
+    {
+        return action;
+    }
+
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('p') {
+        app.project_jumper = Some(ProjectJumper::default());
+        return KeyAction::Consumed;
+    }
+
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('n') {
+        app.show_notifications = true;
+        app.notification_selection = 0;
+        return KeyAction::Consumed;
+    }
+
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        match key.code {
+            KeyCode::Char('t') => return KeyAction::OpenTab,
+            KeyCode::Char('w') => return KeyAction::CloseTab,
+            // Forward is `Alt-→`: most terminals send `Ctrl-i` as Tab, so
+            // this only fires where the terminal reports it on its own.
+            KeyCode::Char('o') => return KeyAction::JumpBack,
+            KeyCode::Char('i') => return KeyAction::JumpForward,
+            KeyCode::PageDown => return KeyAction::SwitchTab(1),
+            KeyCode::PageUp => return KeyAction::SwitchTab(-1),
+            _ => {}
+        }
+    }
+
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        match key.code {
+            KeyCode::Left => return KeyAction::JumpBack,
+            KeyCode::Right => return KeyAction::JumpForward,
+            _ => {}
+        }
+    }
+
     if matches!(app.active_pane, Pane::Settings) {
         return handle_settings(app, key);
     }
@@ -102,17 +382,18 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> KeyAction {
     }
 
     match app.input_mode {
-        InputMode::Vim(state) => handle_vim(app, key, state),
+        InputMode::Vim(state) => handle_vim(app, key, state, after_g),
         InputMode::Standard => handle_standard(app, key),
     }
 }
 
 fn handle_dock_nav(app: &mut App, key: KeyEvent) -> KeyAction {
     let focus = app.dock_focus.unwrap_or(0);
+    let items = app.dock_items();
 
     match key.code {
         KeyCode::Char('l') | KeyCode::Right | KeyCode::Tab => {
-            if focus + 1 >= DOCK_ITEMS.len() {
+            if focus + 1 >= items.len() {
                 app.dock_focus = None;
                 app.active_pane = Pane::Projects;
             } else {
@@ -130,19 +411,19 @@ fn handle_dock_nav(app: &mut App, key: KeyEvent) -> KeyAction {
             KeyAction::Consumed
         }
         KeyCode::Char('j') | KeyCode::Down => {
-            app.dock_focus = Some((focus + 1) % DOCK_ITEMS.len());
+            app.dock_focus = Some((focus + 1) % items.len());
             KeyAction::Consumed
         }
         KeyCode::Char('k') | KeyCode::Up => {
             app.dock_focus = Some(if focus == 0 {
-                DOCK_ITEMS.len() - 1
+                items.len() - 1
             } else {
                 focus - 1
             });
             KeyAction::Consumed
         }
         KeyCode::Enter | KeyCode::Char(' ') => {
-            let item = DOCK_ITEMS[focus];
+            let item = items[focus.min(items.len() - 1)];
             app.dock_filter = if app.dock_filter == Some(item) {
                 None
             } else {
@@ -166,9 +447,314 @@ fn handle_dock_nav(app: &mut App, key: KeyEvent) -> KeyAction {
     }
 }
 
+fn handle_project_filter(app: &mut App, key: KeyEvent) -> KeyAction {
+    let Some(query) = &mut app.project_filter else {
+        return KeyAction::None;
+    };
+    match key.code {
+        KeyCode::Esc => {
+            app.project_filter = None;
+            KeyAction::Consumed
+        }
+        KeyCode::Enter => {
+            let first = app.first_filtered_project();
+            app.project_filter = None;
+            match first {
+                Some(i) => {
+                    app.folder_cursor = None;
+                    app.selected_project = i;
+                    KeyAction::ProjectChanged
+                }
+                None => KeyAction::Consumed,
+            }
+        }
+        KeyCode::Backspace => {
+            query.pop();
+            KeyAction::Consumed
+        }
+        KeyCode::Char(c) => {
+            query.push(c);
+            KeyAction::Consumed
+        }
+        _ => KeyAction::Consumed,
+    }
+}
+
+fn handle_cheatsheet(app: &mut App, key: KeyEvent) -> KeyAction {
+    let Some(help) = &mut app.help else {
+        return KeyAction::None;
+    };
+    if help.searching {
+        match key.code {
+            KeyCode::Esc => {
+                help.query.clear();
+                help.searching = false;
+            }
+            KeyCode::Enter => help.searching = false,
+            KeyCode::Backspace => {
+                help.query.pop();
+            }
+            KeyCode::Char(c) => help.query.push(c),
+            _ => {}
+        }
+        help.scroll = 0;
+        return KeyAction::Consumed;
+    }
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc if !help.query.is_empty() => {
+            help.query.clear();
+            help.scroll = 0;
+        }
+        KeyCode::Char('?') | KeyCode::Esc | KeyCode::Char('q') => return KeyAction::ToggleHelp,
+        KeyCode::Char('/') => help.searching = true,
+        KeyCode::Char('d') if ctrl => help.scroll_by(HELP_PAGE),
+        KeyCode::Char('u') if ctrl => help.scroll_by(-HELP_PAGE),
+        KeyCode::PageDown => help.scroll_by(HELP_PAGE),
+        KeyCode::PageUp => help.scroll_by(-HELP_PAGE),
+        KeyCode::Char('j') | KeyCode::Down => help.scroll_by(1),
+        KeyCode::Char('k') | KeyCode::Up => help.scroll_by(-1),
+        KeyCode::Char('g') | KeyCode::Home => help.scroll = 0,
+        KeyCode::Char('G') | KeyCode::End => help.scroll_by(i32::from(u16::MAX)),
+        _ => {}
+    }
+    KeyAction::Consumed
+}
+
+/// A card to read takes every key; while a card asks for something to be
+/// done, keys go to the app as usual and only Esc (skip it) is the tour's.
+fn handle_tour(goal: TourGoal, key: KeyEvent) -> Option<KeyAction> {
+    let action = match (goal, key.code) {
+        (TourGoal::Read, KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Right) => {
+            KeyAction::TourStep(1)
+        }
+        (TourGoal::Read, KeyCode::Backspace | KeyCode::Left) => KeyAction::TourStep(-1),
+        (TourGoal::Read, KeyCode::Esc | KeyCode::Char('q')) => KeyAction::EndTour,
+        (TourGoal::Read, _) => KeyAction::Consumed,
+        (_, KeyCode::Esc) => KeyAction::TourStep(1),
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn handle_task_search(app: &mut App, key: KeyEvent) -> KeyAction {
+    let Some(search) = &mut app.task_search else {
+        return KeyAction::None;
+    };
+    match key.code {
+        KeyCode::Esc => {
+            app.task_search = None;
+            KeyAction::Consumed
+        }
+        KeyCode::Enter => {
+            if search.query.is_empty() {
+                app.task_search = None;
+            } else {
+                search.editing = false;
+                app.jump_to_search_match(true);
+            }
+            KeyAction::Consumed
+        }
+        KeyCode::Backspace => {
+            search.query.pop();
+            KeyAction::Consumed
+        }
+        KeyCode::Char(c) => {
+            search.query.push(c);
+            KeyAction::Consumed
+        }
+        _ => KeyAction::Consumed,
+    }
+}
+
+fn start_task_search(app: &mut App) -> KeyAction {
+    app.task_search = Some(TaskSearch {
+        query: String::new(),
+        editing: true,
+    });
+    KeyAction::Consumed
+}
+
+fn handle_project_jumper(app: &mut App, key: KeyEvent) -> KeyAction {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let match_count = app.project_jumper_matches().len();
+    let Some(jumper) = &mut app.project_jumper else {
+        return KeyAction::None;
+    };
+    match key.code {
+        KeyCode::Esc => {
+            app.project_jumper = None;
+            KeyAction::Consumed
+        }
+        KeyCode::Enter => {
+            let selection = jumper.selection;
+            let purpose = jumper.purpose;
+            let target = app.project_jumper_matches().get(selection).map(|(i, _)| *i);
+            app.project_jumper = None;
+            let Some(i) = target else {
+                return KeyAction::Consumed;
+            };
+            match purpose {
+                JumperPurpose::Goto => {
+                    app.active_pane = Pane::Tasks;
+                    jump_to_project(app, i)
+                }
+                JumperPurpose::Form => {
+                    app.set_form_project(i);
+                    KeyAction::Consumed
+                }
+                JumperPurpose::MoveTask => {
+                    app.move_detail_task_to_project(i);
+                    KeyAction::Consumed
+                }
+            }
+        }
+        KeyCode::Down | KeyCode::Tab => {
+            jumper.selection = (jumper.selection + 1) % match_count.max(1);
+            KeyAction::Consumed
+        }
+        KeyCode::Char('n' | 'j') if ctrl => {
+            jumper.selection = (jumper.selection + 1) % match_count.max(1);
+            KeyAction::Consumed
+        }
+        KeyCode::Up | KeyCode::BackTab => {
+            jumper.selection = jumper
+                .selection
+                .checked_sub(1)
+                .unwrap_or(match_count.saturating_sub(1));
+            KeyAction::Consumed
+        }
+        KeyCode::Char('p' | 'k') if ctrl => {
+            jumper.selection = jumper
+                .selection
+                .checked_sub(1)
+                .unwrap_or(match_count.saturating_sub(1));
+            KeyAction::Consumed
+        }
+        KeyCode::Backspace => {
+            jumper.query.pop();
+            jumper.selection = 0;
+            KeyAction::Consumed
+        }
+        KeyCode::Char(c) if !ctrl => {
+            jumper.query.push(c);
+            jumper.selection = 0;
+            KeyAction::Consumed
+        }
+        _ => KeyAction::Consumed,
+    }
+}
+
+/// Enter in the find field moves on to the replacement; Enter there applies.
+fn handle_find_replace(app: &mut App, key: KeyEvent) -> KeyAction {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let Some(fr) = &mut app.find_replace else {
+        return KeyAction::None;
+    };
+    let field = if fr.on_replace {
+        &mut fr.replace
+    } else {
+        &mut fr.find
+    };
+    match key.code {
+        KeyCode::Esc => {
+            app.find_replace = None;
+            KeyAction::Consumed
+        }
+        KeyCode::Enter if fr.on_replace => KeyAction::ApplyFindReplace,
+        KeyCode::Enter | KeyCode::Tab | KeyCode::BackTab | KeyCode::Down | KeyCode::Up => {
+            fr.on_replace = !fr.on_replace;
+            KeyAction::Consumed
+        }
+        KeyCode::Backspace => {
+            field.pop();
+            KeyAction::Consumed
+        }
+        KeyCode::Char(c) if !ctrl => {
+            field.push(c);
+            KeyAction::Consumed
+        }
+        _ => KeyAction::Consumed,
+    }
+}
+
+fn open_label_manager(app: &mut App) -> KeyAction {
+    if app.shared_labels().is_empty() {
+        app.flash("No shared labels");
+    } else {
+        app.label_manager = Some(LabelManager::default());
+    }
+    KeyAction::Consumed
+}
+
+/// `r` types a new name and `d` removes; either waits for a confirming
+/// Enter, with Esc stepping back out one stage at a time.
+fn handle_label_manager(app: &mut App, key: KeyEvent) -> KeyAction {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let labels = app.shared_labels();
+    let Some(manager) = &mut app.label_manager else {
+        return KeyAction::None;
+    };
+    let len = labels.len();
+    manager.selection = manager.selection.min(len.saturating_sub(1));
+
+    if manager.confirm.is_some() {
+        return match key.code {
+            KeyCode::Enter | KeyCode::Char('y') => KeyAction::ApplyLabelChange,
+            _ => {
+                manager.confirm = None;
+                KeyAction::Consumed
+            }
+        };
+    }
+
+    if let Some(name) = &mut manager.rename {
+        match key.code {
+            KeyCode::Esc => manager.rename = None,
+            KeyCode::Enter => {
+                let name = name.trim().trim_start_matches('@').to_string();
+                let current = labels.get(manager.selection).map(|(l, _)| l.as_str());
+                if !name.is_empty() && Some(name.as_str()) != current {
+                    manager.rename = None;
+                    manager.confirm = Some(LabelChange::Rename(name));
+                }
+            }
+            KeyCode::Backspace => {
+                name.pop();
+            }
+            KeyCode::Char(c) if !ctrl && c != ' ' => name.push(c),
+            _ => {}
+        }
+        return KeyAction::Consumed;
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q' | '@') => app.label_manager = None,
+        KeyCode::Char('j') | KeyCode::Down => {
+            manager.selection = (manager.selection + 1) % len.max(1);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            manager.selection = manager
+                .selection
+                .checked_sub(1)
+                .unwrap_or(len.saturating_sub(1));
+        }
+        KeyCode::Char('r') | KeyCode::Enter => {
+            manager.rename = labels.get(manager.selection).map(|(l, _)| l.clone());
+        }
+        KeyCode::Char('d') | KeyCode::Delete => manager.confirm = Some(LabelChange::Remove),
+        _ => {}
+    }
+    KeyAction::Consumed
+}
+
 fn handle_input(app: &mut App, key: KeyEvent) -> KeyAction {
     let in_form = app.task_form.is_some();
 
+    if crate::clipboard::is_paste_key(&key) {
+        return KeyAction::PasteInput;
+    }
+
     match key.code {
         KeyCode::Esc => {
             if in_form {
@@ -189,6 +775,10 @@ fn handle_input(app: &mut App, key: KeyEvent) -> KeyAction {
             }
         }
         KeyCode::Enter => KeyAction::SubmitInput,
+        KeyCode::Tab if app.editing_due_field() => {
+            app.open_date_picker();
+            KeyAction::Consumed
+        }
         KeyCode::Backspace => {
             app.input_buffer.pop();
             KeyAction::Consumed
@@ -201,6 +791,32 @@ fn handle_input(app: &mut App, key: KeyEvent) -> KeyAction {
     }
 }
 
+fn handle_date_picker(app: &mut App, key: KeyEvent) -> KeyAction {
+    let Some(date) = app.date_picker else {
+        return KeyAction::None;
+    };
+    let moved = match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.date_picker = None;
+            return KeyAction::Consumed;
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            app.pick_date();
+            return KeyAction::Consumed;
+        }
+        KeyCode::Char('h') | KeyCode::Left => date.pred_opt(),
+        KeyCode::Char('l') | KeyCode::Right => date.succ_opt(),
+        KeyCode::Char('j') | KeyCode::Down => date.checked_add_days(Days::new(7)),
+        KeyCode::Char('k') | KeyCode::Up => date.checked_sub_days(Days::new(7)),
+        KeyCode::Char('L') | KeyCode::PageDown => date.checked_add_months(Months::new(1)),
+        KeyCode::Char('H') | KeyCode::PageUp => date.checked_sub_months(Months::new(1)),
+        KeyCode::Char('t') => Some(crate::ui::dates::today()),
+        _ => Some(date),
+    };
+    app.date_picker = moved.or(Some(date));
+    KeyAction::Consumed
+}
+
 fn handle_form_nav(app: &mut App, key: KeyEvent) -> KeyAction {
     match key.code {
         KeyCode::Char('q') => KeyAction::CancelInput,
@@ -227,6 +843,50 @@ fn handle_form_nav(app: &mut App, key: KeyEvent) -> KeyAction {
     }
 }
 
+/// Space toggles the highlighted label on the new task; Enter or Esc goes
+/// back to the form.
+fn handle_form_label_picker(app: &mut App, key: KeyEvent) -> KeyAction {
+    let len = app.form_label_names().len();
+    let Some(selection) = app.form_label_picker else {
+        return KeyAction::None;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.form_label_picker = None,
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.form_label_picker = Some((selection + 1) % len.max(1));
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.form_label_picker = Some(selection.checked_sub(1).unwrap_or(len.saturating_sub(1)));
+        }
+        KeyCode::Char(' ' | 'x') => {
+            if let Some(name) = app.form_label_names().get(selection).map(|n| n.to_string()) {
+                app.toggle_form_label(&name);
+            }
+        }
+        _ => {}
+    }
+    KeyAction::Consumed
+}
+
+fn handle_section_picker(app: &mut App, key: KeyEvent) -> KeyAction {
+    let len = app.section_picker_options().len();
+    let Some(selection) = app.section_picker else {
+        return KeyAction::None;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.section_picker = None,
+        KeyCode::Enter => app.pick_section(),
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.section_picker = Some((selection + 1) % len.max(1));
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.section_picker = Some(selection.checked_sub(1).unwrap_or(len.saturating_sub(1)));
+        }
+        _ => {}
+    }
+    KeyAction::Consumed
+}
+
 fn handle_theme_picker(app: &mut App, key: KeyEvent) -> KeyAction {
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => KeyAction::CloseThemePicker,
@@ -249,6 +909,104 @@ fn handle_theme_picker(app: &mut App, key: KeyEvent) -> KeyAction {
     }
 }
 
+fn handle_notifications(app: &mut App, key: KeyEvent) -> KeyAction {
+    let len = app.notifications.len();
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.show_notifications = false;
+            KeyAction::Consumed
+        }
+        KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.show_notifications = false;
+            KeyAction::Consumed
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.notification_selection = (app.notification_selection + 1) % len.max(1);
+            KeyAction::Consumed
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.notification_selection = app
+                .notification_selection
+                .checked_sub(1)
+                .unwrap_or(len.saturating_sub(1));
+            KeyAction::Consumed
+        }
+        KeyCode::Char('r') | KeyCode::Enter => KeyAction::MarkNotificationRead,
+        KeyCode::Char('R') => KeyAction::MarkAllNotificationsRead,
+        _ => KeyAction::Consumed,
+    }
+}
+
+fn open_recent_panel(app: &mut App) -> KeyAction {
+    if app.recent_tasks().is_empty() {
+        app.flash("Nothing opened or completed yet");
+    } else {
+        app.recent_panel = Some(0);
+    }
+    KeyAction::Consumed
+}
+
+fn handle_recent_panel(app: &mut App, key: KeyEvent) -> KeyAction {
+    let len = app.recent_tasks().len();
+    let Some(selection) = app.recent_panel.as_mut() else {
+        return KeyAction::None;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q' | 'H') => {
+            app.recent_panel = None;
+            KeyAction::Consumed
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            *selection = (*selection + 1) % len.max(1);
+            KeyAction::Consumed
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            *selection = selection.checked_sub(1).unwrap_or(len.saturating_sub(1));
+            KeyAction::Consumed
+        }
+        KeyCode::Enter => KeyAction::OpenRecent,
+        _ => KeyAction::Consumed,
+    }
+}
+
+fn handle_workspace_overview(app: &mut App, key: KeyEvent) -> KeyAction {
+    let projects: Vec<usize> = app
+        .workspace_overview_rows()
+        .into_iter()
+        .filter_map(|row| match row {
+            OverviewRow::Project(i) => Some(i),
+            _ => None,
+        })
+        .collect();
+    let len = projects.len();
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q' | 'w') => {
+            app.show_workspace_overview = false;
+            KeyAction::Consumed
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.workspace_overview_selection = (app.workspace_overview_selection + 1) % len.max(1);
+            KeyAction::Consumed
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.workspace_overview_selection = app
+                .workspace_overview_selection
+                .checked_sub(1)
+                .unwrap_or(len.saturating_sub(1));
+            KeyAction::Consumed
+        }
+        KeyCode::Enter => match projects.get(app.workspace_overview_selection) {
+            Some(&i) => {
+                app.show_workspace_overview = false;
+                app.active_pane = Pane::Tasks;
+                jump_to_project(app, i)
+            }
+            None => KeyAction::Consumed,
+        },
+        _ => KeyAction::Consumed,
+    }
+}
+
 fn handle_priority_picker(app: &mut App, key: KeyEvent) -> KeyAction {
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => {
@@ -294,8 +1052,20 @@ fn handle_priority_picker(app: &mut App, key: KeyEvent) -> KeyAction {
     }
 }
 
-fn handle_detail(_app: &mut App, key: KeyEvent) -> KeyAction {
+fn handle_detail(app: &mut App, key: KeyEvent) -> KeyAction {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return match key.code {
+            KeyCode::Char('d') => KeyAction::ScrollComments(COMMENTS_PAGE),
+            KeyCode::Char('u') => KeyAction::ScrollComments(-COMMENTS_PAGE),
+            _ => KeyAction::None,
+        };
+    }
     match key.code {
+        KeyCode::PageDown => KeyAction::ScrollComments(COMMENTS_PAGE),
+        KeyCode::PageUp => KeyAction::ScrollComments(-COMMENTS_PAGE),
+        KeyCode::Char(']') => KeyAction::SelectComment(1),
+        KeyCode::Char('[') => KeyAction::SelectComment(-1),
+        KeyCode::Char('r') => KeyAction::ReplyToComment,
         KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left | KeyCode::BackTab => {
             KeyAction::CloseDetail
         }
@@ -303,9 +1073,20 @@ fn handle_detail(_app: &mut App, key: KeyEvent) -> KeyAction {
         KeyCode::Char('?') => KeyAction::ToggleHelp,
         KeyCode::Char('x') => KeyAction::CompleteTask,
         KeyCode::Char('c') => KeyAction::StartCommentInput,
+        KeyCode::Char('O') => KeyAction::OpenInBrowser,
+        KeyCode::Char('E') => KeyAction::OpenRecurrence,
+        KeyCode::Char('p') => KeyAction::OpenParentTask,
+        KeyCode::Char('P') => KeyAction::OpenPriorityPicker,
+        KeyCode::Char('H') => KeyAction::ToggleHistory,
+        KeyCode::Char(c @ '1'..='9') => {
+            app.open_detail_link(c as usize - '1' as usize);
+            KeyAction::Consumed
+        }
         KeyCode::Char('i') | KeyCode::Enter => KeyAction::StartFieldEdit,
         KeyCode::Char('j') | KeyCode::Down => KeyAction::DetailFieldDown,
         KeyCode::Char('k') | KeyCode::Up => KeyAction::DetailFieldUp,
+        KeyCode::Char('J') => KeyAction::DetailTask(1),
+        KeyCode::Char('K') => KeyAction::DetailTask(-1),
         _ => KeyAction::None,
     }
 }
@@ -335,6 +1116,46 @@ fn handle_settings(app: &mut App, key: KeyEvent) -> KeyAction {
                     app.cycle_idle_timeout();
                     return KeyAction::Consumed;
                 }
+                3 => {
+                    app.toggle_preview();
+                    return KeyAction::Consumed;
+                }
+                4 => {
+                    app.cycle_title_overflow();
+                    return KeyAction::Consumed;
+                }
+                5 => {
+                    app.cycle_focus_minutes();
+                    return KeyAction::Consumed;
+                }
+                6 => {
+                    app.toggle_focus_notify();
+                    return KeyAction::Consumed;
+                }
+                7 => {
+                    app.toggle_plain_mode();
+                    return KeyAction::Consumed;
+                }
+                8 => {
+                    app.cycle_priority_marker();
+                    return KeyAction::Consumed;
+                }
+                9 => {
+                    app.toggle_clock();
+                    return KeyAction::Consumed;
+                }
+                10 => {
+                    app.cycle_key_hints();
+                    return KeyAction::Consumed;
+                }
+                11 => {
+                    app.toggle_check_updates();
+                    return KeyAction::Consumed;
+                }
+                12 => {
+                    app.cycle_poll_interval();
+                    return KeyAction::Consumed;
+                }
                 _ => {}
             }
             KeyAction::Consumed
@@ -344,19 +1165,64 @@ fn handle_settings(app: &mut App, key: KeyEvent) -> KeyAction {
     }
 }
 
-fn settings_item_count() -> usize {
-    3
+pub fn settings_item_count() -> usize {
+    13
 }
 
-fn handle_vim(app: &mut App, key: KeyEvent, state: VimState) -> KeyAction {
+fn handle_vim(app: &mut App, key: KeyEvent, state: VimState, after_g: bool) -> KeyAction {
     match state {
-        VimState::Normal => handle_vim_normal(app, key),
+        VimState::Normal => handle_vim_normal(app, key, after_g),
         VimState::Visual => handle_vim_visual(app, key),
         VimState::Insert => handle_vim_insert(app, key),
     }
 }
 
-fn handle_vim_normal(app: &mut App, key: KeyEvent) -> KeyAction {
+fn handle_vim_normal(app: &mut App, key: KeyEvent, after_g: bool) -> KeyAction {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        match key.code {
+            KeyCode::Char('h') => return KeyAction::ShrinkSidebar,
+            KeyCode::Char('l') => return KeyAction::GrowSidebar,
+            _ => {}
+        }
+    }
+
+    // `g` only names a chord: `gg` top, `gt` / `gT` tabs, `gi` Inbox and
+    // `g1`–`g9` favorites. Any other second key just ends it.
+    if after_g {
+        return match key.code {
+            KeyCode::Char('g') => jump_to_edge(app, true),
+            KeyCode::Char('t') => KeyAction::SwitchTab(1),
+            KeyCode::Char('T') => KeyAction::SwitchTab(-1),
+            KeyCode::Char('i') => match app.projects.iter().position(|p| p.is_inbox()) {
+                Some(i) => jump_to_project(app, i),
+                None => KeyAction::Consumed,
+            },
+            KeyCode::Char(c @ '1'..='9') => {
+                let nth = c as usize - '1' as usize;
+                match app
+                    .projects
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| p.is_favorite)
+                    .map(|(i, _)| i)
+                    .nth(nth)
+                {
+                    Some(i) => jump_to_project(app, i),
+                    None => KeyAction::Consumed,
+                }
+            }
+            _ => KeyAction::Consumed,
+        };
+    }
+
+    if take_pending_y() {
+        return yank_chord(key.code);
+    }
+
+    if take_pending_bang() {
+        return priority_chord(app, key.code);
+    }
+
     if take_pending_z() {
         return match key.code {
             KeyCode::Char('a') if matches!(app.active_pane, Pane::Tasks) => {
@@ -368,11 +1234,43 @@ fn handle_vim_normal(app: &mut App, key: KeyEvent) -> KeyAction {
         };
     }
 
+    if app.zen_mode && is_pane_switch(app, key.code) {
+        return KeyAction::Consumed;
+    }
+
+    // A leading `0` isn't a count, so only `1`–`9` can start one.
+    if let KeyCode::Char(c @ '0'..='9') = key.code
+        && (c != '0' || PENDING_COUNT.lock().unwrap().is_some())
+    {
+        push_count_digit(c as usize - '0' as usize);
+        return KeyAction::Consumed;
+    }
+
+    let count = take_pending_count();
+
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => return move_in_pane_by(app, 1, count.unwrap_or(1)),
+        KeyCode::Char('k') | KeyCode::Up => return move_in_pane_by(app, -1, count.unwrap_or(1)),
+        KeyCode::Char('g') | KeyCode::Char('G') if count.is_some() => {
+            return jump_to_line(app, count.unwrap_or(1));
+        }
+        // Digits are counts here, so `1p`–`4p` stand in for standard mode's `1`–`4`.
+        KeyCode::Char('p') if matches!(app.active_pane, Pane::Tasks) => {
+            return match count {
+                Some(n @ 1..=4) => KeyAction::SetPriority(5 - n as u8),
+                Some(_) => KeyAction::Consumed,
+                None => KeyAction::OpenPriorityPicker,
+            };
+        }
+        _ => {}
+    }
+
     match key.code {
         KeyCode::Char('q') => KeyAction::Quit,
         KeyCode::Char('?') => KeyAction::ToggleHelp,
         KeyCode::Char(',') => KeyAction::ToggleSettings,
         KeyCode::Char('R') => KeyAction::ForceResync,
+        KeyCode::Char('Z') => KeyAction::ToggleZen,
 
         KeyCode::Char('z') => {
             set_pending_z();
@@ -383,12 +1281,86 @@ fn handle_vim_normal(app: &mut App, key: KeyEvent) -> KeyAction {
         KeyCode::Char('a') if matches!(app.active_pane, Pane::Tasks) => KeyAction::StartInput,
         KeyCode::Char('f') if matches!(app.active_pane, Pane::Tasks) => KeyAction::CycleFilter,
         KeyCode::Char('o') if matches!(app.active_pane, Pane::Tasks) => KeyAction::CycleSort,
+        KeyCode::Char('r') if matches!(app.active_pane, Pane::Tasks) => KeyAction::ReverseSort,
         KeyCode::Char('s') if matches!(app.active_pane, Pane::Projects) => KeyAction::StarProject,
+        KeyCode::Char('i') if matches!(app.active_pane, Pane::Projects) => {
+            KeyAction::ShowProjectInfo
+        }
+        KeyCode::Char('w') if matches!(app.active_pane, Pane::Projects) => {
+            KeyAction::OpenWorkspaceOverview
+        }
+        KeyCode::Char('H') => open_recent_panel(app),
+        KeyCode::Char('@') => open_label_manager(app),
+        KeyCode::Char('A') => KeyAction::StartQuickAdd,
+        KeyCode::Char('*') if matches!(app.active_pane, Pane::Tasks) => KeyAction::TogglePin,
+        KeyCode::Char('/') if matches!(app.active_pane, Pane::Projects) => {
+            app.project_filter = Some(String::new());
+            KeyAction::Consumed
+        }
+        KeyCode::Char('/') if matches!(app.active_pane, Pane::Tasks) => start_task_search(app),
+        KeyCode::Char('y') if matches!(app.active_pane, Pane::Tasks) => {
+            set_pending_y();
+            KeyAction::Consumed
+        }
+        KeyCode::Char('!') if matches!(app.active_pane, Pane::Tasks) => {
+            set_pending_bang();
+            KeyAction::Consumed
+        }
+        KeyCode::Char('O') if matches!(app.active_pane, Pane::Tasks) => KeyAction::OpenInBrowser,
+        KeyCode::Char('S') if matches!(app.active_pane, Pane::Tasks) => KeyAction::SplitTask,
+        KeyCode::Char('<') if matches!(app.active_pane, Pane::Tasks) => KeyAction::PromoteTask,
+        KeyCode::Char('%') if matches!(app.active_pane, Pane::Tasks) => KeyAction::StartFindReplace,
+        KeyCode::Char('E') if matches!(app.active_pane, Pane::Tasks) => KeyAction::OpenRecurrence,
+        KeyCode::Char('F') if matches!(app.active_pane, Pane::Tasks) => KeyAction::StartFocus,
+        KeyCode::Char('t') if matches!(app.active_pane, Pane::Tasks) => {
+            KeyAction::Postpone(Postpone::Today)
+        }
+        KeyCode::Char('+') if matches!(app.active_pane, Pane::Tasks) => {
+            KeyAction::Postpone(Postpone::Tomorrow)
+        }
+        KeyCode::Char('w') if matches!(app.active_pane, Pane::Tasks) => {
+            KeyAction::Postpone(Postpone::NextWeek)
+        }
+        KeyCode::Char('W') if matches!(app.active_pane, Pane::Tasks) => {
+            KeyAction::Postpone(Postpone::Weekend)
+        }
+        KeyCode::Char('T')
+            if matches!(app.active_pane, Pane::Tasks)
+                && app.dock_filter == Some(DockItem::DueOverdue) =>
+        {
+            KeyAction::RescheduleOverdue
+        }
+        KeyCode::Char('J') if matches!(app.active_pane, Pane::Tasks) && app.today_view_active => {
+            KeyAction::MoveDayOrder(1)
+        }
+        KeyCode::Char('K') if matches!(app.active_pane, Pane::Tasks) && app.today_view_active => {
+            KeyAction::MoveDayOrder(-1)
+        }
+        KeyCode::Char('J') if matches!(app.active_pane, Pane::Tasks) => KeyAction::MoveSection(1),
+        KeyCode::Char('K') if matches!(app.active_pane, Pane::Tasks) => KeyAction::MoveSection(-1),
+        KeyCode::Char('J') if matches!(app.active_pane, Pane::Projects) => {
+            KeyAction::MoveSidebarItem(1)
+        }
+        KeyCode::Char('K') if matches!(app.active_pane, Pane::Projects) => {
+            KeyAction::MoveSidebarItem(-1)
+        }
+        KeyCode::Char('n')
+            if matches!(app.active_pane, Pane::Tasks) && app.task_search.is_some() =>
+        {
+            app.jump_to_search_match(true);
+            KeyAction::Consumed
+        }
+        KeyCode::Char('N')
+            if matches!(app.active_pane, Pane::Tasks) && app.task_search.is_some() =>
+        {
+            app.jump_to_search_match(false);
+            KeyAction::Consumed
+        }
 
-        KeyCode::Char('j') | KeyCode::Down => move_in_pane(app, 1),
-        KeyCode::Char('k') | KeyCode::Up => move_in_pane(app, -1),
-
-        KeyCode::Char('g') => jump_to_edge(app, true),
+        KeyCode::Char('g') => {
+            set_pending_g();
+            KeyAction::Consumed
+        }
         KeyCode::Char('G') => jump_to_edge(app, false),
 
         KeyCode::Char('l') | KeyCode::Right | KeyCode::Tab => {
@@ -406,7 +1378,7 @@ fn handle_vim_normal(app: &mut App, key: KeyEvent) -> KeyAction {
             match app.active_pane {
                 Pane::Tasks => app.active_pane = Pane::Projects,
                 Pane::Projects => {
-                    app.dock_focus = Some(DOCK_ITEMS.len() - 1);
+                    app.dock_focus = Some(app.dock_items().len() - 1);
                     app.active_pane = Pane::StatsDock;
                 }
                 _ => {}
@@ -433,7 +1405,9 @@ fn handle_vim_normal(app: &mut App, key: KeyEvent) -> KeyAction {
 
         KeyCode::Esc => {
             if matches!(app.active_pane, Pane::Tasks) {
-                if app.dock_filter.is_some() {
+                if app.task_search.is_some() {
+                    app.task_search = None;
+                } else if app.dock_filter.is_some() {
                     app.dock_filter = None;
                     let visible_len = app.visible_tasks().len();
                     app.selected_task = app.selected_task.min(visible_len.saturating_sub(1));
@@ -450,6 +1424,50 @@ fn handle_vim_normal(app: &mut App, key: KeyEvent) -> KeyAction {
     }
 }
 
+fn handle_triage(app: &mut App, key: KeyEvent) -> KeyAction {
+    let action = match key.code {
+        KeyCode::Char('t') => TriageAction::Postpone(Postpone::Today),
+        KeyCode::Char('+') => TriageAction::Postpone(Postpone::Tomorrow),
+        KeyCode::Char('w') => TriageAction::Postpone(Postpone::NextWeek),
+        KeyCode::Char('W') => TriageAction::Postpone(Postpone::Weekend),
+        KeyCode::Char('x') => TriageAction::Complete,
+        KeyCode::Char('d') => TriageAction::Delete,
+        KeyCode::Char('s') | KeyCode::Char('j') | KeyCode::Down => TriageAction::Skip,
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.triage = None;
+            return KeyAction::Consumed;
+        }
+        _ => {
+            if let Some(triage) = app.triage.as_mut() {
+                triage.confirm_delete = false;
+            }
+            return KeyAction::Consumed;
+        }
+    };
+    KeyAction::Triage(action)
+}
+
+fn handle_recurrence_editor(app: &mut App, key: KeyEvent) -> KeyAction {
+    let Some(editor) = &mut app.recurrence_editor else {
+        return KeyAction::None;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.recurrence_editor = None,
+        KeyCode::Enter => return KeyAction::ApplyRecurrence,
+        KeyCode::Char('j') | KeyCode::Down | KeyCode::Tab => {
+            editor.row = (editor.row + 1) % editor.row_count();
+        }
+        KeyCode::Char('k') | KeyCode::Up | KeyCode::BackTab => {
+            editor.row = editor.row.checked_sub(1).unwrap_or(editor.row_count() - 1);
+        }
+        KeyCode::Char('h') | KeyCode::Left | KeyCode::Char('-') => editor.adjust(-1),
+        KeyCode::Char('l') | KeyCode::Right | KeyCode::Char('+') => editor.adjust(1),
+        KeyCode::Char(' ') => editor.toggle_day(),
+        _ => {}
+    }
+    KeyAction::Consumed
+}
+
 fn handle_vim_visual(_app: &mut App, key: KeyEvent) -> KeyAction {
     match key.code {
         KeyCode::Esc => KeyAction::Consumed,
@@ -466,20 +1484,111 @@ fn handle_vim_insert(_app: &mut App, key: KeyEvent) -> KeyAction {
 }
 
 fn handle_standard(app: &mut App, key: KeyEvent) -> KeyAction {
+    if take_pending_y() {
+        return yank_chord(key.code);
+    }
+
+    if take_pending_bang() {
+        return priority_chord(app, key.code);
+    }
+
     if key.modifiers.contains(KeyModifiers::CONTROL) {
         return match key.code {
             KeyCode::Char('a') if matches!(app.active_pane, Pane::Tasks) => KeyAction::StartInput,
             KeyCode::Char('x') if matches!(app.active_pane, Pane::Tasks) => KeyAction::CompleteTask,
+            KeyCode::Char('h') => KeyAction::ShrinkSidebar,
+            KeyCode::Char('l') => KeyAction::GrowSidebar,
             _ => KeyAction::None,
         };
     }
 
+    if app.zen_mode && is_pane_switch(app, key.code) {
+        return KeyAction::Consumed;
+    }
+
     match key.code {
         KeyCode::Char('q') => KeyAction::Quit,
         KeyCode::Char('?') => KeyAction::ToggleHelp,
         KeyCode::Char(',') => KeyAction::ToggleSettings,
         KeyCode::Char('R') => KeyAction::ForceResync,
+        KeyCode::Char('Z') => KeyAction::ToggleZen,
         KeyCode::Char('f') if matches!(app.active_pane, Pane::Tasks) => KeyAction::CycleFilter,
+        KeyCode::Char('i') if matches!(app.active_pane, Pane::Projects) => {
+            KeyAction::ShowProjectInfo
+        }
+        KeyCode::Char('w') if matches!(app.active_pane, Pane::Projects) => {
+            KeyAction::OpenWorkspaceOverview
+        }
+        KeyCode::Char('H') => open_recent_panel(app),
+        KeyCode::Char('@') => open_label_manager(app),
+        KeyCode::Char('A') => KeyAction::StartQuickAdd,
+        KeyCode::Char('*') if matches!(app.active_pane, Pane::Tasks) => KeyAction::TogglePin,
+        KeyCode::Char('/') if matches!(app.active_pane, Pane::Projects) => {
+            app.project_filter = Some(String::new());
+            KeyAction::Consumed
+        }
+        KeyCode::Char('/') if matches!(app.active_pane, Pane::Tasks) => start_task_search(app),
+        KeyCode::Char('y') if matches!(app.active_pane, Pane::Tasks) => {
+            set_pending_y();
+            KeyAction::Consumed
+        }
+        KeyCode::Char('!') if matches!(app.active_pane, Pane::Tasks) => {
+            set_pending_bang();
+            KeyAction::Consumed
+        }
+        KeyCode::Char(c @ '1'..='4') if matches!(app.active_pane, Pane::Tasks) => {
+            KeyAction::SetPriority(5 - (c as u8 - b'0'))
+        }
+        KeyCode::Char('O') if matches!(app.active_pane, Pane::Tasks) => KeyAction::OpenInBrowser,
+        KeyCode::Char('S') if matches!(app.active_pane, Pane::Tasks) => KeyAction::SplitTask,
+        KeyCode::Char('<') if matches!(app.active_pane, Pane::Tasks) => KeyAction::PromoteTask,
+        KeyCode::Char('%') if matches!(app.active_pane, Pane::Tasks) => KeyAction::StartFindReplace,
+        KeyCode::Char('E') if matches!(app.active_pane, Pane::Tasks) => KeyAction::OpenRecurrence,
+        KeyCode::Char('F') if matches!(app.active_pane, Pane::Tasks) => KeyAction::StartFocus,
+        KeyCode::Char('t') if matches!(app.active_pane, Pane::Tasks) => {
+            KeyAction::Postpone(Postpone::Today)
+        }
+        KeyCode::Char('+') if matches!(app.active_pane, Pane::Tasks) => {
+            KeyAction::Postpone(Postpone::Tomorrow)
+        }
+        KeyCode::Char('w') if matches!(app.active_pane, Pane::Tasks) => {
+            KeyAction::Postpone(Postpone::NextWeek)
+        }
+        KeyCode::Char('W') if matches!(app.active_pane, Pane::Tasks) => {
+            KeyAction::Postpone(Postpone::Weekend)
+        }
+        KeyCode::Char('T')
+            if matches!(app.active_pane, Pane::Tasks)
+                && app.dock_filter == Some(DockItem::DueOverdue) =>
+        {
+            KeyAction::RescheduleOverdue
+        }
+        KeyCode::Char('J') if matches!(app.active_pane, Pane::Tasks) && app.today_view_active => {
+            KeyAction::MoveDayOrder(1)
+        }
+        KeyCode::Char('K') if matches!(app.active_pane, Pane::Tasks) && app.today_view_active => {
+            KeyAction::MoveDayOrder(-1)
+        }
+        KeyCode::Char('J') if matches!(app.active_pane, Pane::Tasks) => KeyAction::MoveSection(1),
+        KeyCode::Char('K') if matches!(app.active_pane, Pane::Tasks) => KeyAction::MoveSection(-1),
+        KeyCode::Char('J') if matches!(app.active_pane, Pane::Projects) => {
+            KeyAction::MoveSidebarItem(1)
+        }
+        KeyCode::Char('K') if matches!(app.active_pane, Pane::Projects) => {
+            KeyAction::MoveSidebarItem(-1)
+        }
+        KeyCode::Char('n')
+            if matches!(app.active_pane, Pane::Tasks) && app.task_search.is_some() =>
+        {
+            app.jump_to_search_match(true);
+            KeyAction::Consumed
+        }
+        KeyCode::Char('N')
+            if matches!(app.active_pane, Pane::Tasks) && app.task_search.is_some() =>
+        {
+            app.jump_to_search_match(false);
+            KeyAction::Consumed
+        }
 
         KeyCode::Down => move_in_pane(app, 1),
         KeyCode::Up => move_in_pane(app, -1),
@@ -502,7 +1611,7 @@ fn handle_standard(app: &mut App, key: KeyEvent) -> KeyAction {
             match app.active_pane {
                 Pane::Tasks => app.active_pane = Pane::Projects,
                 Pane::Projects => {
-                    app.dock_focus = Some(DOCK_ITEMS.len() - 1);
+                    app.dock_focus = Some(app.dock_items().len() - 1);
                     app.active_pane = Pane::StatsDock;
                 }
                 _ => {}
@@ -521,7 +1630,9 @@ fn handle_standard(app: &mut App, key: KeyEvent) -> KeyAction {
 
         KeyCode::Esc => {
             if matches!(app.active_pane, Pane::Tasks) {
-                if app.dock_filter.is_some() {
+                if app.task_search.is_some() {
+                    app.task_search = None;
+                } else if app.dock_filter.is_some() {
                     app.dock_filter = None;
                     let visible_len = app.visible_tasks().len();
                     app.selected_task = app.selected_task.min(visible_len.saturating_sub(1));
@@ -538,6 +1649,17 @@ fn handle_standard(app: &mut App, key: KeyEvent) -> KeyAction {
     }
 }
 
+/// Keys that would move focus to the Projects pane or the StatsDock, both of
+/// which are hidden in zen mode.
+fn is_pane_switch(app: &App, code: KeyCode) -> bool {
+    match code {
+        KeyCode::Char('h') | KeyCode::Char('l') => matches!(app.input_mode, InputMode::Vim(_)),
+        KeyCode::Left | KeyCode::Right | KeyCode::Tab | KeyCode::BackTab => true,
+        KeyCode::Esc => app.dock_filter.is_none() && app.task_search.is_none(),
+        _ => false,
+    }
+}
+
 fn move_in_pane(app: &mut App, delta: i32) -> KeyAction {
     match app.active_pane {
         Pane::Projects => {
@@ -548,8 +1670,12 @@ fn move_in_pane(app: &mut App, delta: i32) -> KeyAction {
             let pos = nav
                 .iter()
                 .position(|item| match item {
+                    ProjectNavItem::Favorite(view) => {
+                        app.folder_cursor.is_none() && app.favorite_view.as_ref() == Some(view)
+                    }
                     ProjectNavItem::Project(i) => {
                         !app.today_view_active
+                            && app.favorite_view.is_none()
                             && app.folder_cursor.is_none()
                             && *i == app.selected_project
                     }
@@ -568,7 +1694,11 @@ fn move_in_pane(app: &mut App, delta: i32) -> KeyAction {
             if next_pos < 0 {
                 return KeyAction::Consumed;
             }
-            match nav[next_pos as usize] {
+            match nav[next_pos as usize].clone() {
+                ProjectNavItem::Favorite(view) => {
+                    app.folder_cursor = None;
+                    KeyAction::FavoriteSelected(view)
+                }
                 ProjectNavItem::Project(i) => {
                     app.folder_cursor = None;
                     app.selected_project = i;
@@ -606,16 +1736,97 @@ fn move_in_pane(app: &mut App, delta: i32) -> KeyAction {
     }
 }
 
+/// Repeats a single-step move `count` times. Counted moves in the task list
+/// stop at the ends instead of wrapping; the last selection-changing action
+/// wins so the caller reloads whatever the cursor finally landed on.
+fn move_in_pane_by(app: &mut App, delta: i32, count: usize) -> KeyAction {
+    if count <= 1 {
+        return move_in_pane(app, delta);
+    }
+    let mut result = KeyAction::Consumed;
+    for _ in 0..count {
+        if matches!(app.active_pane, Pane::Tasks) {
+            let len = app.visible_tasks().len();
+            let at_edge = if delta > 0 {
+                app.selected_task + 1 >= len
+            } else {
+                app.selected_task == 0
+            };
+            if at_edge {
+                break;
+            }
+        } else if !matches!(app.active_pane, Pane::Projects) {
+            break;
+        }
+        match move_in_pane(app, delta) {
+            KeyAction::Consumed => {}
+            action => result = action,
+        }
+    }
+    result
+}
+
+/// `{count}G` / `{count}g`: select the `line`-th (1-based) row of the pane,
+/// clamped to the last one.
+fn jump_to_line(app: &mut App, line: usize) -> KeyAction {
+    let idx = line.saturating_sub(1);
+    match app.active_pane {
+        Pane::Projects => {
+            let nav = app.visible_nav_items();
+            let Some(item) = nav.get(idx.min(nav.len().saturating_sub(1))) else {
+                return KeyAction::Consumed;
+            };
+            match item.clone() {
+                ProjectNavItem::Favorite(view) => {
+                    app.folder_cursor = None;
+                    KeyAction::FavoriteSelected(view)
+                }
+                ProjectNavItem::Project(i) => {
+                    app.folder_cursor = None;
+                    app.selected_project = i;
+                    KeyAction::ProjectChanged
+                }
+                ProjectNavItem::Folder(fi) => {
+                    app.folder_cursor = Some(fi);
+                    KeyAction::Consumed
+                }
+                ProjectNavItem::TodayView => {
+                    app.folder_cursor = None;
+                    KeyAction::TodayViewSelected
+                }
+            }
+        }
+        Pane::Tasks => {
+            let visible_len = app.visible_tasks().len();
+            app.selected_task = idx.min(visible_len.saturating_sub(1));
+            KeyAction::Consumed
+        }
+        _ => KeyAction::Consumed,
+    }
+}
+
+/// Selects project `i` directly, dropping any dock filter so its tasks show.
+fn jump_to_project(app: &mut App, i: usize) -> KeyAction {
+    app.folder_cursor = None;
+    app.dock_filter = None;
+    app.selected_project = i;
+    KeyAction::ProjectChanged
+}
+
 fn jump_to_edge(app: &mut App, top: bool) -> KeyAction {
     match app.active_pane {
         Pane::Projects => {
             let nav = app.visible_nav_items();
             let item = if top { nav.first() } else { nav.last() };
             match item {
+                Some(ProjectNavItem::Favorite(view)) => {
+                    app.folder_cursor = None;
+                    return KeyAction::FavoriteSelected(view.clone());
+                }
                 Some(ProjectNavItem::Project(i)) => {
                     let i = *i;
                     app.folder_cursor = None;
-                    if app.selected_project != i {
+                    if app.selected_project != i || app.favorite_view.is_some() {
                         app.selected_project = i;
                         return KeyAction::ProjectChanged;
                     }
@@ -1,24 +1,38 @@
 mod app;
+mod browser;
+mod clipboard;
+mod filter_query;
+mod hooks;
+mod keymap;
 mod keys;
+mod notify;
+mod recurrence;
+mod shell;
+mod split;
+mod tour;
 mod ui;
+mod update;
 
-use std::io::Write as _;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use crossterm::event::{self, Event, KeyCode, KeyModifiers};
 
 use ratatoist_core::api::client::TodoistClient;
 use ratatoist_core::config::Config;
 use ratatoist_core::logging;
+use ratatoist_core::settings::Settings;
 
 use app::App;
+use shell::{AliasOutcome, Shell};
 
 #[derive(Parser)]
 #[command(name = "ratatoist", version, about = "A terminal UI for Todoist")]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
     #[arg(long)]
     debug: bool,
     #[arg(long)]
@@ -28,20 +42,59 @@ struct Cli {
         help = "Simulate new-user onboarding without touching your config"
     )]
     new_user: bool,
+    #[arg(
+        long,
+        help = "Run against generated sample data; no token needed, nothing is saved"
+    )]
+    demo: bool,
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Read config, state and themes from DIR (or set RATATOIST_CONFIG_DIR)"
+    )]
+    config_dir: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Write logs to DIR instead of $XDG_STATE_HOME/ratatoist/logs"
+    )]
+    log_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Replace this binary with the latest GitHub release (release-tarball installs only)
+    SelfUpdate,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    if let Some(dir) = cli.config_dir {
+        Config::set_config_dir(dir);
+    }
 
-    let _log_guard = logging::init(cli.debug)?;
+    let log_dir = cli.log_dir.unwrap_or_else(logging::default_log_dir);
+    let _log_guard = logging::init(cli.debug, Some(log_dir.clone()))?;
+
+    if let Some(Command::SelfUpdate) = cli.command {
+        return update::self_update().await;
+    }
 
     let mut terminal = ratatui::init();
+    install_panic_hook(log_dir.clone());
 
-    let (client, ephemeral) = if cli.new_user {
+    let mut settings = Settings::load(&Config::config_dir());
+
+    let mut onboarded = false;
+    let (client, ephemeral) = if cli.demo {
+        (TodoistClient::demo(), true)
+    } else if cli.new_user {
         match run_new_user_setup(&mut terminal).await {
             Ok(token) => {
+                onboarded = true;
                 run_alias_setup(&mut terminal).await;
+                run_preferences_setup(&mut terminal, &mut settings);
                 match TodoistClient::new(&token) {
                     Ok(c) => (c, true),
                     Err(e) => {
@@ -68,12 +121,17 @@ async fn main() -> Result<()> {
             },
             Err(_) => match run_new_user_setup(&mut terminal).await {
                 Ok(token) => {
+                    onboarded = true;
                     if let Err(e) = Config::save_token(&token) {
                         ratatui::restore();
                         eprintln!("Failed to save config: {e:#}");
                         std::process::exit(1);
                     }
                     run_alias_setup(&mut terminal).await;
+                    run_preferences_setup(&mut terminal, &mut settings);
+                    if let Err(e) = settings.save(&Config::config_dir()) {
+                        tracing::warn!(error = %e, "failed to save onboarding choices");
+                    }
                     match TodoistClient::new(&token) {
                         Ok(c) => (c, false),
                         Err(e) => {
@@ -92,9 +150,13 @@ async fn main() -> Result<()> {
         (client, ephemeral)
     };
 
-    let mut app = App::new(client, cli.idle_forcer, ephemeral);
+    let mut app = App::new(client, settings, cli.idle_forcer, ephemeral, cli.debug);
+    app.log_dir = log_dir;
 
     app.load_with_splash(&mut terminal).await;
+    if onboarded {
+        app.offer_tour();
+    }
 
     let result = app.run(&mut terminal).await;
     ratatui::restore();
@@ -102,17 +164,37 @@ async fn main() -> Result<()> {
     result
 }
 
+/// Replaces ratatui's hook so a panic leaves a usable terminal, a one-line
+/// pointer on stderr and a crash report next to the logs.
+fn install_panic_hook(log_dir: PathBuf) {
+    std::panic::set_hook(Box::new(move |info| {
+        ratatui::restore();
+        let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+        tracing::error!(panic = %info, "panicked");
+        match logging::write_crash_report(&log_dir, &info.to_string(), &backtrace) {
+            Ok(path) => eprintln!(
+                "ratatoist crashed: {info}\nA crash report was written to {}",
+                path.display()
+            ),
+            Err(e) => eprintln!("ratatoist crashed: {info}\n(failed to write crash report: {e:#})"),
+        }
+    }));
+}
+
 async fn run_alias_setup(terminal: &mut ratatui::DefaultTerminal) {
     let themes = ui::theme::Theme::builtin();
     let theme = &themes[0];
 
-    let Some(rc_path) = detect_shell_rc() else {
-        return;
+    let shell = Shell::detect();
+    let rc_path = shell.rc_path();
+    let rc_display = match &rc_path {
+        Some(path) => path
+            .to_str()
+            .unwrap_or("")
+            .replace(&std::env::var("HOME").unwrap_or_default(), "~"),
+        None => "shell not recognised: copy the line into your config".to_string(),
     };
-    let rc_display = rc_path
-        .to_str()
-        .unwrap_or("")
-        .replace(&std::env::var("HOME").unwrap_or_default(), "~");
+    let rat_line = shell.alias_line("rat");
 
     let mut selected: usize = 0;
     let mut custom_input = String::new();
@@ -127,6 +209,7 @@ async fn run_alias_setup(terminal: &mut ratatui::DefaultTerminal) {
                     selected,
                     &custom_input,
                     is_typing,
+                    &rat_line,
                     &rc_display,
                     status.as_deref(),
                     theme,
@@ -145,6 +228,13 @@ async fn run_alias_setup(terminal: &mut ratatui::DefaultTerminal) {
             break;
         }
 
+        let page = AliasPage {
+            shell,
+            rc_path: rc_path.as_deref(),
+            rc_display: &rc_display,
+            rat_line: &rat_line,
+        };
+
         if is_typing {
             match key.code {
                 KeyCode::Esc => {
@@ -162,7 +252,7 @@ async fn run_alias_setup(terminal: &mut ratatui::DefaultTerminal) {
                 }
                 KeyCode::Enter if !custom_input.trim().is_empty() => {
                     let name = custom_input.trim().to_string();
-                    commit_alias(&name, &rc_path, &rc_display, &mut status, terminal, theme).await;
+                    commit_alias(&name, &page, &mut status, terminal, theme);
                     break;
                 }
                 _ => {}
@@ -178,7 +268,7 @@ async fn run_alias_setup(terminal: &mut ratatui::DefaultTerminal) {
             }
             KeyCode::Enter => match selected {
                 0 => {
-                    commit_alias("rat", &rc_path, &rc_display, &mut status, terminal, theme).await;
+                    commit_alias("rat", &page, &mut status, terminal, theme);
                     break;
                 }
                 1 => is_typing = true,
@@ -189,52 +279,168 @@ async fn run_alias_setup(terminal: &mut ratatui::DefaultTerminal) {
     }
 }
 
-async fn commit_alias(
-    name: &str,
-    rc_path: &Path,
-    rc_display: &str,
-    status: &mut Option<String>,
+/// Wizard pages after the alias step: theme (previewed live), input mode and
+/// starting view. Esc keeps a page's current value; Ctrl-C skips the rest.
+fn run_preferences_setup(terminal: &mut ratatui::DefaultTerminal, settings: &mut Settings) {
+    let mut themes = ui::theme::Theme::builtin();
+    themes.extend(ui::theme::Theme::load_user_themes(
+        &Config::config_dir().join("themes"),
+    ));
+    let theme_options: Vec<(String, String)> = themes
+        .iter()
+        .map(|t| (t.name.clone(), String::new()))
+        .collect();
+    let current = settings
+        .theme
+        .as_deref()
+        .and_then(|name| themes.iter().position(|t| t.name == name))
+        .unwrap_or(0);
+    let Some(theme_idx) = choose(terminal, "theme  1/3", &theme_options, current, |i| {
+        &themes[i]
+    }) else {
+        return;
+    };
+    settings.theme = Some(themes[theme_idx].name.clone());
+    let theme = &themes[theme_idx];
+
+    let modes = [
+        ("vim", "modal editing, hjkl to move"),
+        ("standard", "arrow keys, no modes"),
+    ];
+    let Some(mode) = choose_label(
+        terminal,
+        "input mode  2/3",
+        &modes,
+        &settings.input_mode,
+        theme,
+    ) else {
+        return;
+    };
+    settings.input_mode = mode;
+
+    let views = [
+        ("last", "pick up where you left off"),
+        ("inbox", "open your Inbox"),
+        ("today", "overdue and due today, across projects"),
+    ];
+    if let Some(view) = choose_label(
+        terminal,
+        "starting view  3/3",
+        &views,
+        &settings.default_view,
+        theme,
+    ) {
+        settings.default_view = view;
+    }
+}
+
+/// A wizard page over fixed `(label, description)` options, returning the
+/// picked label.
+fn choose_label(
     terminal: &mut ratatui::DefaultTerminal,
+    title: &str,
+    options: &[(&str, &str)],
+    current: &str,
     theme: &ui::theme::Theme,
-) {
-    match write_alias(name, rc_path) {
-        Ok(()) => {
-            *status = Some(format!("added  alias {name}='ratatoist'  to {rc_display}"));
-            terminal
-                .draw(|f| {
-                    ui::setup::render_alias(f, 0, name, false, rc_display, status.as_deref(), theme)
-                })
-                .ok();
-            std::thread::sleep(Duration::from_millis(1200));
+) -> Option<String> {
+    let owned: Vec<(String, String)> = options
+        .iter()
+        .map(|(l, d)| (l.to_string(), d.to_string()))
+        .collect();
+    let initial = options.iter().position(|(l, _)| *l == current).unwrap_or(0);
+    choose(terminal, title, &owned, initial, |_| theme).map(|i| owned[i].0.clone())
+}
+
+/// Runs one wizard page. Returns the picked index (the initial one on Esc), or
+/// `None` if the user bailed out with Ctrl-C.
+fn choose<'t>(
+    terminal: &mut ratatui::DefaultTerminal,
+    title: &str,
+    options: &[(String, String)],
+    initial: usize,
+    theme_for: impl Fn(usize) -> &'t ui::theme::Theme,
+) -> Option<usize> {
+    let mut selected = initial;
+    loop {
+        terminal
+            .draw(|f| ui::setup::render_choice(f, title, options, selected, theme_for(selected)))
+            .ok();
+
+        let Ok(true) = event::poll(Duration::from_millis(16)) else {
+            continue;
+        };
+        let Ok(Event::Key(key)) = event::read() else {
+            continue;
+        };
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return None;
         }
-        Err(e) => {
-            *status = Some(format!("could not write: {e}"));
+        match key.code {
+            KeyCode::Esc => return Some(initial),
+            KeyCode::Enter => return Some(selected),
+            KeyCode::Char('j') | KeyCode::Down => selected = (selected + 1) % options.len(),
+            KeyCode::Char('k') | KeyCode::Up => {
+                selected = selected.checked_sub(1).unwrap_or(options.len() - 1);
+            }
+            _ => {}
         }
     }
 }
 
-fn detect_shell_rc() -> Option<PathBuf> {
-    let shell = std::env::var("SHELL").unwrap_or_default();
-    let home = std::env::var("HOME").ok()?;
-    let home = PathBuf::from(home);
-    if shell.contains("zsh") {
-        Some(home.join(".zshrc"))
-    } else if shell.contains("bash") {
-        let profile = home.join(".bash_profile");
-        if profile.exists() {
-            Some(profile)
-        } else {
-            Some(home.join(".bashrc"))
+struct AliasPage<'a> {
+    shell: Shell,
+    rc_path: Option<&'a Path>,
+    rc_display: &'a str,
+    rat_line: &'a str,
+}
+
+fn commit_alias(
+    name: &str,
+    page: &AliasPage,
+    status: &mut Option<String>,
+    terminal: &mut ratatui::DefaultTerminal,
+    theme: &ui::theme::Theme,
+) {
+    let line = page.shell.alias_line(name);
+    let Some(rc_path) = page.rc_path else {
+        // Unknown shell: nothing to write, so leave the line up until a key press.
+        *status = Some(format!("add to your shell config:  {line}"));
+        draw_alias_status(page, name, status, terminal, theme);
+        while !matches!(event::read(), Ok(Event::Key(_)) | Err(_)) {}
+        return;
+    };
+    *status = Some(match page.shell.write_alias(name, rc_path) {
+        Ok(AliasOutcome::Added) => format!("added  {line}  to {}", page.rc_display),
+        Ok(AliasOutcome::AlreadyPresent) => {
+            format!("already set  {name}  in {}", page.rc_display)
         }
-    } else {
-        None
-    }
+        Err(e) => format!("could not write: {e:#}"),
+    });
+    draw_alias_status(page, name, status, terminal, theme);
+    std::thread::sleep(Duration::from_millis(1200));
 }
 
-fn write_alias(name: &str, rc_path: &Path) -> Result<()> {
-    let mut file = std::fs::OpenOptions::new().append(true).open(rc_path)?;
-    writeln!(file, "\nalias {name}='ratatoist'")?;
-    Ok(())
+fn draw_alias_status(
+    page: &AliasPage,
+    name: &str,
+    status: &Option<String>,
+    terminal: &mut ratatui::DefaultTerminal,
+    theme: &ui::theme::Theme,
+) {
+    terminal
+        .draw(|f| {
+            ui::setup::render_alias(
+                f,
+                0,
+                name,
+                false,
+                page.rat_line,
+                page.rc_display,
+                status.as_deref(),
+                theme,
+            )
+        })
+        .ok();
 }
 
 async fn run_new_user_setup(terminal: &mut ratatui::DefaultTerminal) -> Result<String> {
@@ -261,6 +467,17 @@ async fn run_new_user_setup(terminal: &mut ratatui::DefaultTerminal) -> Result<S
             anyhow::bail!("cancelled");
         }
 
+        if clipboard::is_paste_key(&key) {
+            match clipboard::paste() {
+                Ok(text) => {
+                    input.push_str(&text);
+                    error = None;
+                }
+                Err(e) => error = Some(format!("{e:#}")),
+            }
+            continue;
+        }
+
         match key.code {
             KeyCode::Esc => anyhow::bail!("cancelled"),
 
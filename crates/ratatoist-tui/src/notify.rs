@@ -0,0 +1,33 @@
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result, bail};
+
+/// Shows a desktop notification without blocking the UI.
+pub fn send(title: &str, body: &str) -> Result<()> {
+    let mut cmd = if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification {} with title {}",
+            applescript_string(body),
+            applescript_string(title)
+        );
+        let mut c = Command::new("osascript");
+        c.args(["-e", &script]);
+        c
+    } else if cfg!(target_os = "windows") {
+        bail!("desktop notifications are not supported on Windows");
+    } else {
+        let mut c = Command::new("notify-send");
+        c.args(["--app-name=ratatoist", title, body]);
+        c
+    };
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .context("could not launch the desktop notifier")?;
+    Ok(())
+}
+
+fn applescript_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,296 @@
+use chrono::{Datelike, Days, Months, NaiveDate};
+
+const WEEKDAYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+const DAY_NAMES: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekdays,
+    Weekly,
+    Monthly,
+}
+
+const FREQUENCIES: [Frequency; 4] = [
+    Frequency::Daily,
+    Frequency::Weekdays,
+    Frequency::Weekly,
+    Frequency::Monthly,
+];
+
+impl Frequency {
+    pub fn label(self) -> &'static str {
+        match self {
+            Frequency::Daily => "daily",
+            Frequency::Weekdays => "weekdays",
+            Frequency::Weekly => "weekly",
+            Frequency::Monthly => "monthly",
+        }
+    }
+
+    /// What `interval` counts, for the "every N …" row.
+    pub fn unit(self) -> &'static str {
+        match self {
+            Frequency::Daily | Frequency::Weekdays => "days",
+            Frequency::Weekly => "weeks",
+            Frequency::Monthly => "months",
+        }
+    }
+
+    pub fn cycle(self, step: isize) -> Self {
+        let at = FREQUENCIES.iter().position(|f| *f == self).unwrap_or(0);
+        let len = FREQUENCIES.len() as isize;
+        FREQUENCIES[(at as isize + step).rem_euclid(len) as usize]
+    }
+}
+
+/// A repeating schedule as the recurrence builder edits it, turned into
+/// Todoist's natural-language `due_string` only when applied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    /// Every this many days, weeks or months; unused for weekdays.
+    pub interval: u32,
+    /// Days a weekly rule falls on, Monday first.
+    pub weekdays: [bool; 7],
+    /// Day of the month a monthly rule falls on.
+    pub month_day: u32,
+}
+
+impl Recurrence {
+    /// Weekly on `today`'s weekday, with the monthly day preset to `today`'s.
+    pub fn starting(today: NaiveDate) -> Self {
+        let mut weekdays = [false; 7];
+        weekdays[today.weekday().num_days_from_monday() as usize] = true;
+        Self {
+            frequency: Frequency::Weekly,
+            interval: 1,
+            weekdays,
+            month_day: today.day(),
+        }
+    }
+
+    pub fn due_string(&self) -> String {
+        let n = self.interval.max(1);
+        match self.frequency {
+            Frequency::Daily if n == 1 => "every day".to_string(),
+            Frequency::Daily => format!("every {n} days"),
+            Frequency::Weekdays => "every weekday".to_string(),
+            Frequency::Weekly => {
+                let days: Vec<&str> = WEEKDAYS
+                    .iter()
+                    .zip(self.weekdays)
+                    .filter(|(_, on)| *on)
+                    .map(|(day, _)| *day)
+                    .collect();
+                match (n, days.is_empty()) {
+                    (1, true) => "every week".to_string(),
+                    (1, false) => format!("every {}", days.join(", ")),
+                    (_, true) => format!("every {n} weeks"),
+                    (_, false) => format!("every {n} weeks on {}", days.join(", ")),
+                }
+            }
+            Frequency::Monthly if n == 1 => format!("every {}", ordinal(self.month_day)),
+            Frequency::Monthly => format!("every {n} months on the {}", ordinal(self.month_day)),
+        }
+    }
+}
+
+/// The first date after `from` that a Todoist `every …` due string falls on,
+/// for skipping an occurrence without the server's help. Covers what the
+/// builder above writes plus the common spellings; `None` for anything it
+/// can't be sure of, such as `every!`, end dates or "last day".
+pub fn next_occurrence(rule: &str, from: NaiveDate) -> Option<NaiveDate> {
+    let rule = rule.trim().to_lowercase();
+    let rule = rule.strip_prefix("every ")?;
+    if [" until ", " ending ", " for "]
+        .iter()
+        .any(|word| rule.contains(word))
+    {
+        return None;
+    }
+    // The series start and time of day don't change which day comes next.
+    let rule = [" starting ", " from ", " at "]
+        .iter()
+        .filter_map(|word| rule.find(word))
+        .min()
+        .map_or(rule, |end| &rule[..end])
+        .trim();
+
+    let (n, rest) = match rule.split_once(' ') {
+        Some((count, rest)) if count.parse::<u32>().is_ok() => {
+            (count.parse::<u32>().ok()?.max(1), rest)
+        }
+        _ => (1, rule),
+    };
+    match rest {
+        "day" | "days" => from.checked_add_days(Days::new(n.into())),
+        "week" | "weeks" => from.checked_add_days(Days::new(7 * u64::from(n))),
+        "month" | "months" => from.checked_add_months(Months::new(n)),
+        "year" | "years" => from.checked_add_months(Months::new(12 * n)),
+        "weekday" | "workday" if n == 1 => {
+            next_on_days(from, &[true, true, true, true, true, false, false], 1)
+        }
+        _ => {
+            if let Some(days) = rest
+                .strip_prefix("weeks on ")
+                .or(rest.strip_prefix("week on "))
+            {
+                return next_on_days(from, &parse_weekdays(days)?, n);
+            }
+            if let Some(day) = rest
+                .strip_prefix("months on the ")
+                .or(rest.strip_prefix("month on the "))
+            {
+                return next_on_month_day(from, parse_ordinal(day)?, n);
+            }
+            if n != 1 {
+                return None;
+            }
+            if let Some(day) = parse_ordinal(rest) {
+                return next_on_month_day(from, day, 1);
+            }
+            next_on_days(from, &parse_weekdays(rest)?, 1)
+        }
+    }
+}
+
+/// `mon, wed and fri` as a Monday-first set.
+fn parse_weekdays(list: &str) -> Option<[bool; 7]> {
+    let mut days = [false; 7];
+    for name in list
+        .split([',', ' '])
+        .map(str::trim)
+        .filter(|w| !w.is_empty() && *w != "and")
+    {
+        let at = DAY_NAMES
+            .iter()
+            .position(|day| name.len() >= 3 && day.starts_with(name))?;
+        days[at] = true;
+    }
+    days.contains(&true).then_some(days)
+}
+
+fn parse_ordinal(word: &str) -> Option<u32> {
+    let digits = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    if digits.len() == word.len() {
+        return None;
+    }
+    digits.parse().ok().filter(|d| (1..=31).contains(d))
+}
+
+/// The next day in `days` after `from`, looking past `from`'s week only
+/// every `weeks` weeks.
+fn next_on_days(from: NaiveDate, days: &[bool; 7], weeks: u32) -> Option<NaiveDate> {
+    let on = |d: NaiveDate| days[d.weekday().num_days_from_monday() as usize];
+    let monday = from - Days::new(from.weekday().num_days_from_monday().into());
+    let sunday = monday + Days::new(6);
+    if let Some(d) = from
+        .iter_days()
+        .skip(1)
+        .take_while(|d| *d <= sunday)
+        .find(|d| on(*d))
+    {
+        return Some(d);
+    }
+    let next_week = monday.checked_add_days(Days::new(7 * u64::from(weeks)))?;
+    next_week.iter_days().take(7).find(|d| on(*d))
+}
+
+/// Day `day` of the month, every `months` months, skipping months too
+/// short to have it.
+fn next_on_month_day(from: NaiveDate, day: u32, months: u32) -> Option<NaiveDate> {
+    let first = from.with_day(1)?;
+    if day > from.day()
+        && let Some(d) = first.with_day(day)
+    {
+        return Some(d);
+    }
+    (1..=12)
+        .filter_map(|k| first.checked_add_months(Months::new(months * k)))
+        .find_map(|month| month.with_day(day))
+}
+
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_todoist_due_strings() {
+        // 2026-03-11 is a Wednesday.
+        let mut rule = Recurrence::starting(NaiveDate::from_ymd_opt(2026, 3, 11).unwrap());
+        assert_eq!(rule.due_string(), "every wed");
+
+        rule.weekdays[0] = true;
+        rule.interval = 2;
+        assert_eq!(rule.due_string(), "every 2 weeks on mon, wed");
+
+        rule.frequency = Frequency::Monthly;
+        assert_eq!(rule.due_string(), "every 2 months on the 11th");
+        rule.interval = 1;
+        rule.month_day = 22;
+        assert_eq!(rule.due_string(), "every 22nd");
+
+        rule.frequency = Frequency::Daily;
+        assert_eq!(rule.due_string(), "every day");
+        rule.frequency = Frequency::Weekdays;
+        assert_eq!(rule.due_string(), "every weekday");
+        assert_eq!(rule.frequency.cycle(2), Frequency::Monthly);
+        assert_eq!(Frequency::Daily.cycle(-1), Frequency::Monthly);
+    }
+
+    #[test]
+    fn finds_the_next_occurrence() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        // 2026-03-11 is a Wednesday.
+        let wed = date(3, 11);
+        let next = |rule| next_occurrence(rule, wed);
+
+        assert_eq!(next("every day"), Some(date(3, 12)));
+        assert_eq!(next("Every 3 days at 9am"), Some(date(3, 14)));
+        assert_eq!(next("every day starting mar 1"), Some(date(3, 12)));
+        assert_eq!(next("every 2 weeks"), Some(date(3, 25)));
+        assert_eq!(next("every mon, wed"), Some(date(3, 16)));
+        assert_eq!(next("every friday"), Some(date(3, 13)));
+        assert_eq!(next("every 2 weeks on mon, wed"), Some(date(3, 23)));
+        assert_eq!(next("every weekday"), Some(date(3, 12)));
+        assert_eq!(
+            next_occurrence("every weekday", date(3, 13)),
+            Some(date(3, 16))
+        );
+        assert_eq!(next("every 22nd"), Some(date(3, 22)));
+        assert_eq!(next("every 11th"), Some(date(4, 11)));
+        assert_eq!(
+            next_occurrence("every 31st", date(3, 31)),
+            Some(date(5, 31))
+        );
+        assert_eq!(next("every 2 months on the 11th"), Some(date(5, 11)));
+        assert_eq!(next("every month"), Some(date(4, 11)));
+
+        assert_eq!(next("every! 3 days"), None);
+        assert_eq!(next("every day until apr 1"), None);
+        assert_eq!(next("every last day"), None);
+        assert_eq!(next("every morning"), None);
+        assert_eq!(next("tomorrow"), None);
+    }
+}
@@ -0,0 +1,136 @@
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// The user's login shell, as far as onboarding cares: where its startup file
+/// lives and how it spells an alias.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Zsh,
+    Bash,
+    Fish,
+    Nu,
+    /// sh, dash, ksh or anything unrecognised: no file is written, the line is
+    /// shown for the user to copy.
+    Posix,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AliasOutcome {
+    Added,
+    AlreadyPresent,
+}
+
+impl Shell {
+    pub fn detect() -> Self {
+        if std::env::var_os("NU_VERSION").is_some() {
+            return Shell::Nu;
+        }
+        Self::from_path(&std::env::var("SHELL").unwrap_or_default())
+    }
+
+    fn from_path(shell: &str) -> Self {
+        match shell.rsplit('/').next().unwrap_or_default() {
+            "zsh" => Shell::Zsh,
+            "bash" => Shell::Bash,
+            "fish" => Shell::Fish,
+            "nu" => Shell::Nu,
+            _ => Shell::Posix,
+        }
+    }
+
+    /// Startup file the alias goes into; `None` for the copy-it-yourself fallback.
+    pub fn rc_path(self) -> Option<PathBuf> {
+        let home = dirs::home_dir()?;
+        match self {
+            Shell::Zsh => Some(home.join(".zshrc")),
+            Shell::Bash => {
+                let profile = home.join(".bash_profile");
+                Some(if profile.exists() {
+                    profile
+                } else {
+                    home.join(".bashrc")
+                })
+            }
+            Shell::Fish => Some(xdg_config_home(&home).join("fish").join("config.fish")),
+            Shell::Nu => Some(
+                dirs::config_dir()
+                    .unwrap_or_else(|| xdg_config_home(&home))
+                    .join("nushell")
+                    .join("config.nu"),
+            ),
+            Shell::Posix => None,
+        }
+    }
+
+    pub fn alias_line(self, name: &str) -> String {
+        match self {
+            Shell::Fish => format!("abbr --add {name} ratatoist"),
+            Shell::Nu => format!("alias {name} = ratatoist"),
+            Shell::Zsh | Shell::Bash | Shell::Posix => format!("alias {name}='ratatoist'"),
+        }
+    }
+
+    /// True if `rc` already defines `name` (as alias or abbreviation) in any
+    /// common spelling, so onboarding never appends a duplicate.
+    fn defines(self, rc: &str, name: &str) -> bool {
+        rc.lines().map(str::trim).any(|line| {
+            let rest = line
+                .strip_prefix("alias ")
+                .or_else(|| line.strip_prefix("abbr --add "))
+                .or_else(|| line.strip_prefix("abbr -a "));
+            rest.is_some_and(|rest| {
+                rest.strip_prefix(name)
+                    .is_some_and(|after| after.starts_with(['=', ' ']))
+            })
+        })
+    }
+
+    pub fn write_alias(self, name: &str, rc_path: &Path) -> Result<AliasOutcome> {
+        let existing = std::fs::read_to_string(rc_path).unwrap_or_default();
+        if self.defines(&existing, name) {
+            return Ok(AliasOutcome::AlreadyPresent);
+        }
+        if let Some(dir) = rc_path.parent() {
+            std::fs::create_dir_all(dir).context("failed to create config directory")?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(rc_path)
+            .with_context(|| format!("failed to open {}", rc_path.display()))?;
+        writeln!(file, "\n{}", self.alias_line(name))?;
+        Ok(AliasOutcome::Added)
+    }
+}
+
+fn xdg_config_home(home: &Path) -> PathBuf {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home.join(".config"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_shell_from_path() {
+        assert_eq!(Shell::from_path("/usr/bin/fish"), Shell::Fish);
+        assert_eq!(Shell::from_path("/bin/zsh"), Shell::Zsh);
+        assert_eq!(Shell::from_path("/bin/dash"), Shell::Posix);
+        assert_eq!(Shell::from_path(""), Shell::Posix);
+    }
+
+    #[test]
+    fn existing_alias_is_not_duplicated() {
+        let rc = "export PATH=$PATH\nalias rat='ratatoist'\n";
+        assert!(Shell::Zsh.defines(rc, "rat"));
+        assert!(!Shell::Zsh.defines(rc, "ra"));
+        assert!(Shell::Fish.defines("  abbr -a rat ratatoist", "rat"));
+        assert!(Shell::Nu.defines("alias rat = ratatoist", "rat"));
+        assert!(!Shell::Nu.defines("# alias rat = ratatoist", "rat"));
+    }
+}
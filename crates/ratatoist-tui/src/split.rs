@@ -0,0 +1,112 @@
+use crate::ui::text::strip_list_marker;
+
+/// How a task breaks down into subtasks. `content` and `description` are the
+/// parent's new values, or `None` when they stay as they are.
+#[derive(Debug, PartialEq)]
+pub struct SplitPlan {
+    pub content: Option<String>,
+    pub description: Option<String>,
+    pub subtasks: Vec<String>,
+}
+
+/// Works out the subtasks hiding in a task, trying in order:
+///
+/// 1. list lines (`- a`, `[ ] b`, `1. c`) in the description, which leave
+///    any other prose behind as the new description;
+/// 2. a description of two or more plain lines, one subtask each;
+/// 3. a title like `Pack: tent, stove; mat`, which keeps `Pack`;
+/// 4. a title of `;`-separated parts, which is kept as is.
+///
+/// Returns `None` when fewer than two subtasks would come out.
+pub fn plan(content: &str, description: &str) -> Option<SplitPlan> {
+    let lines: Vec<&str> = description
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect();
+
+    let items: Vec<String> = lines
+        .iter()
+        .filter_map(|l| strip_list_marker(l))
+        .filter(|l| !l.is_empty())
+        .map(str::to_string)
+        .collect();
+    if !items.is_empty() {
+        let prose: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|l| strip_list_marker(l).is_none())
+            .collect();
+        return Some(SplitPlan {
+            content: None,
+            description: Some(prose.join("\n")),
+            subtasks: items,
+        });
+    }
+
+    if lines.len() > 1 {
+        return Some(SplitPlan {
+            content: None,
+            description: Some(String::new()),
+            subtasks: lines.iter().map(|l| l.to_string()).collect(),
+        });
+    }
+
+    if let Some((title, rest)) = content.split_once(':') {
+        let parts = split_parts(rest, &[',', ';']);
+        if parts.len() > 1 && !title.trim().is_empty() {
+            return Some(SplitPlan {
+                content: Some(title.trim().to_string()),
+                description: None,
+                subtasks: parts,
+            });
+        }
+    }
+
+    let parts = split_parts(content, &[';']);
+    (parts.len() > 1).then_some(SplitPlan {
+        content: None,
+        description: None,
+        subtasks: parts,
+    })
+}
+
+fn split_parts(s: &str, delimiters: &[char]) -> Vec<String> {
+    s.split(delimiters)
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_first_source_that_yields_subtasks() {
+        let checklist = plan(
+            "Trip prep",
+            "Before Friday:\n- [ ] book train\n- [x] pack bag\n",
+        )
+        .unwrap();
+        assert_eq!(checklist.content, None);
+        assert_eq!(checklist.description.as_deref(), Some("Before Friday:"));
+        assert_eq!(checklist.subtasks, vec!["book train", "pack bag"]);
+
+        let plain = plan("Trip prep", "book train\npack bag").unwrap();
+        assert_eq!(plain.description.as_deref(), Some(""));
+        assert_eq!(plain.subtasks.len(), 2);
+
+        let titled = plan("Pack: tent, stove; mat", "").unwrap();
+        assert_eq!(titled.content.as_deref(), Some("Pack"));
+        assert_eq!(titled.subtasks, vec!["tent", "stove", "mat"]);
+
+        let semis = plan("call bank; email Sam", "").unwrap();
+        assert_eq!(semis.content, None);
+        assert_eq!(semis.subtasks, vec!["call bank", "email Sam"]);
+
+        assert_eq!(plan("Meeting at 10:30", "just one note"), None);
+        assert_eq!(plan("Read: chapter one", ""), None);
+    }
+}
@@ -0,0 +1,162 @@
+use chrono::{DateTime, Local};
+
+use crate::app::InputMode;
+
+/// The pane a tour card talks about; it gets a highlighted border.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TourTarget {
+    Projects,
+    Tasks,
+    Dock,
+}
+
+/// What moves the tour past a card: reading it, or doing what it describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TourGoal {
+    Read,
+    AddTask,
+    CompleteTask,
+    DockFilter,
+}
+
+/// One card of the first-run tour, worded for each input mode.
+#[derive(Debug)]
+pub struct TourStep {
+    pub title: &'static str,
+    pub vim: &'static str,
+    pub standard: &'static str,
+    pub target: Option<TourTarget>,
+    pub goal: TourGoal,
+}
+
+impl TourStep {
+    pub fn body(&self, mode: &InputMode) -> &'static str {
+        match mode {
+            InputMode::Vim(_) => self.vim,
+            InputMode::Standard => self.standard,
+        }
+    }
+}
+
+const fn step(
+    title: &'static str,
+    vim: &'static str,
+    standard: &'static str,
+    target: Option<TourTarget>,
+    goal: TourGoal,
+) -> TourStep {
+    TourStep {
+        title,
+        vim,
+        standard,
+        target,
+        goal,
+    }
+}
+
+pub const STEPS: &[TourStep] = &[
+    step(
+        "Welcome to ratatoist",
+        "A one-minute tour of the panes and the keys you'll use every day.",
+        "A one-minute tour of the panes and the keys you'll use every day.",
+        None,
+        TourGoal::Read,
+    ),
+    step(
+        "Projects",
+        "Favorites, the Today view and your projects. j/k moves, Enter opens one, l goes to its tasks.",
+        "Favorites, the Today view and your projects. ↑/↓ moves, Enter opens one, Tab goes to its tasks.",
+        Some(TourTarget::Projects),
+        TourGoal::Read,
+    ),
+    step(
+        "Tasks",
+        "The open project's tasks. Enter shows a task's details, Esc comes back.",
+        "The open project's tasks. Enter shows a task's details, Esc comes back.",
+        Some(TourTarget::Tasks),
+        TourGoal::Read,
+    ),
+    step(
+        "Add a task",
+        "Press a, type a title, Enter, then Tab to add it.",
+        "Press Ctrl-a, type a title, Enter, then Tab to add it.",
+        Some(TourTarget::Tasks),
+        TourGoal::AddTask,
+    ),
+    step(
+        "Complete a task",
+        "x completes the selected task; x on it again brings it back.",
+        "Ctrl-x completes the selected task; Ctrl-x on it again brings it back.",
+        Some(TourTarget::Tasks),
+        TourGoal::CompleteTask,
+    ),
+    step(
+        "Dock filters",
+        "The dock counts tasks by due date and priority. Press l to step into it, j/k to pick a count and Enter to filter the list by it.",
+        "The dock counts tasks by due date and priority. Press Tab to step into it, ↑/↓ to pick a count and Enter to filter the list by it.",
+        Some(TourTarget::Dock),
+        TourGoal::DockFilter,
+    ),
+    step(
+        "That's the tour",
+        "? lists every key, and / in it searches them. , opens settings.",
+        "? lists every key, and / in it searches them. , opens settings.",
+        None,
+        TourGoal::Read,
+    ),
+];
+
+/// Progress through the first-run tour.
+#[derive(Debug, Clone)]
+pub struct Tour {
+    pub step: usize,
+    /// When the current card came up, so only completions after it count.
+    pub since: DateTime<Local>,
+    /// How many tasks there were when the current card came up.
+    pub tasks_before: usize,
+}
+
+impl Tour {
+    pub fn new(tasks: usize) -> Self {
+        Self {
+            step: 0,
+            since: Local::now(),
+            tasks_before: tasks,
+        }
+    }
+
+    pub fn current(&self) -> &'static TourStep {
+        &STEPS[self.step.min(STEPS.len() - 1)]
+    }
+
+    /// Moves `by` cards, restarting the card's baseline; `false` once that
+    /// runs off the end of the tour.
+    pub fn go(&mut self, by: isize, tasks: usize) -> bool {
+        let next = self.step as isize + by;
+        if next >= STEPS.len() as isize {
+            return false;
+        }
+        self.step = next.max(0) as usize;
+        self.since = Local::now();
+        self.tasks_before = tasks;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walks_forward_and_back_and_ends_after_the_last_card() {
+        let mut tour = Tour::new(3);
+        assert!(tour.go(-1, 3));
+        assert_eq!(tour.step, 0);
+        for _ in 1..STEPS.len() {
+            assert!(tour.go(1, 4));
+        }
+        assert_eq!(tour.current().title, "That's the tour");
+        assert_eq!(tour.tasks_before, 4);
+        assert!(!tour.go(1, 4));
+    }
+}
@@ -1,14 +1,23 @@
+use std::sync::atomic::Ordering;
+
 use ratatui::Frame;
-use ratatui::layout::Alignment;
+use ratatui::layout::{Alignment, Constraint, Layout};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+use ratatui::widgets::{
+    Block, Borders, Padding, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+};
 
-use crate::app::InputMode;
+use crate::app::App;
+use crate::keymap;
 use crate::ui::theme::Theme;
 
 use super::popup::{centered_rect, render_dim_overlay};
 
-pub fn render(frame: &mut Frame, mode: &InputMode, theme: &Theme) {
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(help) = &app.help else {
+        return;
+    };
+    let theme = app.theme();
     render_dim_overlay(frame, theme);
 
     let area = frame.area();
@@ -24,16 +33,69 @@ pub fn render(frame: &mut Frame, mode: &InputMode, theme: &Theme) {
         .padding(Padding::new(2, 2, 1, 1))
         .style(theme.base_bg());
 
-    let lines = match mode {
-        InputMode::Vim(_) => vim_bindings(theme),
-        InputMode::Standard => standard_bindings(theme),
-    };
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let [search_area, _, list_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
 
-    let paragraph = Paragraph::new(lines)
-        .block(block)
-        .wrap(Wrap { trim: false });
+    let mut search = vec![Span::styled("/ ", theme.key_hint())];
+    if help.query.is_empty() && !help.searching {
+        search.push(Span::styled("search bindings", theme.muted_text()));
+    } else {
+        search.push(Span::styled(help.query.as_str(), theme.normal_text()));
+    }
+    if help.searching {
+        search.push(Span::styled("▎", theme.due_upcoming()));
+    }
+    frame.render_widget(Paragraph::new(Line::from(search)), search_area);
 
-    frame.render_widget(paragraph, popup);
+    let mut lines = Vec::new();
+    for (title, bindings) in keymap::search(&app.input_mode, &help.query) {
+        if !lines.is_empty() {
+            lines.push(Line::default());
+        }
+        lines.push(section(title, theme));
+        lines.extend(bindings.iter().map(|b| binding(b.keys, b.action, theme)));
+    }
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "no matching bindings",
+            theme.muted_text(),
+        )));
+    }
+
+    let max_scroll = (lines.len() as u16).saturating_sub(list_area.height);
+    help.scroll_max.store(max_scroll, Ordering::Relaxed);
+    let scroll = help.scroll.min(max_scroll);
+    frame.render_widget(Paragraph::new(lines).scroll((scroll, 0)), list_area);
+    if max_scroll > 0 {
+        let mut state = ScrollbarState::new(max_scroll as usize).position(scroll as usize);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .style(theme.muted_text()),
+            list_area,
+            &mut state,
+        );
+    }
+
+    let hint = if help.searching {
+        "Enter done  Esc clear"
+    } else {
+        "j/k scroll  / search  ? or Esc close"
+    };
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(hint, theme.muted_text())))
+            .alignment(Alignment::Center),
+        hint_area,
+    );
 }
 
 fn section(title: &str, theme: &Theme) -> Line<'static> {
@@ -46,86 +108,3 @@ fn binding(key: &str, desc: &str, theme: &Theme) -> Line<'static> {
         Span::styled(desc.to_string(), theme.normal_text()),
     ])
 }
-
-fn blank() -> Line<'static> {
-    Line::default()
-}
-
-fn vim_bindings(theme: &Theme) -> Vec<Line<'static>> {
-    vec![
-        section("Navigation", theme),
-        binding("j / k", "Move down / up", theme),
-        binding("h / l", "Switch pane left / right", theme),
-        binding("g / G", "Jump to top / bottom", theme),
-        binding("Tab / Shift-Tab", "Next / previous pane", theme),
-        binding("Enter", "Open project / toggle fold", theme),
-        binding("Esc", "Go back", theme),
-        blank(),
-        section("Tasks", theme),
-        binding("x", "Complete / uncomplete", theme),
-        binding("a", "Add task (quick-add)", theme),
-        binding("o", "Cycle sort mode", theme),
-        binding("f", "Cycle filter (active/done/both)", theme),
-        binding("Enter", "Open detail / toggle fold", theme),
-        binding("Space", "Toggle fold / overdue section", theme),
-        blank(),
-        section("Today view", theme),
-        binding("Space", "Toggle Overdue section", theme),
-        blank(),
-        section("Detail pane", theme),
-        binding("j / k", "Navigate fields", theme),
-        binding("i / Enter", "Edit selected field", theme),
-        binding("c", "Add comment", theme),
-        binding("x", "Complete task", theme),
-        binding("Esc / h", "Back to tasks", theme),
-        blank(),
-        section("Projects", theme),
-        binding("s", "Star / unstar", theme),
-        blank(),
-        section("Folding", theme),
-        binding("za", "Toggle fold at cursor", theme),
-        binding("zR", "Open all folds", theme),
-        binding("zM", "Close all folds", theme),
-        blank(),
-        section("General", theme),
-        binding(",", "Open settings", theme),
-        binding("R", "Force full re-sync", theme),
-        binding("?", "This help", theme),
-        binding("q", "Quit", theme),
-        binding("Ctrl-c", "Force quit", theme),
-        blank(),
-        Line::from(Span::styled("press ? or Esc to close", theme.muted_text()))
-            .alignment(Alignment::Center),
-    ]
-}
-
-fn standard_bindings(theme: &Theme) -> Vec<Line<'static>> {
-    vec![
-        section("Navigation", theme),
-        binding("↑ / ↓", "Move up / down", theme),
-        binding("← / →", "Switch pane", theme),
-        binding("Home / End", "Jump to top / bottom", theme),
-        binding("Tab / Shift-Tab", "Next / previous pane", theme),
-        binding("Enter", "Open detail / toggle fold", theme),
-        binding("Esc", "Go back", theme),
-        blank(),
-        section("Tasks", theme),
-        binding("Ctrl-x", "Complete / uncomplete", theme),
-        binding("Ctrl-a", "Add task (quick-add)", theme),
-        binding("f", "Cycle filter (active/done/both)", theme),
-        blank(),
-        section("Detail pane", theme),
-        binding("↑ / ↓", "Navigate fields", theme),
-        binding("Enter", "Edit selected field", theme),
-        blank(),
-        section("General", theme),
-        binding(",", "Open settings", theme),
-        binding("R", "Force full re-sync", theme),
-        binding("?", "This help", theme),
-        binding("q", "Quit", theme),
-        binding("Ctrl-c", "Force quit", theme),
-        blank(),
-        Line::from(Span::styled("press ? or Esc to close", theme.muted_text()))
-            .alignment(Alignment::Center),
-    ]
-}
@@ -0,0 +1,42 @@
+use ratatui::Frame;
+use ratatui::layout::Alignment;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+
+use crate::app::Confirm;
+use crate::ui::theme::Theme;
+
+use super::popup::{centered_fixed_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, confirm: &Confirm, theme: &Theme) {
+    render_dim_overlay(frame, theme);
+
+    let area = frame.area();
+    let popup_area = centered_fixed_rect(45, 7, area);
+
+    let block = Block::default()
+        .title(format!(" {} ", confirm.title))
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(2, 2, 1, 0))
+        .style(theme.base_bg());
+
+    let lines = vec![
+        Line::from(Span::styled(&confirm.message, theme.normal_text()))
+            .alignment(Alignment::Center),
+        Line::default(),
+        Line::from(vec![
+            Span::styled("y/Enter", theme.key_hint()),
+            Span::styled(" confirm  ", theme.muted_text()),
+            Span::styled("n/Esc", theme.key_hint()),
+            Span::styled(" cancel", theme.muted_text()),
+        ])
+        .alignment(Alignment::Center),
+    ];
+
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
+    frame.render_widget(paragraph, popup_area);
+}
@@ -0,0 +1,72 @@
+use ratatui::Frame;
+use ratatui::layout::Alignment;
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph};
+
+use crate::app::App;
+use crate::ui::dates;
+
+use super::popup::centered_fixed_rect;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(cursor) = app.date_picker else {
+        return;
+    };
+    let theme = app.theme();
+    let today = dates::today();
+
+    let popup = centered_fixed_rect(34, 13, frame.area());
+    let block = Block::default()
+        .title(format!(" {} ", cursor.format("%B %Y")))
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(2, 2, 1, 0))
+        .style(theme.base_bg());
+
+    let mut lines = vec![Line::from(Span::styled(
+        "Mo  Tu  We  Th  Fr  Sa  Su",
+        theme.muted_text(),
+    ))];
+    for week in dates::month_weeks(cursor) {
+        let spans: Vec<Span> = week
+            .iter()
+            .map(|day| match day {
+                None => Span::raw("    "),
+                Some(day) => {
+                    let style = if *day == cursor {
+                        theme.selected_item()
+                    } else if *day == today {
+                        theme.due_today().add_modifier(Modifier::BOLD)
+                    } else if *day < today {
+                        theme.muted_text()
+                    } else {
+                        theme.normal_text()
+                    };
+                    Span::styled(format!("{:>2}  ", day.format("%-d")), style)
+                }
+            })
+            .collect();
+        lines.push(Line::from(spans));
+    }
+    while lines.len() < 7 {
+        lines.push(Line::default());
+    }
+    lines.push(Line::default());
+    lines.push(
+        Line::from(Span::styled(
+            "hjkl move  H/L month  t today",
+            theme.muted_text(),
+        ))
+        .alignment(Alignment::Center),
+    );
+    lines.push(
+        Line::from(Span::styled("Enter pick  Esc back", theme.muted_text()))
+            .alignment(Alignment::Center),
+    );
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
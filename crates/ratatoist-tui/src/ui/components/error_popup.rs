@@ -44,12 +44,23 @@ pub fn render(frame: &mut Frame, error: &AppError, theme: &Theme) {
 
     lines.push(Line::default());
 
-    let dismiss = if error.recoverable {
-        "press any key to dismiss"
+    let mut dismiss_spans = vec![
+        Span::styled("c", theme.key_hint()),
+        Span::styled("  copy details   ", theme.muted_text()),
+    ];
+    if error.retry.is_some() {
+        dismiss_spans.extend([
+            Span::styled("r", theme.key_hint()),
+            Span::styled("  retry   ", theme.muted_text()),
+            Span::styled("any other key to dismiss", theme.muted_text()),
+        ]);
+    } else if error.recoverable {
+        dismiss_spans.push(Span::styled("any other key to dismiss", theme.muted_text()));
     } else {
-        "press any key to exit"
-    };
-    lines.push(Line::from(Span::styled(dismiss, theme.muted_text())).alignment(Alignment::Center));
+        dismiss_spans.push(Span::styled("any other key to exit", theme.muted_text()));
+    }
+    let dismiss_line = Line::from(dismiss_spans);
+    lines.push(dismiss_line.alignment(Alignment::Center));
 
     let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
 
@@ -0,0 +1,99 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, Padding, Paragraph};
+
+use crate::app::App;
+
+use super::popup::{centered_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(fr) = &app.find_replace else {
+        return;
+    };
+    let theme = app.theme();
+    render_dim_overlay(frame, theme);
+
+    let popup = centered_rect(60, 60, frame.area());
+    let project = app
+        .projects
+        .get(app.selected_project)
+        .map_or("", |p| p.name.as_str());
+    let block = Block::default()
+        .title(format!(" Find & replace in {project} "))
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(2, 2, 1, 1))
+        .style(theme.base_bg());
+
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let [find_area, replace_area, _, count_area, list_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let field = |label: &'static str, value: &str, active: bool| {
+        let mut spans = vec![
+            Span::styled(
+                label,
+                if active {
+                    theme.key_hint()
+                } else {
+                    theme.muted_text()
+                },
+            ),
+            Span::styled(value.to_string(), theme.normal_text()),
+        ];
+        if active {
+            spans.push(Span::styled("▎", theme.due_upcoming()));
+        }
+        Paragraph::new(Line::from(spans))
+    };
+    frame.render_widget(field("Find     ", &fr.find, !fr.on_replace), find_area);
+    frame.render_widget(field("Replace  ", &fr.replace, fr.on_replace), replace_area);
+
+    let matches = app.find_replace_matches();
+    let summary = match matches.len() {
+        _ if fr.find.is_empty() => String::new(),
+        0 => "no titles would change".to_string(),
+        1 => "1 title will change".to_string(),
+        n => format!("{n} titles will change"),
+    };
+    frame.render_widget(
+        Paragraph::new(Span::styled(summary, theme.muted_text())),
+        count_area,
+    );
+
+    let items: Vec<ListItem> = matches
+        .iter()
+        .map(|(task, renamed)| {
+            ListItem::new(vec![
+                Line::from(Span::styled(task.content.as_str(), theme.muted_text())),
+                Line::from(vec![
+                    Span::styled("→ ", theme.key_hint()),
+                    Span::styled(renamed.as_str(), theme.normal_text()),
+                ]),
+            ])
+        })
+        .collect();
+    frame.render_widget(List::new(items), list_area);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "Tab switch field  Enter next / apply  Esc cancel",
+            theme.muted_text(),
+        )))
+        .alignment(Alignment::Center),
+        hint_area,
+    );
+}
@@ -4,7 +4,7 @@ use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
 
-use crate::app::App;
+use crate::app::{App, LOCAL_NOTE_FIELD};
 
 use super::popup::{centered_rect, render_dim_overlay};
 
@@ -17,11 +17,14 @@ pub fn render(frame: &mut Frame, app: &App) {
 
     let title = if app.comment_input {
         " Add Comment "
+    } else if app.quick_add_input {
+        " Quick Add "
     } else if app.editing_field {
         match app.detail_field {
             0 => " Edit Content ",
-            1 => " Edit Due Date ",
-            2 => " Edit Description ",
+            2 => " Edit Due Date ",
+            5 => " Edit Description ",
+            LOCAL_NOTE_FIELD => " Edit Private Note ",
             _ => " Edit ",
         }
     } else {
@@ -48,6 +51,8 @@ pub fn render(frame: &mut Frame, app: &App) {
             "e.g. tomorrow, next monday, 2026-03-15, 28/02/2026..."
         } else if app.comment_input {
             "write a comment..."
+        } else if app.quick_add_input {
+            "e.g. Call Sam tomorrow 4pm #Work @phone p2 — parsed by Todoist"
         } else {
             "type task content (p1, @label, #project, due date parsed automatically)..."
         };
@@ -56,22 +61,32 @@ pub fn render(frame: &mut Frame, app: &App) {
             theme.muted_text().add_modifier(Modifier::ITALIC),
         )));
     } else {
-        lines.push(Line::from(vec![
-            Span::styled(&app.input_buffer, theme.normal_text()),
-            Span::styled("▎", theme.due_upcoming()),
-        ]));
+        let mut rows: Vec<Line> = app
+            .input_buffer
+            .split('\n')
+            .map(|row| Line::from(Span::styled(row, theme.normal_text())))
+            .collect();
+        if let Some(last) = rows.last_mut() {
+            last.spans.push(Span::styled("▎", theme.due_upcoming()));
+        }
+        lines.extend(rows);
+    }
+
+    let mut hints = vec![
+        Span::styled("Enter", theme.key_hint()),
+        Span::styled(" submit  ", theme.muted_text()),
+        Span::styled("Esc", theme.key_hint()),
+        Span::styled(" cancel  ", theme.muted_text()),
+    ];
+    if app.editing_due_field() {
+        hints.push(Span::styled("Tab", theme.key_hint()));
+        hints.push(Span::styled(" calendar  ", theme.muted_text()));
     }
+    hints.push(Span::styled("Ctrl-V", theme.key_hint()));
+    hints.push(Span::styled(" paste", theme.muted_text()));
 
     lines.push(Line::default());
-    lines.push(
-        Line::from(vec![
-            Span::styled("Enter", theme.key_hint()),
-            Span::styled(" submit  ", theme.muted_text()),
-            Span::styled("Esc", theme.key_hint()),
-            Span::styled(" cancel", theme.muted_text()),
-        ])
-        .alignment(Alignment::Center),
-    );
+    lines.push(Line::from(hints).alignment(Alignment::Center));
 
     let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
     frame.render_widget(paragraph, inner);
@@ -0,0 +1,87 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph};
+
+use crate::app::{App, LabelChange};
+
+use super::popup::{centered_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(manager) = &app.label_manager else {
+        return;
+    };
+    let theme = app.theme();
+    render_dim_overlay(frame, theme);
+
+    let popup = centered_rect(50, 60, frame.area());
+    let block = Block::default()
+        .title(" Shared labels ")
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(1, 1, 1, 0))
+        .style(theme.base_bg());
+
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let [list_area, prompt_area, hint_area] = Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(2),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let labels = app.shared_labels();
+    let items: Vec<ListItem> = labels
+        .iter()
+        .map(|(name, count)| {
+            let noun = if *count == 1 { "task" } else { "tasks" };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("@{name}"), theme.label_tag()),
+                Span::styled(format!("  {count} {noun}"), theme.muted_text()),
+            ]))
+        })
+        .collect();
+    let selection = manager.selection.min(labels.len().saturating_sub(1));
+    let list = List::new(items).highlight_style(theme.selected_item());
+    let mut state = ListState::default().with_selected(Some(selection));
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    let (name, count) = labels
+        .get(selection)
+        .map_or(("", 0), |(name, count)| (name.as_str(), *count));
+    let noun = if count == 1 { "task" } else { "tasks" };
+    let (prompt, hint) = match (&manager.rename, &manager.confirm) {
+        (_, Some(change)) => {
+            let warning = match change {
+                LabelChange::Rename(new_name) => {
+                    format!("Rename @{name} to @{new_name} on {count} {noun}?")
+                }
+                LabelChange::Remove => format!("Remove @{name} from {count} {noun}?"),
+            };
+            (
+                Line::from(Span::styled(warning, theme.due_overdue())),
+                "Enter confirm  Esc back",
+            )
+        }
+        (Some(new_name), None) => (
+            Line::from(vec![
+                Span::styled("Rename to  @", theme.key_hint()),
+                Span::styled(new_name.as_str(), theme.normal_text()),
+                Span::styled("▎", theme.due_upcoming()),
+            ]),
+            "Enter next  Esc back",
+        ),
+        (None, None) => (Line::default(), "r rename  d remove  Esc close"),
+    };
+    frame.render_widget(Paragraph::new(prompt), prompt_area);
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(hint, theme.muted_text())))
+            .alignment(Alignment::Center),
+        hint_area,
+    );
+}
@@ -1,8 +1,23 @@
 pub mod cheatsheet;
+pub mod confirm_popup;
+pub mod date_picker;
 pub mod error_popup;
+pub mod find_replace;
 pub mod input_popup;
+pub mod labels;
 pub mod list;
+pub mod notifications;
+pub mod perf_overlay;
 pub mod popup;
 pub mod priority_picker;
+pub mod project_info;
+pub mod project_jumper;
+pub mod recent;
+pub mod recurrence;
+pub mod recurring_prompt;
+pub mod section_picker;
 pub mod task_form;
 pub mod theme_picker;
+pub mod tour;
+pub mod triage;
+pub mod workspace_overview;
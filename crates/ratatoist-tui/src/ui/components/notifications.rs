@@ -0,0 +1,141 @@
+use chrono::DateTime;
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph};
+
+use ratatoist_core::api::models::LiveNotification;
+
+use crate::app::App;
+use crate::ui::dates;
+
+use super::popup::{centered_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let theme = app.theme();
+    render_dim_overlay(frame, theme);
+
+    let popup = centered_rect(60, 60, frame.area());
+    let unread = app.unread_notification_count();
+    let title = if unread > 0 {
+        format!(" Notifications ({unread} unread) ")
+    } else {
+        " Notifications ".to_string()
+    };
+
+    let block = Block::default()
+        .title(title)
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(1, 1, 1, 0))
+        .style(theme.base_bg());
+
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let [list_area, hint_area] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+
+    if app.notifications.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "No invites, assignments or comments",
+                theme.muted_text(),
+            ))),
+            list_area,
+        );
+    } else {
+        let items: Vec<ListItem> = app
+            .notifications
+            .iter()
+            .map(|n| {
+                let (summary, detail) = describe(app, n);
+                let dot = if n.is_unread { "● " } else { "  " };
+                let summary_style = if n.is_unread {
+                    theme.normal_text()
+                } else {
+                    theme.muted_text()
+                };
+                let mut lines = vec![Line::from(vec![
+                    Span::styled(dot, theme.due_upcoming()),
+                    Span::styled(summary, summary_style),
+                    Span::styled(
+                        format!(
+                            "  {}",
+                            n.created_at.as_deref().map(when).unwrap_or_default()
+                        ),
+                        theme.muted_text(),
+                    ),
+                ])];
+                if !detail.is_empty() {
+                    lines.push(Line::from(Span::styled(
+                        format!("  {detail}"),
+                        theme.subtle_text(),
+                    )));
+                }
+                ListItem::new(lines)
+            })
+            .collect();
+
+        let list = List::new(items).highlight_style(theme.selected_item());
+        let mut state = ListState::default().with_selected(Some(app.notification_selection));
+        frame.render_stateful_widget(list, list_area, &mut state);
+    }
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("r", theme.key_hint()),
+            Span::styled(" read  ", theme.muted_text()),
+            Span::styled("R", theme.key_hint()),
+            Span::styled(" all read  ", theme.muted_text()),
+            Span::styled("Esc", theme.key_hint()),
+            Span::styled(" close", theme.muted_text()),
+        ]))
+        .alignment(Alignment::Center),
+        hint_area,
+    );
+}
+
+/// One-line summary plus the task, comment or project it refers to.
+fn describe(app: &App, n: &LiveNotification) -> (String, String) {
+    let who = n
+        .from_user
+        .as_ref()
+        .and_then(|u| u.name.clone().or_else(|| u.email.clone()))
+        .unwrap_or_else(|| "Someone".to_string());
+    let task = n.item_content.clone().unwrap_or_default();
+    match n.notification_type.as_str() {
+        "item_assigned" => (format!("{who} assigned you a task"), task),
+        "note_added" => (
+            format!("{who} commented on {task}"),
+            n.note_content.clone().unwrap_or_default(),
+        ),
+        _ => {
+            let project = n
+                .project_name
+                .clone()
+                .or_else(|| {
+                    let id = n.project_id.as_deref()?;
+                    app.projects
+                        .iter()
+                        .find(|p| p.id == id)
+                        .map(|p| p.name.clone())
+                })
+                .unwrap_or_else(|| "a project".to_string());
+            (format!("{who} invited you to {project}"), String::new())
+        }
+    }
+}
+
+fn when(timestamp: &str) -> String {
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|dt| {
+            dt.with_timezone(&dates::zone())
+                .format("%b %d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,71 @@
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Padding, Paragraph};
+
+use crate::app::App;
+
+const WIDTH: u16 = 28;
+const HEIGHT: u16 = 8;
+
+/// `--debug` corner panel: render and loop timings, data sizes, sync
+/// round-trip, background jobs still running and the API request budget.
+pub fn render(frame: &mut Frame, app: &App) {
+    let theme = app.theme();
+    let area = frame.area();
+    if area.width < WIDTH || area.height < HEIGHT + 2 {
+        return;
+    }
+    let rect = Rect::new(area.right() - WIDTH, area.y, WIDTH, HEIGHT);
+
+    let last_sync = match app.perf.last_sync_ms.load(Ordering::Relaxed) {
+        0 => "-".to_string(),
+        ms => format!("{ms} ms"),
+    };
+    let budget = match app.rate_budget() {
+        Some(b) => format!("{}/{}", b.remaining, b.limit),
+        None => "-".to_string(),
+    };
+    let rows = [
+        ("frame", millis(app.frame_time)),
+        ("loop", millis(app.loop_latency)),
+        (
+            "data",
+            format!("{}t {}p", app.tasks.len(), app.projects.len()),
+        ),
+        ("sync", last_sync),
+        (
+            "jobs",
+            app.perf.in_flight.load(Ordering::Relaxed).to_string(),
+        ),
+        ("budget", budget),
+    ];
+    let lines: Vec<Line> = rows
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::styled(format!("{label:<7}"), theme.muted_text()),
+                Span::styled(value, theme.normal_text()),
+            ])
+        })
+        .collect();
+
+    let block = Block::default()
+        .title(" debug ")
+        .title_style(theme.muted_text())
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.inactive_border())
+        .padding(Padding::horizontal(1))
+        .style(theme.surface_bg());
+
+    frame.render_widget(Clear, rect);
+    frame.render_widget(Paragraph::new(lines).block(block), rect);
+}
+
+fn millis(d: Duration) -> String {
+    format!("{:.1} ms", d.as_secs_f64() * 1000.0)
+}
@@ -0,0 +1,118 @@
+use ratatui::Frame;
+use ratatui::layout::Alignment;
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+
+use ratatoist_core::api::models::Project;
+use ratatoist_core::api::sync::CollaboratorState;
+
+use crate::app::App;
+
+use super::popup::{centered_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let theme = app.theme();
+    let Some(project) = app.projects.get(app.selected_project) else {
+        return;
+    };
+    render_dim_overlay(frame, theme);
+
+    let popup = centered_rect(50, 60, frame.area());
+    let block = Block::default()
+        .title(format!(" {} ", project.name))
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(2, 2, 1, 1))
+        .style(theme.base_bg());
+
+    let collaborators = app.project_collaborators();
+    let (members, invites): (Vec<_>, Vec<_>) = collaborators
+        .into_iter()
+        .partition(|s| s.state != "invited");
+
+    let mut lines = Vec::new();
+    if members.is_empty() && invites.is_empty() {
+        let note = if project.is_shared {
+            "Shared — members haven't synced yet"
+        } else {
+            "Only you have access"
+        };
+        lines.push(Line::from(Span::styled(note, theme.muted_text())));
+    }
+
+    if !members.is_empty() {
+        lines.push(heading(format!("Members ({})", members.len()), app));
+        for s in &members {
+            lines.push(person_line(app, project, s, "●"));
+        }
+    }
+
+    if !invites.is_empty() {
+        if !members.is_empty() {
+            lines.push(Line::default());
+        }
+        lines.push(heading(format!("Pending invites ({})", invites.len()), app));
+        for s in &invites {
+            lines.push(person_line(app, project, s, "○"));
+        }
+    }
+
+    lines.push(Line::default());
+    lines.push(
+        Line::from(Span::styled("press Esc to close", theme.muted_text()))
+            .alignment(Alignment::Center),
+    );
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        popup,
+    );
+}
+
+fn heading(text: String, app: &App) -> Line<'static> {
+    Line::from(Span::styled(
+        text,
+        app.theme().muted_text().add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn person_line(
+    app: &App,
+    project: &Project,
+    state: &CollaboratorState,
+    marker: &'static str,
+) -> Line<'static> {
+    let theme = app.theme();
+    let mut name = app.user_display(&state.user_id);
+    if app.current_user_id.as_deref() == Some(state.user_id.as_str()) {
+        name.push_str(" (you)");
+    }
+    Line::from(vec![
+        Span::styled(format!("{marker} "), theme.success()),
+        Span::styled(name, theme.normal_text()),
+        Span::styled(
+            format!("  {}", role_label(project, state)),
+            theme.muted_text(),
+        ),
+    ])
+}
+
+/// Workspace projects carry an explicit role; in personal shared projects the
+/// creator owns the project and everyone else can edit.
+fn role_label(project: &Project, state: &CollaboratorState) -> &'static str {
+    match state.role.as_deref() {
+        Some("CREATOR") => "owner",
+        Some("ADMIN") => "admin",
+        Some("READ_WRITE") => "can edit",
+        Some("READ_ONLY") => "view only",
+        _ if state.state == "invited" => "invited",
+        _ if project.creator_uid.as_deref() == Some(state.user_id.as_str()) => "owner",
+        _ => "member",
+    }
+}
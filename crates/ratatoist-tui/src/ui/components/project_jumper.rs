@@ -0,0 +1,90 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph};
+
+use crate::app::{App, JumperPurpose};
+
+use super::popup::{centered_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(jumper) = &app.project_jumper else {
+        return;
+    };
+    let theme = app.theme();
+    render_dim_overlay(frame, theme);
+
+    let area = frame.area();
+    let popup = centered_rect(50, 60, area);
+
+    let block = Block::default()
+        .title(match jumper.purpose {
+            JumperPurpose::Goto => " Go to project ",
+            JumperPurpose::Form => " Project for the new task ",
+            JumperPurpose::MoveTask => " Move task to project ",
+        })
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(2, 2, 1, 1))
+        .style(theme.base_bg());
+
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let [input_area, _, list_area, hint_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("> ", theme.key_hint()),
+            Span::styled(jumper.query.as_str(), theme.normal_text()),
+            Span::styled("▎", theme.due_upcoming()),
+        ])),
+        input_area,
+    );
+
+    let matches = app.project_jumper_matches();
+    if matches.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "no matching projects",
+                theme.muted_text(),
+            ))),
+            list_area,
+        );
+    } else {
+        let items: Vec<ListItem> = matches
+            .into_iter()
+            .map(|(i, path)| {
+                let name_len = app.projects[i].name.len();
+                let (context, name) = path.split_at(path.len() - name_len);
+                ListItem::new(Line::from(vec![
+                    Span::styled(context.to_string(), theme.muted_text()),
+                    Span::styled(name.to_string(), theme.normal_text()),
+                ]))
+            })
+            .collect();
+        let list = List::new(items).highlight_style(theme.selected_item());
+        let mut state = ListState::default().with_selected(Some(jumper.selection));
+        frame.render_stateful_widget(list, list_area, &mut state);
+    }
+
+    let hint = match jumper.purpose {
+        JumperPurpose::Goto => "↑/↓ select  Enter go  Esc cancel",
+        JumperPurpose::Form => "↑/↓ select  Enter pick  Esc back",
+        JumperPurpose::MoveTask => "↑/↓ select  Enter move  Esc cancel",
+    };
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(hint, theme.muted_text())))
+            .alignment(Alignment::Center),
+        hint_area,
+    );
+}
@@ -0,0 +1,73 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph};
+
+use crate::app::{App, RecentKind};
+
+use super::popup::{centered_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(selection) = app.recent_panel else {
+        return;
+    };
+    let theme = app.theme();
+    render_dim_overlay(frame, theme);
+
+    let popup = centered_rect(60, 60, frame.area());
+    let block = Block::default()
+        .title(" Recent ")
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(1, 1, 1, 0))
+        .style(theme.base_bg());
+
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let [list_area, hint_area] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+
+    let items: Vec<ListItem> = app
+        .recent_tasks()
+        .into_iter()
+        .map(|(recent, task)| {
+            let (mark, mark_style) = match recent.kind {
+                RecentKind::Completed => ("✓ ", theme.due_upcoming()),
+                RecentKind::Opened => ("◦ ", theme.muted_text()),
+            };
+            let project = app
+                .projects
+                .iter()
+                .find(|p| p.id == task.project_id)
+                .map_or("", |p| p.name.as_str());
+            ListItem::new(Line::from(vec![
+                Span::styled(recent.at.format("%H:%M  ").to_string(), theme.muted_text()),
+                Span::styled(mark, mark_style),
+                Span::styled(task.content.as_str(), theme.normal_text()),
+                Span::styled(format!("  {project}"), theme.subtle_text()),
+            ]))
+        })
+        .collect();
+    let list = List::new(items).highlight_style(theme.selected_item());
+    let mut state = ListState::default().with_selected(Some(selection));
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("✓", theme.due_upcoming()),
+            Span::styled(" completed  ", theme.muted_text()),
+            Span::styled("◦", theme.muted_text()),
+            Span::styled(" opened    ", theme.muted_text()),
+            Span::styled("Enter", theme.key_hint()),
+            Span::styled(" go  ", theme.muted_text()),
+            Span::styled("Esc", theme.key_hint()),
+            Span::styled(" close", theme.muted_text()),
+        ]))
+        .alignment(Alignment::Center),
+        hint_area,
+    );
+}
@@ -0,0 +1,98 @@
+use ratatui::Frame;
+use ratatui::layout::Alignment;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph};
+
+use crate::app::App;
+use crate::recurrence::Frequency;
+
+use super::popup::{centered_fixed_rect, render_dim_overlay};
+
+const DAY_LETTERS: [&str; 7] = ["M", "T", "W", "T", "F", "S", "S"];
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(editor) = &app.recurrence_editor else {
+        return;
+    };
+    let theme = app.theme();
+    render_dim_overlay(frame, theme);
+
+    let popup = centered_fixed_rect(46, 11, frame.area());
+    let block = Block::default()
+        .title(" Repeat ")
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(2, 2, 1, 0))
+        .style(theme.base_bg());
+
+    let rule = &editor.rule;
+    let label = |row: usize, text: &'static str| {
+        let style = if editor.row == row {
+            theme.key_hint()
+        } else {
+            theme.muted_text()
+        };
+        Span::styled(format!("{text:<8}"), style)
+    };
+    let value = |row: usize, text: String| {
+        if editor.row == row {
+            Span::styled(format!("‹ {text} ›"), theme.active_title())
+        } else {
+            Span::styled(format!("  {text}  "), theme.normal_text())
+        }
+    };
+
+    let mut lines = vec![Line::from(vec![
+        label(0, "Repeat"),
+        value(0, rule.frequency.label().to_string()),
+    ])];
+    if editor.row_count() > 1 {
+        lines.push(Line::from(vec![
+            label(1, "Every"),
+            value(1, rule.interval.to_string()),
+            Span::styled(rule.frequency.unit(), theme.muted_text()),
+        ]));
+    }
+    match rule.frequency {
+        Frequency::Weekly => {
+            let mut spans = vec![label(2, "On"), Span::raw("  ")];
+            for (i, letter) in DAY_LETTERS.iter().enumerate() {
+                let style = if editor.row == 2 && editor.day_cursor == i {
+                    theme.selected_item()
+                } else if rule.weekdays[i] {
+                    theme.active_title()
+                } else {
+                    theme.muted_text()
+                };
+                spans.push(Span::styled(format!(" {letter} "), style));
+            }
+            lines.push(Line::from(spans));
+        }
+        Frequency::Monthly => lines.push(Line::from(vec![
+            label(2, "On day"),
+            value(2, rule.month_day.to_string()),
+        ])),
+        Frequency::Daily | Frequency::Weekdays => {}
+    }
+
+    while lines.len() < 4 {
+        lines.push(Line::default());
+    }
+    lines.push(Line::from(vec![
+        Span::styled("→ ", theme.key_hint()),
+        Span::styled(rule.due_string(), theme.due_upcoming()),
+    ]));
+    lines.push(Line::default());
+    lines.push(
+        Line::from(Span::styled(
+            "j/k row  h/l change  Space day  Enter set  Esc cancel",
+            theme.muted_text(),
+        ))
+        .alignment(Alignment::Center),
+    );
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
@@ -0,0 +1,42 @@
+use ratatui::Frame;
+use ratatui::layout::Alignment;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph};
+
+use crate::ui::theme::Theme;
+
+use super::popup::{centered_fixed_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, theme: &Theme) {
+    render_dim_overlay(frame, theme);
+
+    let area = frame.area();
+    let popup_area = centered_fixed_rect(40, 9, area);
+
+    let block = Block::default()
+        .title(" Recurring task ")
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(2, 2, 1, 0))
+        .style(theme.base_bg());
+
+    let option = |key: &'static str, label: &'static str| {
+        Line::from(vec![
+            Span::styled(format!("{key:>7}  "), theme.key_hint()),
+            Span::styled(label, theme.normal_text()),
+        ])
+    };
+
+    let lines = vec![
+        option("c/Enter", "complete this occurrence"),
+        option("f", "complete forever"),
+        option("s", "skip this occurrence"),
+        Line::default(),
+        Line::from(Span::styled("Esc to cancel", theme.muted_text())).alignment(Alignment::Center),
+    ];
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
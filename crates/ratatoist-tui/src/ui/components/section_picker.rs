@@ -0,0 +1,56 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph};
+
+use crate::app::App;
+
+use super::popup::{centered_fixed_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(selection) = app.section_picker else {
+        return;
+    };
+    let theme = app.theme();
+    render_dim_overlay(frame, theme);
+
+    let options = app.section_picker_options();
+    let popup = centered_fixed_rect(40, options.len() as u16 + 6, frame.area());
+    let block = Block::default()
+        .title(" Section ")
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(2, 2, 1, 0))
+        .style(theme.base_bg());
+
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let [list_area, hint_area] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+
+    let items: Vec<ListItem> = options
+        .iter()
+        .map(|section| match section {
+            Some(section) => {
+                ListItem::new(Span::styled(section.name.as_str(), theme.normal_text()))
+            }
+            None => ListItem::new(Span::styled("No section", theme.muted_text())),
+        })
+        .collect();
+    let list = List::new(items).highlight_style(theme.selected_item());
+    let mut state = ListState::default().with_selected(Some(selection));
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "Enter move  Esc cancel",
+            theme.muted_text(),
+        )))
+        .alignment(Alignment::Center),
+        hint_area,
+    );
+}
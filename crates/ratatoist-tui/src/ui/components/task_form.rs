@@ -2,20 +2,20 @@ use ratatui::Frame;
 use ratatui::layout::Alignment;
 use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph, Wrap};
 
 use ratatoist_core::api::models::priority_label;
 
 use crate::app::{App, TaskForm};
 
-use super::popup::{centered_rect, render_dim_overlay};
+use super::popup::{centered_fixed_rect, centered_rect, render_dim_overlay};
 
 pub fn render(frame: &mut Frame, app: &App, form: &TaskForm) {
     let theme = app.theme();
     render_dim_overlay(frame, theme);
 
     let area = frame.area();
-    let popup = centered_rect(55, 45, area);
+    let popup = centered_rect(55, 60, area);
 
     let block = Block::default()
         .title(" New Task ")
@@ -32,6 +32,32 @@ pub fn render(frame: &mut Frame, app: &App, form: &TaskForm) {
 
     let mut lines = Vec::new();
 
+    let or_none = |value: &str, none: &str| {
+        if value.is_empty() {
+            none.to_string()
+        } else {
+            value.to_string()
+        }
+    };
+    let section = form
+        .section_id
+        .as_ref()
+        .and_then(|id| app.sections.iter().find(|s| &s.id == id))
+        .map_or_else(|| "none".to_string(), |s| s.name.clone());
+    let labels = form
+        .labels
+        .iter()
+        .map(|l| format!("@{l}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let assignee = form.responsible_uid.as_ref().map_or_else(
+        || "unassigned".to_string(),
+        |uid| match app.user_names.get(uid) {
+            Some(user) if !user.full_name.is_empty() => user.full_name.clone(),
+            _ => app.user_display(uid),
+        },
+    );
+
     let fields = [
         (
             "Content",
@@ -58,6 +84,11 @@ pub fn render(frame: &mut Frame, app: &App, form: &TaskForm) {
                 .map(|p| p.name.clone())
                 .unwrap_or_else(|| "Inbox".to_string()),
         ),
+        ("Section", section),
+        ("Labels", or_none(&labels, "none")),
+        ("Notes", or_none(&form.description, "none")),
+        ("Deadline", or_none(&form.deadline, "none")),
+        ("Assignee", assignee),
     ];
 
     for (idx, (label, value)) in fields.iter().enumerate() {
@@ -99,7 +130,9 @@ pub fn render(frame: &mut Frame, app: &App, form: &TaskForm) {
     )));
     lines.push(Line::default());
 
-    let submit_hint = if form.editing {
+    let submit_hint = if app.editing_due_field() {
+        "Enter save field  Tab calendar  Esc back to form"
+    } else if form.editing {
         "Enter save field  Esc back to form"
     } else {
         "j/k navigate  Enter/i edit  Tab submit  Esc cancel"
@@ -110,4 +143,51 @@ pub fn render(frame: &mut Frame, app: &App, form: &TaskForm) {
 
     let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
     frame.render_widget(paragraph, inner);
+
+    if let Some(selection) = app.form_label_picker {
+        render_label_picker(frame, app, form, selection);
+    }
+    if app.project_jumper.is_some() {
+        super::project_jumper::render(frame, app);
+    }
+}
+
+fn render_label_picker(frame: &mut Frame, app: &App, form: &TaskForm, selection: usize) {
+    let theme = app.theme();
+    let names = app.form_label_names();
+    let height = (names.len() as u16 + 4).min(18);
+    let popup = centered_fixed_rect(32, height, frame.area());
+    let block = Block::default()
+        .title(" Labels ")
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .title_bottom(Line::from(" Space toggle  Enter done ").centered())
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(1, 1, 1, 0))
+        .style(theme.base_bg());
+
+    let items: Vec<ListItem> = names
+        .iter()
+        .map(|name| {
+            let on = form.labels.iter().any(|l| l == name);
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    if on { "[x] " } else { "[ ] " },
+                    if on {
+                        theme.due_upcoming()
+                    } else {
+                        theme.muted_text()
+                    },
+                ),
+                Span::styled(format!("@{name}"), theme.label_tag()),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme.selected_item());
+    let mut state = ListState::default().with_selected(Some(selection));
+    frame.render_stateful_widget(list, popup, &mut state);
 }
@@ -6,11 +6,12 @@ use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding};
 
 use crate::app::App;
 
-use super::popup::{centered_rect, render_dim_overlay};
+use super::popup::centered_rect;
 
+/// `app.theme()` is the highlighted theme while the picker is open, so the UI
+/// behind the list previews it; no dim overlay, or the preview would be hidden.
 pub fn render(frame: &mut Frame, app: &App) {
     let theme = app.theme();
-    render_dim_overlay(frame, theme);
 
     let area = frame.area();
     let popup = centered_rect(45, 70, area);
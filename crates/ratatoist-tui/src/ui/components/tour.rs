@@ -0,0 +1,71 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap};
+
+use crate::app::App;
+use crate::tour::{STEPS, TourGoal};
+
+const WIDTH: u16 = 52;
+const HEIGHT: u16 = 8;
+/// Statusbar and key hints below the panes.
+const FOOTER: u16 = 2;
+
+/// The current tour card, in the bottom-right corner above the statusbar so
+/// it stays clear of the Projects pane and the dock it may be pointing at.
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(tour) = &app.tour else {
+        return;
+    };
+    let theme = app.theme();
+    let area = frame.area();
+    if area.width < WIDTH + 2 || area.height < HEIGHT + FOOTER + 1 {
+        return;
+    }
+    let rect = Rect::new(
+        area.right() - WIDTH - 2,
+        area.bottom() - HEIGHT - FOOTER - 1,
+        WIDTH,
+        HEIGHT,
+    );
+
+    let step = tour.current();
+    let hint = match step.goal {
+        TourGoal::Read if tour.step == 0 => "Enter start  Esc skip the tour",
+        TourGoal::Read if tour.step + 1 == STEPS.len() => "Enter finish  ← back",
+        TourGoal::Read => "Enter next  ← back  Esc end tour",
+        _ => "Esc skip this step",
+    };
+
+    let block = Block::default()
+        .title(format!(" {} ", step.title))
+        .title_style(theme.active_title())
+        .title_top(
+            Line::from(Span::styled(
+                format!(" {}/{} ", tour.step + 1, STEPS.len()),
+                theme.muted_text(),
+            ))
+            .right_aligned(),
+        )
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.due_today())
+        .padding(Padding::new(2, 2, 1, 0))
+        .style(theme.surface_bg());
+    let inner = block.inner(rect);
+
+    frame.render_widget(Clear, rect);
+    frame.render_widget(block, rect);
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            step.body(&app.input_mode),
+            theme.normal_text(),
+        ))
+        .wrap(Wrap { trim: true }),
+        inner,
+    );
+    frame.render_widget(
+        Paragraph::new(Span::styled(hint, theme.muted_text())).alignment(Alignment::Center),
+        Rect::new(inner.x, inner.bottom() - 1, inner.width, 1),
+    );
+}
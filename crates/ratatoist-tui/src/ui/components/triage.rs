@@ -0,0 +1,83 @@
+use ratatui::Frame;
+use ratatui::layout::Alignment;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+
+use crate::app::App;
+use crate::ui::dates;
+use crate::ui::theme::Theme;
+
+use super::popup::{centered_fixed_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let (Some(triage), Some(task)) = (&app.triage, app.triage_task()) else {
+        return;
+    };
+    let theme = app.theme();
+    render_dim_overlay(frame, theme);
+
+    let popup = centered_fixed_rect(50, 14, frame.area());
+    let block = Block::default()
+        .title(format!(
+            " Overdue {}/{} ",
+            triage.index + 1,
+            triage.task_ids.len()
+        ))
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(2, 2, 1, 0))
+        .style(theme.base_bg());
+
+    let mut title = vec![
+        Span::styled(
+            Theme::priority_dot(task.priority),
+            theme.priority_style(task.priority),
+        ),
+        Span::styled(task.content.as_str(), theme.normal_text()),
+    ];
+    if let Some(due) = &task.due {
+        let formatted = dates::format_due(due, theme);
+        title.push(Span::styled(
+            format!("  {}", formatted.text),
+            formatted.style,
+        ));
+    }
+
+    let option = |key: &'static str, label: &'static str| {
+        Line::from(vec![
+            Span::styled(format!("{key:>5}  "), theme.key_hint()),
+            Span::styled(label, theme.normal_text()),
+        ])
+    };
+
+    let delete = if triage.confirm_delete {
+        Line::from(vec![
+            Span::styled(format!("{:>5}  ", "d"), theme.due_overdue()),
+            Span::styled("press again to delete", theme.due_overdue()),
+        ])
+    } else {
+        option("d", "delete")
+    };
+
+    let lines = vec![
+        Line::from(title),
+        Line::default(),
+        option("t / +", "today / tomorrow"),
+        option("w / W", "next week / weekend"),
+        option("x", "complete"),
+        delete,
+        option("s", "skip"),
+        Line::default(),
+        Line::from(Span::styled("Esc to stop", theme.muted_text())).alignment(Alignment::Center),
+    ];
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        popup,
+    );
+}
@@ -0,0 +1,133 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph};
+
+use crate::app::{App, OverviewRow};
+
+use super::popup::{centered_rect, render_dim_overlay};
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let theme = app.theme();
+    render_dim_overlay(frame, theme);
+
+    let popup = centered_rect(60, 75, frame.area());
+    let block = Block::default()
+        .title(" Workspaces ")
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(1, 1, 1, 0))
+        .style(theme.base_bg());
+
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let [list_area, hint_area] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+
+    let counts = app.project_task_counts();
+    let rows = app.workspace_overview_rows();
+    let mut selected_row = None;
+    let mut project_rows = 0;
+    let mut in_folder = false;
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .enumerate()
+        .map(|(row, entry)| match entry {
+            OverviewRow::Workspace(wi) => {
+                in_folder = false;
+                let workspace = &app.workspaces[*wi];
+                let mut details = Vec::new();
+                if let Some(plan) = &workspace.plan {
+                    details.push(title_case(plan));
+                }
+                if let Some(n) = app.workspace_member_count(workspace) {
+                    details.push(format!("{n} member{}", if n == 1 { "" } else { "s" }));
+                }
+                if let Some(role) = &workspace.role {
+                    details.push(format!("you: {}", role.to_lowercase()));
+                }
+                let mut spans = vec![Span::styled(
+                    workspace.name.clone(),
+                    theme.label_tag().add_modifier(Modifier::BOLD),
+                )];
+                if !details.is_empty() {
+                    spans.push(Span::styled(
+                        format!("  {}", details.join(" · ")),
+                        theme.muted_text(),
+                    ));
+                }
+                let line = Line::from(spans);
+                // Blank line between workspaces, not above the first.
+                if row == 0 {
+                    ListItem::new(line)
+                } else {
+                    ListItem::new(vec![Line::default(), line])
+                }
+            }
+            OverviewRow::Folder(fi) => {
+                in_folder = true;
+                ListItem::new(Line::from(Span::styled(
+                    format!("  ▾ {}", app.folders[*fi].name),
+                    theme.muted_text(),
+                )))
+            }
+            OverviewRow::Project(i) => {
+                if project_rows == app.workspace_overview_selection {
+                    selected_row = Some(row);
+                }
+                project_rows += 1;
+                let project = &app.projects[*i];
+                let indent = if in_folder && project.folder_id.is_some() {
+                    "      "
+                } else {
+                    "    "
+                };
+                let mut spans = vec![
+                    Span::raw(format!(
+                        "{indent}{}",
+                        "  ".repeat(app.project_indent(project))
+                    )),
+                    Span::styled("# ", Style::default().fg(theme.color_for(&project.color))),
+                    Span::styled(project.name.clone(), theme.normal_text()),
+                ];
+                if let Some(c) = counts.get(project.id.as_str()) {
+                    spans.push(Span::styled(format!("  {}", c.active), theme.muted_text()));
+                }
+                ListItem::new(Line::from(spans))
+            }
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(theme.selected_item());
+    let mut state = ListState::default().with_selected(selected_row);
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("j/k", theme.key_hint()),
+            Span::styled(" move  ", theme.muted_text()),
+            Span::styled("Enter", theme.key_hint()),
+            Span::styled(" open project  ", theme.muted_text()),
+            Span::styled("Esc", theme.key_hint()),
+            Span::styled(" close", theme.muted_text()),
+        ]))
+        .alignment(Alignment::Center),
+        hint_area,
+    );
+}
+
+/// `BUSINESS` -> `Business`, `READ_ONLY` -> `Read only`.
+fn title_case(raw: &str) -> String {
+    let lower = raw.replace('_', " ").to_lowercase();
+    let mut chars = lower.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
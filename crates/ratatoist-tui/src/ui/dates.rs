@@ -1,4 +1,7 @@
-use ratatui::style::Style;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use ratatui::style::{Modifier, Style};
 
 use super::theme::Theme;
 use ratatoist_core::api::models::Due;
@@ -9,10 +12,7 @@ pub struct FormattedDue {
 }
 
 pub fn format_due(due: &Due, theme: &Theme) -> FormattedDue {
-    let today = today_str();
-    let date_str = &due.date;
-
-    let days_away = days_between(&today, date_str);
+    let days_away = days_away(today(), due);
 
     if days_away < 0 {
         return FormattedDue {
@@ -22,6 +22,21 @@ pub fn format_due(due: &Due, theme: &Theme) -> FormattedDue {
     }
 
     if days_away == 0 {
+        // Timed tasks due today count down instead, so the next one stands out.
+        if let Some(due_at) = due_local_datetime(due) {
+            let minutes = (due_at - now()).num_minutes();
+            let style = if minutes < 0 {
+                theme.due_overdue()
+            } else if minutes <= IMMINENT_MINUTES {
+                theme.due_today().add_modifier(Modifier::BOLD)
+            } else {
+                theme.due_today()
+            };
+            return FormattedDue {
+                text: relative_label(minutes),
+                style,
+            };
+        }
         return FormattedDue {
             text: display_label(due, days_away),
             style: theme.due_today(),
@@ -42,86 +57,208 @@ pub fn format_due(due: &Due, theme: &Theme) -> FormattedDue {
 }
 
 fn display_label(due: &Due, days_away: i64) -> String {
+    // A due string is what the user typed, so it already names any time.
     if let Some(s) = &due.string
         && !s.is_empty()
     {
         return s.clone();
     }
 
-    match days_away {
+    let day = match days_away {
         0 => "today".to_string(),
         1 => "tomorrow".to_string(),
         -1 => "yesterday".to_string(),
-        _ => format_short_date(&due.date),
+        _ => due_date(due)
+            .map(|d| d.format("%b %-d").to_string())
+            .unwrap_or_else(|| due.date.clone()),
+    };
+    match due_time(due) {
+        Some(time) => format!("{day} {}", time.format("%H:%M")),
+        None => day,
+    }
+}
+
+/// Time of day a due is set for, if it has one. Todoist sends timed dues as
+/// `YYYY-MM-DDTHH:MM:SS` (floating) or with a trailing `Z` when pinned to a
+/// timezone; the latter is shown in the account's zone.
+pub fn due_time(due: &Due) -> Option<NaiveTime> {
+    due_local_datetime(due).map(|dt| dt.time())
+}
+
+/// Calendar day a task is due, in the account's zone. All-day and floating
+/// dues are taken as written; dues pinned to a timezone are instants, so the
+/// day they fall on is worked out in [`zone`].
+pub fn due_date(due: &Due) -> Option<NaiveDate> {
+    match due_local_datetime(due) {
+        Some(dt) => Some(dt.date()),
+        None => NaiveDate::parse_from_str(date_part(&due.date), "%Y-%m-%d").ok(),
     }
 }
 
+/// Signed whole days from `today` to the due day; unparseable dates sort as
+/// far-future.
+fn days_away(today: NaiveDate, due: &Due) -> i64 {
+    due_date(due).map(|d| (d - today).num_days()).unwrap_or(999)
+}
+
+fn due_local_datetime(due: &Due) -> Option<NaiveDateTime> {
+    let raw = due
+        .datetime
+        .as_deref()
+        .or_else(|| due.date.contains('T').then_some(due.date.as_str()))?;
+    parse_due_datetime(raw, due.timezone.is_some())
+}
+
+/// Tasks due within this many minutes are drawn in bold.
+const IMMINENT_MINUTES: i64 = 60;
+
+/// "in 2h", "in 25m", "now", "25m ago", "3h ago" for a signed minute offset.
+fn relative_label(minutes: i64) -> String {
+    let abs = minutes.abs();
+    let amount = if abs < 60 {
+        format!("{abs}m")
+    } else {
+        format!("{}h", abs / 60)
+    };
+    match minutes {
+        0 => "now".to_string(),
+        m if m > 0 => format!("in {amount}"),
+        _ => format!("{amount} ago"),
+    }
+}
+
+/// Todoist sends a due with a `timezone` as a UTC instant (normally with a
+/// trailing `Z`); without one the time is floating and read as written.
+fn parse_due_datetime(raw: &str, has_timezone: bool) -> Option<NaiveDateTime> {
+    if let Ok(instant) = DateTime::parse_from_rfc3339(raw) {
+        return Some(instant.with_timezone(&zone()).naive_local());
+    }
+    let naive = NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S").ok()?;
+    if has_timezone {
+        return Some(naive.and_utc().with_timezone(&zone()).naive_local());
+    }
+    Some(naive)
+}
+
+/// Ordering key for due-date sorting: by day, then by time within the day,
+/// with all-day tasks ahead of timed ones.
+pub fn due_sort_key(due: &Due) -> (Option<NaiveDate>, Option<NaiveTime>) {
+    (due_date(due), due_time(due))
+}
+
 /// The calendar-date portion of a Todoist due date, which may be a bare
 /// `YYYY-MM-DD` or a full `YYYY-MM-DDTHH:MM:SS` timestamp.
 pub fn date_part(due_date: &str) -> &str {
     due_date.split('T').next().unwrap_or(due_date)
 }
 
-pub fn today_str() -> String {
-    chrono::Local::now()
-        .date_naive()
-        .format("%Y-%m-%d")
-        .to_string()
+/// A record timestamp such as `added_at` or `completed_at` as
+/// `YYYY-MM-DD HH:MM` in [`zone`]; bare dates and anything unparseable are
+/// shown as sent.
+pub fn format_timestamp(raw: &str) -> String {
+    match DateTime::parse_from_rfc3339(raw) {
+        Ok(instant) => instant
+            .with_timezone(&zone())
+            .format("%Y-%m-%d %H:%M")
+            .to_string(),
+        Err(_) => raw.to_string(),
+    }
 }
 
-pub fn offset_days_str(days: i64) -> String {
-    let today = chrono::Local::now().date_naive();
-    (today + chrono::Duration::days(days))
-        .format("%Y-%m-%d")
-        .to_string()
+/// Seconds east of UTC of the Todoist account's timezone once the user
+/// record has synced; `i32::MIN` until then, meaning the system's zone.
+static ACCOUNT_OFFSET: AtomicI32 = AtomicI32::new(i32::MIN);
+
+pub fn set_account_offset(secs: Option<i32>) {
+    ACCOUNT_OFFSET.store(secs.unwrap_or(i32::MIN), Ordering::Relaxed);
 }
 
-fn parse_date(s: &str) -> Option<(i32, u32, u32)> {
-    let parts: Vec<&str> = s.split('-').collect();
-    if parts.len() != 3 {
-        return None;
+/// The zone every date calculation runs in: the account's, else the
+/// system's.
+pub fn zone() -> FixedOffset {
+    match ACCOUNT_OFFSET.load(Ordering::Relaxed) {
+        i32::MIN => *Local::now().offset(),
+        secs => FixedOffset::east_opt(secs).unwrap_or_else(|| *Local::now().offset()),
     }
-    Some((
-        parts[0].parse().ok()?,
-        parts[1].parse().ok()?,
-        parts[2].parse().ok()?,
-    ))
 }
 
-fn days_between(a: &str, b: &str) -> i64 {
-    let da = parse_date(a).map(|(y, m, d)| days_from_civil(y, m, d));
-    let db = parse_date(b).map(|(y, m, d)| days_from_civil(y, m, d));
-    match (da, db) {
-        (Some(a), Some(b)) => b - a,
-        _ => 999,
-    }
+/// Wall-clock time in [`zone`].
+pub fn now() -> NaiveDateTime {
+    Utc::now().with_timezone(&zone()).naive_local()
 }
 
-fn format_short_date(date_str: &str) -> String {
-    let Some((_, m, d)) = parse_date(date_str) else {
-        return date_str.to_string();
+pub fn today() -> NaiveDate {
+    now().date()
+}
+
+/// The weeks of `date`'s month, Monday first, with `None` padding the days
+/// that belong to the neighbouring months.
+pub fn month_weeks(date: NaiveDate) -> Vec<[Option<NaiveDate>; 7]> {
+    let Some(first) = date.with_day(1) else {
+        return Vec::new();
     };
-    let months = [
-        "", "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
-    ];
-    let month = months.get(m as usize).unwrap_or(&"???");
-    format!("{month} {d}")
+    let mut weeks = Vec::new();
+    let mut week = [None; 7];
+    let mut day = first;
+    while day.month() == first.month() {
+        let col = day.weekday().num_days_from_monday() as usize;
+        week[col] = Some(day);
+        if col == 6 {
+            weeks.push(std::mem::take(&mut week));
+        }
+        day = day.succ_opt().unwrap_or(day);
+        if day == first {
+            break;
+        }
+    }
+    if week.iter().any(Option::is_some) {
+        weeks.push(week);
+    }
+    weeks
+}
+
+/// Quick-reschedule targets. `due_string` is the phrase Todoist itself
+/// parses; `target_date` mirrors it locally for the optimistic update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Postpone {
+    Today,
+    Tomorrow,
+    NextWeek,
+    Weekend,
 }
 
-fn days_from_civil(y: i32, m: u32, d: u32) -> i64 {
-    let y = if m <= 2 { y as i64 - 1 } else { y as i64 };
-    let era = y.div_euclid(400);
-    let yoe = y.rem_euclid(400) as u64;
-    let m = m as u64;
-    let d = d as u64;
-    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
-    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
-    era * 146097 + doe as i64 - 719468
+impl Postpone {
+    pub fn due_string(self) -> &'static str {
+        match self {
+            Self::Today => "today",
+            Self::Tomorrow => "tomorrow",
+            Self::NextWeek => "next week",
+            Self::Weekend => "weekend",
+        }
+    }
+
+    /// Next week is the coming Monday; the weekend is the next Saturday,
+    /// or today when it already is Saturday.
+    pub fn target_date(self, today: NaiveDate) -> NaiveDate {
+        let from_monday = today.weekday().num_days_from_monday() as i64;
+        let offset = match self {
+            Self::Today => 0,
+            Self::Tomorrow => 1,
+            Self::NextWeek => 7 - from_monday,
+            Self::Weekend => (5 - from_monday).rem_euclid(7),
+        };
+        today + chrono::Duration::days(offset)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{date_part, days_between, days_from_civil};
+    use super::{
+        Postpone, date_part, days_away, due_date, due_sort_key, due_time, format_timestamp,
+        month_weeks, relative_label,
+    };
+    use chrono::{NaiveDate, NaiveTime};
+    use ratatoist_core::api::models::Due;
 
     #[test]
     fn date_part_strips_time_suffix() {
@@ -130,33 +267,131 @@ mod tests {
         assert_eq!(date_part(""), "");
     }
 
+    fn all_day(date: &str) -> Due {
+        Due {
+            date: date.to_string(),
+            ..Default::default()
+        }
+    }
+
     #[test]
-    fn civil_days_anchor_at_unix_epoch() {
-        assert_eq!(days_from_civil(1970, 1, 1), 0);
-        assert_eq!(days_from_civil(1970, 1, 2), 1);
-        assert_eq!(days_from_civil(1969, 12, 31), -1);
+    fn days_away_is_signed_due_minus_today() {
+        let today = NaiveDate::from_ymd_opt(2026, 6, 15).unwrap();
+        assert_eq!(days_away(today, &all_day("2026-06-15")), 0);
+        assert_eq!(days_away(today, &all_day("2026-06-16")), 1);
+        assert_eq!(days_away(today, &all_day("2026-06-14")), -1);
+        assert_eq!(days_away(today, &all_day("2027-06-15")), 365);
+        assert_eq!(days_away(today, &all_day("2026-06-15T23:30:00")), 0);
     }
 
     #[test]
-    fn civil_days_known_value_and_leap_day() {
-        assert_eq!(days_from_civil(2000, 1, 1), 10957);
-        let feb28 = days_from_civil(2020, 2, 28);
-        let mar1 = days_from_civil(2020, 3, 1);
-        assert_eq!(mar1 - feb28, 2);
+    fn days_away_returns_sentinel_on_malformed() {
+        let today = NaiveDate::from_ymd_opt(2026, 6, 15).unwrap();
+        assert_eq!(days_away(today, &all_day("not-a-date")), 999);
+        assert_eq!(days_away(today, &all_day("2026/06/15")), 999);
+        assert_eq!(days_away(today, &all_day("2026-06")), 999);
+    }
+
+    #[test]
+    fn zoned_dues_are_read_as_utc_instants() {
+        let zoned = Due {
+            date: "2026-06-16T12:00:00Z".to_string(),
+            timezone: Some("Europe/Madrid".to_string()),
+            ..Default::default()
+        };
+        let without_z = Due {
+            date: "2026-06-16T12:00:00".to_string(),
+            timezone: Some("Europe/Madrid".to_string()),
+            ..Default::default()
+        };
+        let expected = NaiveDate::from_ymd_opt(2026, 6, 16)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_utc()
+            .with_timezone(&chrono::Local)
+            .naive_local();
+        assert_eq!(due_date(&zoned), Some(expected.date()));
+        assert_eq!(due_time(&zoned), Some(expected.time()));
+        assert_eq!(due_time(&without_z), Some(expected.time()));
     }
 
     #[test]
-    fn days_between_is_signed_target_minus_today() {
-        assert_eq!(days_between("2026-06-15", "2026-06-15"), 0);
-        assert_eq!(days_between("2026-06-15", "2026-06-16"), 1);
-        assert_eq!(days_between("2026-06-15", "2026-06-14"), -1);
-        assert_eq!(days_between("2026-06-15", "2027-06-15"), 365);
+    fn timestamps_are_shown_in_the_local_zone() {
+        let expected = NaiveDate::from_ymd_opt(2026, 6, 16)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_utc()
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string();
+        assert_eq!(format_timestamp("2026-06-16T12:00:00.123456Z"), expected);
+        assert_eq!(format_timestamp("2026-06-16"), "2026-06-16");
+    }
+
+    #[test]
+    fn month_weeks_start_on_monday() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        // June 2026 starts on a Monday and ends on a Tuesday.
+        let june = month_weeks(date(6, 17));
+        assert_eq!(june.len(), 5);
+        assert_eq!(june[0][0], Some(date(6, 1)));
+        assert_eq!(june[4][1], Some(date(6, 30)));
+        assert_eq!(june[4][2], None);
+        // March 2026 starts on a Sunday.
+        let march = month_weeks(date(3, 31));
+        assert_eq!(
+            march[0],
+            [None, None, None, None, None, None, Some(date(3, 1))]
+        );
+        assert_eq!(march.len(), 6);
+    }
+
+    #[test]
+    fn postpone_targets_follow_the_calendar() {
+        let date = |d| NaiveDate::from_ymd_opt(2026, 6, d).unwrap();
+        // 2026-06-17 is a Wednesday.
+        assert_eq!(Postpone::Today.target_date(date(17)), date(17));
+        assert_eq!(Postpone::Tomorrow.target_date(date(17)), date(18));
+        assert_eq!(Postpone::NextWeek.target_date(date(17)), date(22));
+        assert_eq!(Postpone::Weekend.target_date(date(17)), date(20));
+        // Saturday stays put for "weekend"; Sunday rolls to the next one.
+        assert_eq!(Postpone::Weekend.target_date(date(20)), date(20));
+        assert_eq!(Postpone::Weekend.target_date(date(21)), date(27));
+        assert_eq!(Postpone::NextWeek.target_date(date(21)), date(22));
+    }
+
+    #[test]
+    fn due_time_reads_floating_datetimes_and_ignores_all_day() {
+        let timed = Due {
+            date: "2026-06-16T09:30:00".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(due_time(&timed), NaiveTime::from_hms_opt(9, 30, 0));
+
+        let via_field = Due {
+            date: "2026-06-16".to_string(),
+            datetime: Some("2026-06-16T23:00:00".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(due_time(&via_field), NaiveTime::from_hms_opt(23, 0, 0));
+
+        let all_day = Due {
+            date: "2026-06-16".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(due_time(&all_day), None);
+        assert!(due_sort_key(&all_day) < due_sort_key(&timed));
+        assert!(due_sort_key(&timed) < due_sort_key(&via_field));
     }
 
     #[test]
-    fn days_between_returns_sentinel_on_malformed() {
-        assert_eq!(days_between("not-a-date", "2026-06-15"), 999);
-        assert_eq!(days_between("2026/06/15", "2026-06-15"), 999);
-        assert_eq!(days_between("2026-06", "2026-06-15"), 999);
+    fn relative_labels_round_down_to_minutes_then_hours() {
+        assert_eq!(relative_label(0), "now");
+        assert_eq!(relative_label(25), "in 25m");
+        assert_eq!(relative_label(-25), "25m ago");
+        assert_eq!(relative_label(150), "in 2h");
+        assert_eq!(relative_label(-60), "1h ago");
     }
 }
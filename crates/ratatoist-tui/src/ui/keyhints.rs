@@ -3,13 +3,19 @@ use ratatui::layout::Rect;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
-use crate::app::{App, DOCK_ITEMS, InputMode, Pane};
+use crate::app::{App, DockItem, InputMode, KeyHints, Pane};
+
+/// Hints kept in compact mode; each context lists its most relevant first.
+const COMPACT_HINTS: usize = 4;
 
 pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let theme = app.theme();
 
     if let Some(idx) = app.dock_focus {
-        let item = DOCK_ITEMS[idx];
+        let hint = app
+            .dock_items()
+            .get(idx)
+            .map_or_else(String::new, |&item| app.dock_hint(item));
         let mut spans: Vec<Span> = Vec::new();
         spans.push(Span::styled(" ", theme.muted_text()));
         spans.push(Span::styled("h/l", theme.key_hint()));
@@ -18,16 +24,34 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
         spans.push(Span::styled(" filter  ", theme.muted_text()));
         spans.push(Span::styled("Esc", theme.key_hint()));
         spans.push(Span::styled(" clear  ", theme.muted_text()));
-        spans.push(Span::styled(
-            format!("→ {}", item.hint()),
-            theme.active_title(),
-        ));
+        spans.push(Span::styled(format!("→ {hint}"), theme.active_title()));
         let bar = Paragraph::new(Line::from(spans)).style(theme.base_bg());
         frame.render_widget(bar, area);
         return;
     }
 
     let hints = match (&app.input_mode, &app.active_pane) {
+        _ if app.project_filter.is_some() => vec![
+            ("type", "filter projects"),
+            ("Enter", "jump to first match"),
+            ("Esc", "clear"),
+        ],
+        _ if app.task_search.as_ref().is_some_and(|s| s.editing) => vec![
+            ("type", "search tasks"),
+            ("Enter", "find"),
+            ("Esc", "cancel"),
+        ],
+        (_, Pane::Tasks) if app.dock_filter == Some(DockItem::DueOverdue) => vec![
+            ("j/k", "navigate"),
+            ("T", "reschedule all to today"),
+            ("t/+/w/W", "postpone"),
+            ("Esc", "clear filter"),
+        ],
+        (_, Pane::Tasks) if app.task_search.is_some() => vec![
+            ("n", "next match"),
+            ("N", "previous match"),
+            ("Esc", "clear search"),
+        ],
         (_, Pane::StatsDock) => vec![("h/l", "navigate"), ("Enter", "filter"), ("Esc", "clear")],
         (_, Pane::Settings) => vec![
             ("j/k", "navigate"),
@@ -35,7 +59,8 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
             ("Esc", "close"),
         ],
         (_, Pane::Detail) => vec![
-            ("j/k", "scroll"),
+            ("j/k", "fields"),
+            ("^d/^u", "comments"),
             ("x", "complete"),
             ("Esc/h", "back"),
             ("?", "help"),
@@ -43,7 +68,8 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
         ],
         (InputMode::Vim(_), Pane::Projects) => vec![
             ("j/k", "navigate"),
-            ("g/G", "top/bottom"),
+            ("gg/G", "top/bottom"),
+            ("/", "filter"),
             ("l/Tab", "tasks"),
             (",", "settings"),
             ("?", "help"),
@@ -78,9 +104,14 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
         ],
     };
 
+    let shown = match app.key_hints {
+        KeyHints::Compact => COMPACT_HINTS,
+        _ => hints.len(),
+    };
+
     let mut spans: Vec<Span> = Vec::new();
     spans.push(Span::styled(" ", theme.muted_text()));
-    for (i, (key, desc)) in hints.iter().enumerate() {
+    for (i, (key, desc)) in hints.iter().take(shown).enumerate() {
         if i > 0 {
             spans.push(Span::styled("  ", theme.muted_text()));
         }
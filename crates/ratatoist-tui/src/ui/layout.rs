@@ -4,9 +4,11 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Padding, Paragraph};
 
-use crate::app::{App, DOCK_ITEMS, DockItem, Pane, SortMode, TaskFilter};
+use crate::app::{App, DockItem, KeyHints, Pane, SortMode, TaskFilter};
+use crate::tour::TourTarget;
 
-const STATS_HEIGHT: u16 = 4;
+/// Borders plus the due, priority and week rows.
+const STATS_HEIGHT: u16 = 5;
 use crate::ui::theme::Theme;
 
 use super::keyhints;
@@ -17,16 +19,20 @@ pub fn render(frame: &mut Frame, app: &App) {
     let theme = app.theme();
     let area = frame.area();
 
+    let hints_height = if app.key_hints == KeyHints::Off { 0 } else { 1 };
     let [main_area, status_area, hints_area] = Layout::vertical([
         Constraint::Min(1),
         Constraint::Length(1),
-        Constraint::Length(1),
+        Constraint::Length(hints_height),
     ])
     .areas(area);
 
-    let [left_area, right_area] =
-        Layout::horizontal([Constraint::Percentage(30), Constraint::Percentage(70)])
-            .areas(main_area);
+    let sidebar_pct = if app.zen_mode { 0 } else { app.sidebar_pct };
+    let [left_area, right_area] = Layout::horizontal([
+        Constraint::Percentage(sidebar_pct),
+        Constraint::Percentage(100 - sidebar_pct),
+    ])
+    .areas(main_area);
 
     let projects_active = matches!(app.active_pane, Pane::Projects);
     let stats_active = matches!(app.active_pane, Pane::StatsDock);
@@ -35,17 +41,17 @@ pub fn render(frame: &mut Frame, app: &App) {
     if app.show_settings {
         let [projects_area, stats_area, settings_area] = Layout::vertical([
             Constraint::Min(1),
-            Constraint::Length(STATS_HEIGHT),
-            Constraint::Length(5),
+            Constraint::Length(stats_height(app)),
+            Constraint::Length(crate::keys::settings_item_count() as u16 + 2),
         ])
         .areas(left_area);
 
         render_projects_block(frame, app, projects_area, projects_active);
         render_stats_block(frame, app, stats_area, stats_active);
         views::settings::render(frame, app, settings_area, settings_active);
-    } else {
+    } else if !app.zen_mode {
         let [projects_area, stats_area] =
-            Layout::vertical([Constraint::Min(1), Constraint::Length(STATS_HEIGHT)])
+            Layout::vertical([Constraint::Min(1), Constraint::Length(stats_height(app))])
                 .areas(left_area);
 
         render_projects_block(frame, app, projects_area, projects_active);
@@ -54,27 +60,51 @@ pub fn render(frame: &mut Frame, app: &App) {
 
     if matches!(app.active_pane, Pane::Detail) {
         if let Some(task) = app.selected_task() {
-            let task = task.clone();
-            let comments = app.comments.clone();
-            views::detail::render(
+            let (max_scroll, drawn_scroll) = views::detail::render(
                 frame,
-                &task,
-                &comments,
+                task,
+                app.projects
+                    .iter()
+                    .find(|p| p.id == task.project_id)
+                    .map_or("", |p| p.name.as_str()),
+                task.section_id
+                    .as_deref()
+                    .and_then(|id| app.sections.iter().find(|s| s.id == id))
+                    .map(|s| s.name.as_str()),
+                &app.task_ancestors(task),
+                &app.task_children(&task.id),
+                &app.comments,
                 &app.user_names,
                 app.current_user_id.as_deref(),
                 right_area,
-                app.detail_scroll,
+                app.comments_scroll,
                 app.detail_field,
+                app.selected_comment,
+                app.local_notes.get(&task.id).map(String::as_str),
+                app.show_history.then(|| app.task_history.get(&task.id)),
                 theme,
             );
+            app.comments_scroll_max
+                .store(max_scroll, std::sync::atomic::Ordering::Relaxed);
+            app.comments_scroll_drawn
+                .store(drawn_scroll, std::sync::atomic::Ordering::Relaxed);
         }
+    } else if app.show_preview {
+        let [tasks_area, preview_area] =
+            Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)])
+                .areas(right_area);
+        let tasks_active = matches!(app.active_pane, Pane::Tasks);
+        render_tasks_block(frame, app, tasks_area, tasks_active);
+        views::preview::render(frame, app, preview_area);
     } else {
         let tasks_active = matches!(app.active_pane, Pane::Tasks);
         render_tasks_block(frame, app, right_area, tasks_active);
     }
 
     statusbar::render(frame, app, status_area);
-    keyhints::render(frame, app, hints_area);
+    if app.key_hints != KeyHints::Off {
+        keyhints::render(frame, app, hints_area);
+    }
 }
 
 fn render_projects_block(frame: &mut Frame, app: &App, area: Rect, active: bool) {
@@ -89,7 +119,9 @@ fn render_projects_block(frame: &mut Frame, app: &App, area: Rect, active: bool)
         })
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(if active {
+        .border_style(if app.tour_target() == Some(TourTarget::Projects) {
+            theme.due_today()
+        } else if active {
             theme.active_border()
         } else {
             theme.inactive_border()
@@ -103,6 +135,11 @@ fn render_projects_block(frame: &mut Frame, app: &App, area: Rect, active: bool)
     views::projects::render(frame, app, inner, active);
 }
 
+/// One more row when the user has pinned tasks or `[dock] filters`.
+fn stats_height(app: &App) -> u16 {
+    STATS_HEIGHT + u16::from(app.has_pinned_row())
+}
+
 fn dock_filter_color(filter: DockItem, theme: &Theme) -> Color {
     match filter {
         DockItem::DueOverdue => theme.red,
@@ -112,6 +149,8 @@ fn dock_filter_color(filter: DockItem, theme: &Theme) -> Color {
         DockItem::Priority(3) => theme.yellow,
         DockItem::Priority(2) => theme.maroon,
         DockItem::Priority(_) => theme.subtle,
+        DockItem::Pinned => theme.orange,
+        DockItem::Custom(_) => theme.purple,
     }
 }
 
@@ -121,10 +160,13 @@ fn render_tasks_block(frame: &mut Frame, app: &App, area: Rect, active: bool) {
     let (title, title_style, border_style) = if let Some(filter) = app.dock_filter {
         let color = dock_filter_color(filter, theme);
         let s = Style::default().fg(color);
-        (format!(" ◈ {} ", filter.hint()), s, s)
+        (format!(" ◈ {} ", app.dock_hint(filter)), s, s)
     } else {
+        let name = app
+            .favorite_view_name()
+            .unwrap_or_else(|| app.selected_project_name().to_string());
         (
-            format!(" {} ", app.selected_project_name()),
+            format!(" {name} "),
             if active {
                 theme.active_title()
             } else {
@@ -138,7 +180,12 @@ fn render_tasks_block(frame: &mut Frame, app: &App, area: Rect, active: bool) {
         )
     };
 
-    let block = Block::default()
+    let border_style = if app.tour_target() == Some(TourTarget::Tasks) {
+        theme.due_today()
+    } else {
+        border_style
+    };
+    let mut block = Block::default()
         .title(title)
         .title_style(title_style)
         .borders(Borders::ALL)
@@ -146,6 +193,9 @@ fn render_tasks_block(frame: &mut Frame, app: &App, area: Rect, active: bool) {
         .border_style(border_style)
         .padding(Padding::horizontal(1))
         .style(theme.base_bg());
+    if app.tabs.len() > 1 {
+        block = block.title_top(tab_strip(app).right_aligned());
+    }
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -160,6 +210,16 @@ fn render_tasks_block(frame: &mut Frame, app: &App, area: Rect, active: bool) {
         render_filter_row(frame, app, filter_area);
         render_filter_banner(frame, app, banner_area);
         views::tasks::render(frame, app, tasks_area, active);
+    } else if app.project_overdue_count() > 0 {
+        let [filter_area, banner_area, tasks_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(1),
+        ])
+        .areas(inner);
+        render_filter_row(frame, app, filter_area);
+        render_overdue_banner(frame, app, banner_area);
+        views::tasks::render(frame, app, tasks_area, active);
     } else {
         let [filter_area, tasks_area] =
             Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(inner);
@@ -168,6 +228,24 @@ fn render_tasks_block(frame: &mut Frame, app: &App, area: Rect, active: bool) {
     }
 }
 
+/// `1 Inbox │ 2 Today`, the active tab highlighted.
+fn tab_strip(app: &App) -> Line<'static> {
+    let theme = app.theme();
+    let mut spans = Vec::new();
+    for (i, title) in app.tab_titles().into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled("│", theme.inactive_border()));
+        }
+        let style = if i == app.active_tab {
+            theme.active_title()
+        } else {
+            theme.muted_text()
+        };
+        spans.push(Span::styled(format!(" {} {title} ", i + 1), style));
+    }
+    Line::from(spans)
+}
+
 fn render_filter_banner(frame: &mut Frame, app: &App, area: Rect) {
     let theme = app.theme();
     let Some(filter) = app.dock_filter else {
@@ -180,7 +258,7 @@ fn render_filter_banner(frame: &mut Frame, app: &App, area: Rect) {
         .add_modifier(Modifier::BOLD);
     let hint = Style::default().fg(color).bg(theme.surface);
     let line = Line::from(vec![
-        Span::styled(format!(" ◈ {}  ", filter.hint()), banner),
+        Span::styled(format!(" ◈ {}  ", app.dock_hint(filter)), banner),
         Span::styled("Esc: clear", hint),
     ]);
     frame.render_widget(
@@ -189,6 +267,23 @@ fn render_filter_banner(frame: &mut Frame, app: &App, area: Rect) {
     );
 }
 
+fn render_overdue_banner(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = app.theme();
+    let line = Line::from(vec![
+        Span::styled(
+            format!(" {} overdue ", app.project_overdue_count()),
+            theme.due_overdue().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("— press ", theme.muted_text()),
+        Span::styled("!!", theme.key_hint()),
+        Span::styled(" to triage", theme.muted_text()),
+    ]);
+    frame.render_widget(
+        Paragraph::new(line).style(Style::default().bg(theme.surface)),
+        area,
+    );
+}
+
 fn render_filter_row(frame: &mut Frame, app: &App, area: Rect) {
     let theme = app.theme();
 
@@ -208,9 +303,10 @@ fn render_filter_row(frame: &mut Frame, app: &App, area: Rect) {
         Span::styled("Both", style_for(TaskFilter::Both)),
     ];
 
-    if app.sort_mode != SortMode::Default {
+    if app.sort_mode != SortMode::Default || app.sort_descending {
+        let arrow = if app.sort_descending { " ↑" } else { "" };
         spans.push(Span::styled(
-            format!("   ⟳ {}", app.sort_mode.label()),
+            format!("   ⟳ {}{arrow}", app.sort_mode.label()),
             theme.due_upcoming(),
         ));
     }
@@ -223,7 +319,10 @@ fn render_stats_block(frame: &mut Frame, app: &App, area: Rect, active: bool) {
     let stats = app.overview_stats();
 
     let title = if let Some(idx) = app.dock_focus {
-        let hint = DOCK_ITEMS[idx].hint();
+        let hint = app
+            .dock_items()
+            .get(idx)
+            .map_or_else(String::new, |&item| app.dock_hint(item));
         format!(" Stats → {hint} ")
     } else {
         " Stats ".to_string()
@@ -238,7 +337,9 @@ fn render_stats_block(frame: &mut Frame, app: &App, area: Rect, active: bool) {
         })
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(if active {
+        .border_style(if app.tour_target() == Some(TourTarget::Dock) {
+            theme.due_today()
+        } else if active {
             theme.active_border()
         } else {
             theme.inactive_border()
@@ -248,8 +349,13 @@ fn render_stats_block(frame: &mut Frame, app: &App, area: Rect, active: bool) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let [due_area, prio_area] =
-        Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(inner);
+    let [due_area, prio_area, custom_area, week_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(u16::from(app.has_pinned_row())),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
 
     let dock_style = |item: DockItem, idx: usize, base: ratatui::style::Style| {
         if app.dock_focus == Some(idx) {
@@ -284,26 +390,55 @@ fn render_stats_block(frame: &mut Frame, app: &App, area: Rect, active: bool) {
     ]);
 
     let p = &stats.by_priority;
+    let marker = app.priority_marker;
     let prio_line = Line::from(vec![
         Span::styled("P    ", theme.muted_text()),
         Span::styled(
-            format!("● {}  ", p[4]),
+            format!("{} {}  ", marker.dock_symbol(4), p[4]),
             dock_style(DockItem::Priority(4), 3, theme.priority_style(4)),
         ),
         Span::styled(
-            format!("● {}  ", p[3]),
+            format!("{} {}  ", marker.dock_symbol(3), p[3]),
             dock_style(DockItem::Priority(3), 4, theme.priority_style(3)),
         ),
         Span::styled(
-            format!("● {}  ", p[2]),
+            format!("{} {}  ", marker.dock_symbol(2), p[2]),
             dock_style(DockItem::Priority(2), 5, theme.priority_style(2)),
         ),
         Span::styled(
-            format!("─ {}", p[1]),
+            format!("{} {}", marker.dock_symbol(1), p[1]),
             dock_style(DockItem::Priority(1), 6, theme.muted_text()),
         ),
     ]);
 
+    let mut custom_spans = vec![Span::styled("⚲    ", theme.muted_text())];
+    let first_custom = app.dock_items().len() - app.custom_dock.len();
+    if !app.pinned.is_empty() {
+        custom_spans.push(Span::styled(
+            format!("⚑ {}", stats.pinned),
+            dock_style(
+                DockItem::Pinned,
+                first_custom - 1,
+                Style::default().fg(theme.orange),
+            ),
+        ));
+    }
+    for (i, (dock, count)) in app.custom_dock.iter().zip(&stats.custom).enumerate() {
+        if i > 0 || !app.pinned.is_empty() {
+            custom_spans.push(Span::raw("  "));
+        }
+        custom_spans.push(Span::styled(
+            format!("{} {count}", dock.name),
+            dock_style(
+                DockItem::Custom(i),
+                first_custom + i,
+                Style::default().fg(theme.purple),
+            ),
+        ));
+    }
+
     frame.render_widget(Paragraph::new(due_line), due_area);
     frame.render_widget(Paragraph::new(prio_line), prio_area);
+    frame.render_widget(Paragraph::new(Line::from(custom_spans)), custom_area);
+    views::overview::render(frame, app, week_area);
 }
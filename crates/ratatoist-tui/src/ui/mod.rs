@@ -2,9 +2,11 @@ pub mod components;
 pub mod dates;
 pub mod keyhints;
 pub mod layout;
+pub mod plain;
 pub mod setup;
 pub mod splash;
 pub mod statusbar;
+pub mod text;
 pub mod theme;
 pub mod views;
 
@@ -16,25 +18,82 @@ pub const LOGO: &str = r#"
 
 use ratatui::Frame;
 
-use crate::app::App;
+use crate::app::{App, JumperPurpose};
 
 pub fn draw(frame: &mut Frame, app: &App) {
+    draw_layers(frame, app);
+    if app.debug_overlay {
+        components::perf_overlay::render(frame, app);
+    }
+    if app.plain_mode {
+        plain::apply(frame.buffer_mut(), app.theme());
+    }
+}
+
+fn draw_layers(frame: &mut Frame, app: &App) {
+    if let Some(session) = &app.focus {
+        views::focus::render(frame, session, app.theme());
+        if let Some(error) = &app.error {
+            components::error_popup::render(frame, error, app.theme());
+        }
+        return;
+    }
+
     layout::render(frame, app);
+    components::tour::render(frame, app);
 
-    if app.show_theme_picker {
+    if app
+        .project_jumper
+        .as_ref()
+        .is_some_and(|j| j.purpose != JumperPurpose::Form)
+    {
+        components::project_jumper::render(frame, app);
+    } else if app.find_replace.is_some() {
+        components::find_replace::render(frame, app);
+    } else if app.label_manager.is_some() {
+        components::labels::render(frame, app);
+    } else if app.show_theme_picker {
         components::theme_picker::render(frame, app);
+    } else if app.show_notifications {
+        components::notifications::render(frame, app);
+    } else if app.show_project_info {
+        components::project_info::render(frame, app);
+    } else if app.recent_panel.is_some() {
+        components::recent::render(frame, app);
+    } else if app.show_workspace_overview {
+        components::workspace_overview::render(frame, app);
     } else if app.show_priority_picker {
         components::priority_picker::render(frame, app.priority_selection, app.theme());
+    } else if app.section_picker.is_some() {
+        components::section_picker::render(frame, app);
     } else if let Some(form) = &app.task_form {
         components::task_form::render(frame, app, form);
     } else if app.show_input {
         components::input_popup::render(frame, app);
     }
 
-    if app.show_help {
-        components::cheatsheet::render(frame, &app.input_mode, app.theme());
+    if app.date_picker.is_some() {
+        components::date_picker::render(frame, app);
+    }
+
+    if app.triage.is_some() {
+        components::triage::render(frame, app);
     }
 
+    if app.recurrence_editor.is_some() {
+        components::recurrence::render(frame, app);
+    }
+
+    if app.show_recurring_prompt {
+        components::recurring_prompt::render(frame, app.theme());
+    }
+
+    if let Some(confirm) = &app.confirm {
+        components::confirm_popup::render(frame, confirm, app.theme());
+    }
+
+    components::cheatsheet::render(frame, app);
+
     if let Some(error) = &app.error {
         components::error_popup::render(frame, error, app.theme());
     }
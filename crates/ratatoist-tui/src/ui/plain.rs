@@ -0,0 +1,68 @@
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+
+use crate::ui::theme::Theme;
+
+/// True when the `NO_COLOR` convention (https://no-color.org) asks for no color.
+pub fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// Rewrites a rendered frame for plain output: box drawing and symbol glyphs
+/// become ASCII and all color is dropped. Highlighted cells (selection, focused
+/// dock item) keep their emphasis as reverse video.
+pub fn apply(buf: &mut Buffer, theme: &Theme) {
+    let (_, dim_bg) = theme.dim_overlay();
+    for cell in buf.content.iter_mut() {
+        let mut chars = cell.symbol().chars();
+        if let (Some(c), None) = (chars.next(), chars.next())
+            && let Some(ascii) = ascii_for(c)
+        {
+            cell.set_char(ascii);
+        }
+        if !matches!(cell.bg, Color::Reset) && cell.bg != theme.base && cell.bg != dim_bg {
+            cell.modifier.insert(Modifier::REVERSED);
+        }
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+    }
+}
+
+fn ascii_for(c: char) -> Option<char> {
+    let ascii = match c {
+        '─' | '━' | '═' | '—' | '–' => '-',
+        '│' | '┃' | '║' => '|',
+        '╭' | '╮' | '╰' | '╯' | '┌' | '┐' | '└' | '┘' | '├' | '┤' | '┬' | '┴' | '┼' => {
+            '+'
+        }
+        '●' | '★' => '*',
+        '○' | '◦' | '◌' | '⊙' => 'o',
+        '◆' | '◈' | '◇' => '+',
+        '▸' | '▶' | '→' => '>',
+        '◂' | '←' => '<',
+        '▾' | '▼' | '↓' => 'v',
+        '▲' | '↑' => '^',
+        '↻' | '⟳' => '@',
+        '⇅' => '=',
+        '✓' | '×' => 'x',
+        '…' | '·' => '.',
+        '█' | '▀' | '▄' | '▏' | '▎' | '▍' | '▌' | '▋' | '▊' | '▉' => '#',
+        '░' | '▒' | '▓' => '.',
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyphs_map_to_ascii() {
+        assert_eq!(ascii_for('╭'), Some('+'));
+        assert_eq!(ascii_for('●'), Some('*'));
+        assert_eq!(ascii_for('▸'), Some('>'));
+        assert_eq!(ascii_for('a'), None);
+        assert_eq!(ascii_for('日'), None);
+    }
+}
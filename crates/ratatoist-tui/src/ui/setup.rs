@@ -6,6 +6,9 @@ use ratatui::widgets::{Block, BorderType, Borders, Padding, Paragraph};
 
 use crate::ui::theme::Theme;
 
+/// Wizard options visible at once; longer lists scroll.
+const CHOICE_ROWS: usize = 10;
+
 pub fn render(
     frame: &mut Frame,
     input: &str,
@@ -46,11 +49,13 @@ pub fn render(
     render_token_form(frame, input, error, validating, theme, center_area);
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn render_alias(
     frame: &mut Frame,
     selected_idx: usize,
     custom_input: &str,
     is_typing: bool,
+    rat_line: &str,
     rc_path: &str,
     status: Option<&str>,
     theme: &Theme,
@@ -90,6 +95,7 @@ pub fn render_alias(
         selected_idx,
         custom_input,
         is_typing,
+        rat_line,
         rc_path,
         status,
         theme,
@@ -155,7 +161,7 @@ fn render_token_form(
 
     let input_line = if input.is_empty() {
         Line::from(Span::styled(
-            "paste token here…",
+            "paste token here (Ctrl-V)…",
             theme.muted_text().add_modifier(Modifier::ITALIC),
         ))
     } else {
@@ -193,6 +199,7 @@ fn render_alias_form(
     selected_idx: usize,
     custom_input: &str,
     is_typing: bool,
+    rat_line: &str,
     rc_path: &str,
     status: Option<&str>,
     theme: &Theme,
@@ -225,7 +232,7 @@ fn render_alias_form(
     .areas(inner);
 
     let options: [(&str, &str); 3] = [
-        ("rat", "alias rat='ratatoist'"),
+        ("rat", rat_line),
         ("custom", "type your own"),
         ("none", "skip"),
     ];
@@ -280,9 +287,106 @@ fn render_alias_form(
     );
 
     let status_line = match status {
-        Some(msg) if msg.starts_with("added") => Line::from(Span::styled(msg, theme.success())),
-        Some(msg) => Line::from(Span::styled(msg, theme.due_overdue())),
+        Some(msg) if msg.starts_with("could not") => {
+            Line::from(Span::styled(msg, theme.due_overdue()))
+        }
+        Some(msg) => Line::from(Span::styled(msg, theme.success())),
         None => Line::default(),
     };
     frame.render_widget(Paragraph::new(status_line), status_area);
 }
+
+/// One onboarding wizard page: a titled list of `(label, description)`
+/// options. Drawn in `theme`, so the theme page previews the highlighted theme.
+pub fn render_choice(
+    frame: &mut Frame,
+    title: &str,
+    options: &[(String, String)],
+    selected_idx: usize,
+    theme: &Theme,
+) {
+    let area = frame.area();
+    frame.render_widget(Block::default().style(theme.base_bg()), area);
+
+    let logo_lines: Vec<&str> = super::LOGO
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .collect();
+    let logo_height = logo_lines.len() as u16;
+
+    let list_height = options.len().min(CHOICE_ROWS) as u16;
+    let [_, logo_area, _, form_area, _] = Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(logo_height),
+        Constraint::Length(2),
+        Constraint::Length(list_height + 6),
+        Constraint::Min(1),
+    ])
+    .areas(area);
+
+    render_logo(frame, &logo_lines, theme, logo_area);
+
+    let form_width = 64u16.min(area.width.saturating_sub(4));
+    let h_pad = area.width.saturating_sub(form_width) / 2;
+    let [_, center_area, _] = Layout::horizontal([
+        Constraint::Length(h_pad),
+        Constraint::Length(form_width),
+        Constraint::Min(0),
+    ])
+    .areas(form_area);
+
+    let block = Block::default()
+        .title(format!(" {title} "))
+        .title_style(theme.active_title())
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(theme.active_border())
+        .padding(Padding::new(2, 2, 1, 1))
+        .style(theme.base_bg());
+    let inner = block.inner(center_area);
+    frame.render_widget(block, center_area);
+
+    let [list_area, _, hint_area] = Layout::vertical([
+        Constraint::Length(list_height),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    // Keep the highlighted row in view when the list is longer than the box.
+    let first = selected_idx.saturating_sub(CHOICE_ROWS - 1);
+    let rows: Vec<Line> = options
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(CHOICE_ROWS)
+        .map(|(i, (label, desc))| {
+            let is_sel = i == selected_idx;
+            let cursor = if is_sel { "▶ " } else { "  " };
+            let label_style = if is_sel {
+                theme.active_title()
+            } else {
+                theme.muted_text()
+            };
+            Line::from(vec![
+                Span::styled(cursor, theme.active_border()),
+                Span::styled(label.as_str(), label_style),
+                Span::styled(format!("  {desc}"), theme.muted_text()),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(rows), list_area);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("j/k", theme.key_hint()),
+            Span::styled("  choose   ", theme.muted_text()),
+            Span::styled("Enter", theme.key_hint()),
+            Span::styled("  confirm   ", theme.muted_text()),
+            Span::styled("Esc", theme.key_hint()),
+            Span::styled("  keep current", theme.muted_text()),
+        ])),
+        hint_area,
+    );
+}
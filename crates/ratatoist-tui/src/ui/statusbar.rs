@@ -2,27 +2,258 @@ use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
+use tracing::warn;
+
+use ratatoist_core::settings::StatusbarConfig;
 
 use crate::app::{App, InputMode, Pane, VimState};
 
+/// One piece of the statusbar, named in the `[statusbar]` config table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment {
+    Mode,
+    Zen,
+    Breadcrumb,
+    Flash,
+    Pending,
+    Clock,
+    Date,
+    SyncAge,
+    Connection,
+    Notifications,
+    Goal,
+    RateLimit,
+}
+
+impl Segment {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "mode" => Some(Segment::Mode),
+            "zen" => Some(Segment::Zen),
+            "breadcrumb" => Some(Segment::Breadcrumb),
+            "flash" => Some(Segment::Flash),
+            "pending" => Some(Segment::Pending),
+            "clock" => Some(Segment::Clock),
+            "date" => Some(Segment::Date),
+            "sync_age" => Some(Segment::SyncAge),
+            "connection" => Some(Segment::Connection),
+            "notifications" => Some(Segment::Notifications),
+            "goal" => Some(Segment::Goal),
+            "rate_limit" => Some(Segment::RateLimit),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusbarLayout {
+    pub left: Vec<Segment>,
+    pub right: Vec<Segment>,
+}
+
+impl Default for StatusbarLayout {
+    fn default() -> Self {
+        Self {
+            left: vec![
+                Segment::Mode,
+                Segment::Zen,
+                Segment::Breadcrumb,
+                Segment::Flash,
+            ],
+            right: vec![
+                Segment::RateLimit,
+                Segment::Goal,
+                Segment::Notifications,
+                Segment::Connection,
+            ],
+        }
+    }
+}
+
+impl StatusbarLayout {
+    pub fn from_config(config: &StatusbarConfig) -> Self {
+        let default = Self::default();
+        Self {
+            left: parse_segments(config.left.as_deref()).unwrap_or(default.left),
+            right: parse_segments(config.right.as_deref()).unwrap_or(default.right),
+        }
+    }
+}
+
+fn parse_segments(names: Option<&[String]>) -> Option<Vec<Segment>> {
+    let names = names?;
+    Some(
+        names
+            .iter()
+            .filter_map(|name| {
+                let segment = Segment::from_name(name);
+                if segment.is_none() {
+                    warn!(segment = %name, "unknown statusbar segment, skipping");
+                }
+                segment
+            })
+            .collect(),
+    )
+}
+
 pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let theme = app.theme();
 
-    let mode_style = match app.input_mode {
-        InputMode::Vim(VimState::Normal) => theme.mode_normal(),
-        InputMode::Vim(VimState::Visual) => theme.mode_visual(),
-        InputMode::Vim(VimState::Insert) => theme.mode_insert(),
-        InputMode::Standard => theme.mode_standard(),
+    let left_spans: Vec<Span> = app
+        .statusbar
+        .left
+        .iter()
+        .flat_map(|&segment| segment_spans(app, segment))
+        .collect();
+
+    // The Clock setting pins date and time to the far right unless the
+    // configured layout already places the clock.
+    let mut right = app.statusbar.right.clone();
+    if app.show_clock && !right.contains(&Segment::Clock) {
+        right.extend([Segment::Date, Segment::Clock]);
+    }
+
+    let mut right_spans: Vec<Span> = Vec::new();
+    for spans in right
+        .iter()
+        .map(|&segment| segment_spans(app, segment))
+        .filter(|spans| !spans.is_empty())
+    {
+        if !right_spans.is_empty() {
+            right_spans.push(Span::styled(" ", theme.muted_text()));
+        }
+        right_spans.extend(spans);
+    }
+    let right_line = Line::from(right_spans);
+    let right_width = right_line.width() as u16;
+
+    let [left, right] =
+        Layout::horizontal([Constraint::Min(0), Constraint::Length(right_width)]).areas(area);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(left_spans)).style(theme.surface_bg()),
+        left,
+    );
+    frame.render_widget(Paragraph::new(right_line).style(theme.surface_bg()), right);
+}
+
+fn segment_spans(app: &App, segment: Segment) -> Vec<Span<'static>> {
+    let theme = app.theme();
+    match segment {
+        Segment::Mode => {
+            let mode_style = match app.input_mode {
+                InputMode::Vim(VimState::Normal) => theme.mode_normal(),
+                InputMode::Vim(VimState::Visual) => theme.mode_visual(),
+                InputMode::Vim(VimState::Insert) => theme.mode_insert(),
+                InputMode::Standard => theme.mode_standard(),
+            };
+            vec![Span::styled(
+                format!(" {} ", app.input_mode.label()),
+                mode_style,
+            )]
+        }
+        Segment::Zen if app.zen_mode => vec![Span::styled(" ZEN ", theme.mode_visual())],
+        Segment::Zen => Vec::new(),
+        Segment::Breadcrumb => vec![Span::styled(breadcrumb(app), theme.subtle_text())],
+        Segment::Flash => app
+            .flash_message()
+            .map(|msg| vec![Span::styled(format!("  {msg}"), theme.success())])
+            .unwrap_or_default(),
+        Segment::Pending => match app.pending_op_count() {
+            0 => Vec::new(),
+            n => vec![Span::styled(format!("⇅ {n} pending "), theme.due_today())],
+        },
+        Segment::Notifications => match app.unread_notification_count() {
+            0 => Vec::new(),
+            n => vec![Span::styled(format!("✉ {n} "), theme.due_upcoming())],
+        },
+        Segment::Goal => goal_spans(app),
+        Segment::RateLimit => rate_limit_spans(app),
+        Segment::Clock => vec![Span::styled(
+            format!("{} ", super::dates::now().format("%H:%M")),
+            theme.subtle_text(),
+        )],
+        Segment::Date => vec![Span::styled(
+            format!("{} ", super::dates::now().format("%a %d %b")),
+            theme.muted_text(),
+        )],
+        Segment::SyncAge => vec![Span::styled(
+            format!("synced {} ", sync_age(app)),
+            theme.muted_text(),
+        )],
+        Segment::Connection => {
+            let (ws_dot, ws_label, dot_style) = if app.websocket_connected {
+                if app.is_idle() {
+                    (
+                        "◌",
+                        format!("Idle (last sync @ {})", app.sync_age_label()),
+                        theme.muted_text(),
+                    )
+                } else {
+                    ("●", "Connected".to_string(), theme.success())
+                }
+            } else {
+                ("○", "Offline".to_string(), theme.muted_text())
+            };
+            vec![
+                Span::styled(ws_label, theme.muted_text()),
+                Span::styled(format!(" {ws_dot} "), dot_style),
+            ]
+        }
+    }
+}
+
+/// Shown only when the request budget is low: `⚠ 12/450 req`, or once it
+/// is spent, when it refills.
+fn rate_limit_spans(app: &App) -> Vec<Span<'static>> {
+    let theme = app.theme();
+    let Some(budget) = app.rate_budget().filter(|b| b.is_low()) else {
+        return Vec::new();
     };
+    if budget.remaining == 0 {
+        let label = match budget.resets_in {
+            Some(wait) => format!("⚠ rate limited {}s ", wait.as_secs()),
+            None => "⚠ rate limited ".to_string(),
+        };
+        return vec![Span::styled(label, theme.due_overdue())];
+    }
+    vec![Span::styled(
+        format!("⚠ {}/{} req ", budget.remaining, budget.limit),
+        theme.due_today(),
+    )]
+}
 
-    let mode_label = format!(" {} ", app.input_mode.label());
+/// `3/5 today 🔥12`: completions against the daily goal, then the streak.
+fn goal_spans(app: &App) -> Vec<Span<'static>> {
+    let theme = app.theme();
+    let Some(done) = app.completed_today() else {
+        return Vec::new();
+    };
+    let mut spans = match app.daily_goal() {
+        Some(goal) => {
+            let style = if done >= goal {
+                theme.success()
+            } else {
+                theme.muted_text()
+            };
+            vec![Span::styled(format!("{done}/{goal} today "), style)]
+        }
+        None => vec![Span::styled(format!("{done} today "), theme.muted_text())],
+    };
+    if let Some(streak) = app.daily_streak().filter(|s| *s > 0) {
+        spans.push(Span::styled(format!("🔥{streak} "), theme.due_today()));
+    }
+    spans
+}
 
+fn breadcrumb(app: &App) -> String {
     let project_name = app.selected_project_name();
-    let task_count = app.visible_tasks().len();
-
-    let breadcrumb = match app.active_pane {
+    match app.active_pane {
         Pane::Projects => format!("  {project_name}"),
-        Pane::Tasks => format!("  {project_name} ▸ {task_count} tasks"),
+        Pane::Tasks => {
+            let task_count = app.visible_tasks().len();
+            format!("  {project_name} ▸ {task_count} tasks")
+        }
         Pane::Detail => {
             let task_name = app
                 .selected_task()
@@ -32,43 +263,33 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
         }
         Pane::Settings => "  Settings".to_string(),
         Pane::StatsDock => format!("  {project_name} ▸ weekly progress"),
-    };
+    }
+}
 
-    let (ws_dot, ws_label, dot_style) = if app.websocket_connected {
-        if app.is_idle() {
-            (
-                "◌",
-                format!("Idle (last sync @ {})", app.sync_age_label()),
-                theme.muted_text(),
-            )
-        } else {
-            ("●", "Connected".to_string(), theme.success())
-        }
-    } else {
-        ("○", "Offline".to_string(), theme.muted_text())
+fn sync_age(app: &App) -> String {
+    let Some(at) = app.last_sync_at else {
+        return "never".to_string();
     };
+    let mins = (chrono::Local::now() - at).num_minutes();
+    match mins {
+        ..1 => "just now".to_string(),
+        1..60 => format!("{mins}m ago"),
+        _ => format!("{}h ago", mins / 60),
+    }
+}
 
-    let status_str = format!("{ws_label} {ws_dot} ");
-    let status_width = status_str.chars().count() as u16;
-
-    let [left, right] =
-        Layout::horizontal([Constraint::Min(0), Constraint::Length(status_width)]).areas(area);
-
-    let spans = vec![
-        Span::styled(mode_label, mode_style),
-        Span::styled(breadcrumb, theme.subtle_text()),
-    ];
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    frame.render_widget(
-        Paragraph::new(Line::from(spans)).style(theme.surface_bg()),
-        left,
-    );
-    frame.render_widget(
-        Paragraph::new(Line::from(vec![
-            Span::styled(ws_label, theme.muted_text()),
-            Span::styled(format!(" {ws_dot} "), dot_style),
-        ]))
-        .style(theme.surface_bg()),
-        right,
-    );
+    #[test]
+    fn statusbar_layout_from_config() {
+        let config = StatusbarConfig {
+            left: Some(vec!["mode".into(), "bogus".into(), "breadcrumb".into()]),
+            right: None,
+        };
+        let layout = StatusbarLayout::from_config(&config);
+        assert_eq!(layout.left, vec![Segment::Mode, Segment::Breadcrumb]);
+        assert_eq!(layout.right, StatusbarLayout::default().right);
+    }
 }
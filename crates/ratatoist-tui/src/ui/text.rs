@@ -0,0 +1,238 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Cuts `s` to at most `max` display columns, ending in `…` when shortened.
+pub fn truncate_to_width(s: &str, max: usize) -> String {
+    if s.width() <= max {
+        return s.to_string();
+    }
+    if max == 0 {
+        return String::new();
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > max - 1 {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.truncate(out.trim_end().len());
+    out.push('…');
+    out
+}
+
+/// Greedy word wrap to `max` display columns. Words wider than a full line are
+/// hard-split so no returned line ever exceeds `max`.
+pub fn wrap_to_width(s: &str, max: usize) -> Vec<String> {
+    let max = max.max(1);
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut current_w = 0;
+
+    for word in s.split_whitespace() {
+        let word_w = word.width();
+        let sep = usize::from(!current.is_empty());
+        if current_w + sep + word_w <= max {
+            if sep == 1 {
+                current.push(' ');
+            }
+            current.push_str(word);
+            current_w += sep + word_w;
+            continue;
+        }
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+            current_w = 0;
+        }
+        for c in word.chars() {
+            let w = c.width().unwrap_or(0);
+            if current_w + w > max {
+                lines.push(std::mem::take(&mut current));
+                current_w = 0;
+            }
+            current.push(c);
+            current_w += w;
+        }
+    }
+
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Case-insensitive subsequence match of `query` against `candidate`. Returns
+/// `None` when some query character is missing; otherwise a score where higher
+/// is better — consecutive runs and matches at word starts are rewarded, gaps
+/// are penalised.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let mut score = 0i64;
+    let mut qi = 0;
+    let mut prev_match: Option<usize> = None;
+    let mut prev_char: Option<char> = None;
+
+    for (ci, c) in candidate.chars().enumerate() {
+        if qi == query.len() {
+            break;
+        }
+        let lc = c.to_lowercase().next().unwrap_or(c);
+        if lc == query[qi] {
+            score += 1;
+            if prev_match.is_some_and(|p| p + 1 == ci) {
+                score += 5;
+            }
+            if prev_char.is_none_or(|p| !p.is_alphanumeric()) {
+                score += 8;
+            }
+            if let Some(p) = prev_match {
+                score -= (ci - p - 1).min(5) as i64;
+            }
+            prev_match = Some(ci);
+            qi += 1;
+        }
+        prev_char = Some(c);
+    }
+
+    (qi == query.len()).then_some(score)
+}
+
+/// `http(s)://` URLs in `s`, in order. A URL ends at whitespace or a closing
+/// bracket/quote, and trailing sentence punctuation is dropped, so markdown
+/// links and URLs at the end of a sentence come out clean.
+pub fn extract_urls(s: &str) -> Vec<&str> {
+    let mut urls = Vec::new();
+    let mut rest = s;
+    while let Some(start) = ["https://", "http://"]
+        .iter()
+        .filter_map(|scheme| rest.find(scheme))
+        .min()
+    {
+        let tail = &rest[start..];
+        let end = tail
+            .find(|c: char| c.is_whitespace() || matches!(c, ')' | ']' | '>' | '"' | '\''))
+            .unwrap_or(tail.len());
+        let url = tail[..end].trim_end_matches(['.', ',', ';', ':', '!', '?']);
+        if url.len() > "https://".len() {
+            urls.push(url);
+        }
+        rest = &tail[end.max(1)..];
+    }
+    urls
+}
+
+/// `line` without its leading bullet (`-`, `*`, `•`, `+`, `1.`, `1)`) and/or
+/// checkbox (`[ ]`, `[x]`), or `None` when it has neither.
+pub fn strip_list_marker(line: &str) -> Option<&str> {
+    let line = line.trim();
+    let numbered = line
+        .find(|c: char| !c.is_ascii_digit())
+        .filter(|&at| at > 0)
+        .and_then(|at| {
+            line[at..]
+                .strip_prefix(". ")
+                .or_else(|| line[at..].strip_prefix(") "))
+        });
+    let bullet = ["- ", "* ", "• ", "+ "]
+        .iter()
+        .find_map(|m| line.strip_prefix(m))
+        .or(numbered)
+        .map(str::trim_start);
+    let rest = bullet.unwrap_or(line);
+    let checkbox = ["[ ] ", "[x] ", "[X] "]
+        .iter()
+        .find_map(|m| rest.strip_prefix(m));
+    checkbox.or(bullet).map(str::trim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_urls, fuzzy_score, strip_list_marker, truncate_to_width, wrap_to_width};
+
+    #[test]
+    fn truncate_leaves_short_strings_alone() {
+        assert_eq!(truncate_to_width("buy milk", 8), "buy milk");
+        assert_eq!(truncate_to_width("", 4), "");
+    }
+
+    #[test]
+    fn truncate_adds_ellipsis_within_budget() {
+        assert_eq!(truncate_to_width("write the report", 10), "write the…");
+        assert_eq!(truncate_to_width("write the report", 7), "write…");
+        assert_eq!(truncate_to_width("abc", 0), "");
+    }
+
+    #[test]
+    fn truncate_counts_wide_chars_as_two_columns() {
+        assert_eq!(truncate_to_width("日本語テキスト", 5), "日本…");
+    }
+
+    #[test]
+    fn wrap_breaks_on_word_boundaries() {
+        assert_eq!(
+            wrap_to_width("call the plumber about the sink", 12),
+            vec!["call the", "plumber", "about the", "sink"]
+        );
+    }
+
+    #[test]
+    fn wrap_hard_splits_overlong_words() {
+        assert_eq!(wrap_to_width("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn wrap_of_empty_yields_one_empty_line() {
+        assert_eq!(wrap_to_width("", 10), vec![String::new()]);
+    }
+
+    #[test]
+    fn fuzzy_requires_every_query_char_in_order() {
+        assert!(fuzzy_score("wrk", "Work").is_some());
+        assert!(fuzzy_score("WRK", "work").is_some());
+        assert!(fuzzy_score("krw", "Work").is_none());
+        assert!(fuzzy_score("works", "Work").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn fuzzy_prefers_word_starts_and_runs() {
+        let prefix = fuzzy_score("home", "Home / Errands").unwrap();
+        let scattered = fuzzy_score("home", "Shopping / Memo").unwrap();
+        assert!(prefix > scattered);
+
+        let initials = fuzzy_score("gp", "Grocery / Pantry").unwrap();
+        let buried = fuzzy_score("gp", "Signup").unwrap();
+        assert!(initials > buried);
+    }
+
+    #[test]
+    fn urls_are_found_in_order_without_trailing_punctuation() {
+        assert_eq!(
+            extract_urls("see https://example.com/a, then http://x.org/b."),
+            vec!["https://example.com/a", "http://x.org/b"]
+        );
+        assert_eq!(
+            extract_urls("[docs](https://docs.rs/ratatui) <https://a.io>"),
+            vec!["https://docs.rs/ratatui", "https://a.io"]
+        );
+        assert!(extract_urls("no links, just https:// alone").is_empty());
+    }
+
+    #[test]
+    fn list_markers_are_stripped() {
+        assert_eq!(strip_list_marker("- buy milk"), Some("buy milk"));
+        assert_eq!(strip_list_marker("  * [ ] call mom "), Some("call mom"));
+        assert_eq!(strip_list_marker("[x] done"), Some("done"));
+        assert_eq!(strip_list_marker("12. twelfth"), Some("twelfth"));
+        assert_eq!(strip_list_marker("2) second"), Some("second"));
+        assert_eq!(strip_list_marker("plain line"), None);
+        assert_eq!(strip_list_marker("2026 plans"), None);
+        assert_eq!(strip_list_marker("-dash"), None);
+    }
+}
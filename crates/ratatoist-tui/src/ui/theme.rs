@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 use ratatui::style::{Color, Modifier, Style};
 use serde::Deserialize;
 
@@ -64,7 +66,98 @@ fn parse_hex(hex: &str) -> Color {
     let r = u8::from_str_radix(&h[0..2], 16).unwrap_or(0);
     let g = u8::from_str_radix(&h[2..4], 16).unwrap_or(0);
     let b = u8::from_str_radix(&h[4..6], 16).unwrap_or(0);
-    Color::Rgb(r, g, b)
+    match color_depth() {
+        ColorDepth::TrueColor => Color::Rgb(r, g, b),
+        ColorDepth::Ansi256 => Color::Indexed(nearest_ansi256(r, g, b)),
+        ColorDepth::Ansi16 => nearest_ansi16(r, g, b),
+    }
+}
+
+/// How many colors the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+/// Detected once from the environment; themes are quantized to match.
+pub fn color_depth() -> ColorDepth {
+    static DEPTH: OnceLock<ColorDepth> = OnceLock::new();
+    *DEPTH.get_or_init(|| {
+        detect_color_depth(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    })
+}
+
+fn detect_color_depth(colorterm: Option<&str>, term: Option<&str>) -> ColorDepth {
+    if matches!(colorterm, Some("truecolor" | "24bit")) {
+        return ColorDepth::TrueColor;
+    }
+    match term {
+        // No TERM at all is the Windows console, which handles RGB.
+        None => ColorDepth::TrueColor,
+        Some(t) if t.contains("direct") => ColorDepth::TrueColor,
+        Some(t) if t.contains("256") => ColorDepth::Ansi256,
+        Some(_) => ColorDepth::Ansi16,
+    }
+}
+
+/// Nearest entry of the xterm 256-color palette (6×6×6 cube or gray ramp).
+fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let level = |c: u8| {
+        (0..LEVELS.len())
+            .min_by_key(|&i| (LEVELS[i] as i32 - c as i32).abs())
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (LEVELS[ri], LEVELS[gi], LEVELS[bi]);
+    let cube_idx = 16 + 36 * ri + 6 * gi + bi;
+
+    let avg = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_i = ((avg.saturating_sub(8) + 5) / 10).min(23) as u8;
+    let gray = 8 + 10 * gray_i;
+
+    if distance((r, g, b), (gray, gray, gray)) < distance((r, g, b), cube) {
+        232 + gray_i
+    } else {
+        cube_idx as u8
+    }
+}
+
+/// Nearest of the 16 basic ANSI colors, using xterm's default RGB values.
+fn nearest_ansi16(r: u8, g: u8, b: u8) -> Color {
+    const PALETTE: [(Color, (u8, u8, u8)); 16] = [
+        (Color::Black, (0, 0, 0)),
+        (Color::Red, (205, 0, 0)),
+        (Color::Green, (0, 205, 0)),
+        (Color::Yellow, (205, 205, 0)),
+        (Color::Blue, (0, 0, 238)),
+        (Color::Magenta, (205, 0, 205)),
+        (Color::Cyan, (0, 205, 205)),
+        (Color::Gray, (229, 229, 229)),
+        (Color::DarkGray, (127, 127, 127)),
+        (Color::LightRed, (255, 0, 0)),
+        (Color::LightGreen, (0, 255, 0)),
+        (Color::LightYellow, (255, 255, 0)),
+        (Color::LightBlue, (92, 92, 255)),
+        (Color::LightMagenta, (255, 0, 255)),
+        (Color::LightCyan, (0, 255, 255)),
+        (Color::White, (255, 255, 255)),
+    ];
+    PALETTE
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map(|(c, _)| *c)
+        .unwrap_or(Color::Reset)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
 }
 
 /// Maps a Todoist color name to a semantic slot in the active theme.
@@ -232,6 +325,12 @@ impl Theme {
         Style::default().fg(self.red)
     }
 
+    pub fn search_match(&self) -> Style {
+        Style::default()
+            .fg(self.yellow)
+            .add_modifier(Modifier::UNDERLINED)
+    }
+
     pub fn due_today(&self) -> Style {
         Style::default().fg(self.orange)
     }
@@ -312,3 +411,41 @@ impl Theme {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_depth_detection() {
+        assert_eq!(
+            detect_color_depth(Some("truecolor"), Some("xterm-256color")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect_color_depth(None, Some("xterm-256color")),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            detect_color_depth(None, Some("xterm-direct")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(detect_color_depth(None, Some("linux")), ColorDepth::Ansi16);
+        assert_eq!(detect_color_depth(None, None), ColorDepth::TrueColor);
+    }
+
+    #[test]
+    fn quantize_to_256() {
+        assert_eq!(nearest_ansi256(255, 0, 0), 196);
+        assert_eq!(nearest_ansi256(0, 0, 0), 16);
+        assert_eq!(nearest_ansi256(128, 128, 128), 244);
+        assert_eq!(nearest_ansi256(0x1e, 0x1e, 0x2e), 235);
+    }
+
+    #[test]
+    fn quantize_to_16() {
+        assert_eq!(nearest_ansi16(250, 10, 10), Color::LightRed);
+        assert_eq!(nearest_ansi16(30, 30, 40), Color::Black);
+        assert_eq!(nearest_ansi16(120, 120, 130), Color::DarkGray);
+    }
+}
@@ -2,29 +2,50 @@ use std::collections::HashMap;
 
 use chrono::DateTime;
 use ratatui::Frame;
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Layout, Margin, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+use ratatui::widgets::{
+    Block, Borders, Padding, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
+};
 
-use ratatoist_core::api::models::{Comment, Task};
+use ratatoist_core::api::models::{ActivityEvent, Comment, Task};
 
-use crate::app::UserRecord;
-use crate::ui::dates;
+use crate::app::{LOCAL_NOTE_FIELD, SUBTASKS_FIELD, UserRecord};
 use crate::ui::theme::Theme;
+use crate::ui::{dates, text};
+
+/// One `[n]` label per URL on the line, continuing the running count.
+fn link_labels(line: &str, link_no: &mut usize, theme: &Theme) -> Vec<Span<'static>> {
+    text::extract_urls(line)
+        .into_iter()
+        .map(|_| {
+            *link_no += 1;
+            Span::styled(format!(" [{}]", *link_no), theme.key_hint())
+        })
+        .collect()
+}
 
 #[allow(clippy::too_many_arguments)]
 pub fn render(
     frame: &mut Frame,
     task: &Task,
+    project_name: &str,
+    section_name: Option<&str>,
+    ancestors: &[&Task],
+    subtasks: &[&Task],
     comments: &[Comment],
     user_names: &HashMap<String, UserRecord>,
     current_user_id: Option<&str>,
     area: Rect,
     scroll: u16,
     selected_field: usize,
+    selected_comment: Option<usize>,
+    local_note: Option<&str>,
+    // `None` while collapsed; `Some(None)` while the first fetch runs.
+    history: Option<Option<&Result<Vec<ActivityEvent>, String>>>,
     theme: &Theme,
-) {
+) -> (u16, u16) {
     let block = Block::default()
         .title(" Task Detail ")
         .title_style(theme.active_title())
@@ -38,6 +59,17 @@ pub fn render(
     frame.render_widget(block, area);
 
     let mut lines: Vec<Line> = Vec::new();
+    // Numbering must follow `App::detail_links`: description, then comments.
+    let mut link_no = 0usize;
+
+    if !ancestors.is_empty() {
+        let mut crumbs = Vec::new();
+        for ancestor in ancestors {
+            crumbs.push(Span::styled(ancestor.content.as_str(), theme.muted_text()));
+            crumbs.push(Span::styled(" › ", theme.subtle_text()));
+        }
+        lines.push(Line::from(crumbs));
+    }
 
     let content_style = if selected_field == 0 {
         theme.active_title().add_modifier(Modifier::UNDERLINED)
@@ -81,7 +113,11 @@ pub fn render(
     if let Some(due) = &task.due {
         let formatted = dates::format_due(due, theme);
         let recurring_marker = if due.is_recurring { " ↻" } else { "" };
-        let due_display = format!("{}{}  ({})", formatted.text, recurring_marker, due.date);
+        let exact = match (dates::due_date(due), dates::due_time(due)) {
+            (Some(day), Some(time)) => format!("{day} {}", time.format("%H:%M")),
+            _ => due.date.clone(),
+        };
+        let due_display = format!("{}{}  ({exact})", formatted.text, recurring_marker);
         lines.push(Line::from(vec![
             Span::styled("Due       ", due_style),
             Span::styled(due_display, formatted.style),
@@ -95,6 +131,28 @@ pub fn render(
         ]));
     }
 
+    for (field, label, value) in [
+        (3, "Project   ", Some(project_name)),
+        (4, "Section   ", section_name),
+    ] {
+        let active = selected_field == field;
+        lines.push(Line::from(vec![
+            Span::styled(
+                label,
+                if active {
+                    theme.active_title()
+                } else {
+                    theme.muted_text()
+                },
+            ),
+            match value {
+                Some(name) => Span::styled(name.to_string(), theme.normal_text()),
+                None => Span::styled("none", theme.muted_text()),
+            },
+            field_hint(active, theme),
+        ]));
+    }
+
     if task.checked {
         lines.push(Line::from(vec![
             Span::styled("Status    ", theme.muted_text()),
@@ -110,7 +168,38 @@ pub fn render(
         ]));
     }
 
-    let desc_style = if selected_field == 3 {
+    let person = |uid: Option<&str>| match uid {
+        Some(uid) if current_user_id != Some(uid) => {
+            let name = user_names
+                .get(uid)
+                .map(|r| r.display.clone())
+                .unwrap_or_else(|| uid[..uid.len().min(8)].to_string());
+            format!("  by {name}")
+        }
+        _ => String::new(),
+    };
+    let meta = |label: &'static str, at: &str, by: String| {
+        Line::from(vec![
+            Span::styled(label, theme.muted_text()),
+            Span::styled(dates::format_timestamp(at), theme.subtle_text()),
+            Span::styled(by, theme.muted_text()),
+        ])
+    };
+    if let Some(at) = task.added_at.as_deref() {
+        lines.push(meta("Created   ", at, person(task.added_by_uid.as_deref())));
+    }
+    if let Some(at) = task.updated_at.as_deref() {
+        lines.push(meta("Updated   ", at, String::new()));
+    }
+    if let Some(at) = task.completed_at.as_deref() {
+        lines.push(meta(
+            "Completed ",
+            at,
+            person(task.completed_by_uid.as_deref()),
+        ));
+    }
+
+    let desc_style = if selected_field == 5 {
         theme.normal_text().add_modifier(Modifier::UNDERLINED)
     } else {
         theme.normal_text()
@@ -118,48 +207,140 @@ pub fn render(
     lines.push(Line::default());
     lines.push(Line::from(vec![
         Span::styled("Description", theme.subtle_text()),
-        field_hint(selected_field == 3, theme),
+        field_hint(selected_field == 5, theme),
     ]));
     if task.description.is_empty() {
         lines.push(Line::from(Span::styled("(empty)", theme.muted_text())));
     } else {
         for desc_line in task.description.lines() {
-            lines.push(Line::from(Span::styled(desc_line.to_string(), desc_style)));
+            let mut spans = vec![Span::styled(desc_line.to_string(), desc_style)];
+            spans.extend(link_labels(desc_line, &mut link_no, theme));
+            lines.push(Line::from(spans));
+        }
+    }
+
+    let note_selected = selected_field == LOCAL_NOTE_FIELD;
+    if local_note.is_some() || note_selected {
+        lines.push(Line::default());
+        lines.push(Line::from(vec![
+            Span::styled("Private note", theme.subtle_text()),
+            Span::styled("  only on this machine", theme.muted_text()),
+            field_hint(note_selected, theme),
+        ]));
+        let note_style = if note_selected {
+            theme.normal_text().add_modifier(Modifier::UNDERLINED)
+        } else {
+            theme.normal_text()
+        };
+        match local_note {
+            Some(note) => lines.extend(
+                note.lines()
+                    .map(|l| Line::from(Span::styled(l.to_string(), note_style))),
+            ),
+            None => lines.push(Line::from(Span::styled("(empty)", theme.muted_text()))),
+        }
+    }
+
+    if !subtasks.is_empty() {
+        let done = subtasks.iter().filter(|t| t.checked).count();
+        lines.push(Line::default());
+        lines.push(Line::from(vec![
+            Span::styled("Subtasks", theme.subtle_text()),
+            Span::styled(
+                format!("  {done}/{} done", subtasks.len()),
+                theme.muted_text(),
+            ),
+        ]));
+        for (i, subtask) in subtasks.iter().enumerate() {
+            let selected = selected_field == SUBTASKS_FIELD + i;
+            let (mark, mark_style, style) = if subtask.checked {
+                (
+                    "✓ ",
+                    theme.success(),
+                    theme.muted_text().add_modifier(Modifier::CROSSED_OUT),
+                )
+            } else {
+                ("○ ", theme.muted_text(), theme.normal_text())
+            };
+            let style = if selected {
+                style.add_modifier(Modifier::UNDERLINED)
+            } else {
+                style
+            };
+            lines.push(Line::from(vec![
+                Span::styled(mark, mark_style),
+                Span::styled(subtask.content.as_str(), style),
+                field_hint(selected, theme),
+            ]));
         }
     }
 
     lines.push(Line::default());
-    lines.push(Line::from(Span::styled(
-        "─── Comments ───",
-        theme.subtle_text(),
-    )));
-    lines.push(Line::default());
+    match history {
+        None => lines.push(Line::from(vec![
+            Span::styled("History", theme.subtle_text()),
+            Span::styled("  H to show", theme.muted_text()),
+        ])),
+        Some(history) => {
+            lines.push(Line::from(Span::styled("History", theme.subtle_text())));
+            match history {
+                None => lines.push(Line::from(Span::styled("loading…", theme.muted_text()))),
+                Some(Err(e)) => lines.push(Line::from(Span::styled(
+                    format!("unavailable: {e}"),
+                    theme.muted_text(),
+                ))),
+                Some(Ok(events)) if events.is_empty() => {
+                    lines.push(Line::from(Span::styled("no activity", theme.muted_text())))
+                }
+                Some(Ok(events)) => {
+                    for event in events {
+                        let mut spans = vec![
+                            Span::styled(
+                                format!("{}  ", format_comment_time(&event.event_date)),
+                                theme.muted_text(),
+                            ),
+                            Span::styled(event.describe(), theme.normal_text()),
+                        ];
+                        if let Some(uid) = event.initiator_id.as_deref()
+                            && current_user_id != Some(uid)
+                            && let Some(user) = user_names.get(uid)
+                        {
+                            spans.push(Span::styled(
+                                format!("  by {}", user.display),
+                                theme.subtle_text(),
+                            ));
+                        }
+                        lines.push(Line::from(spans));
+                    }
+                }
+            }
+        }
+    }
 
+    let mut thread: Vec<Line> = Vec::new();
+    // Rows of the selected comment in `thread`, to keep it in view.
+    let mut selected_rows = None;
     if comments.is_empty() {
-        lines.push(Line::from(Span::styled(
+        thread.push(Line::from(Span::styled(
             "no comments yet",
             theme.muted_text(),
         )));
     } else {
         let user_colors = theme.user_colors();
-        let mut seen_users: Vec<String> = Vec::new();
-        let mut prev_user: Option<String> = None;
+        let mut seen_users: Vec<&str> = Vec::new();
+        let mut prev_user: Option<&str> = None;
 
-        for comment in comments {
-            let user_id = comment
-                .posted_by_uid
-                .as_deref()
-                .unwrap_or("you")
-                .to_string();
+        for (i, comment) in comments.iter().enumerate() {
+            let user_id = comment.posted_by_uid.as_deref().unwrap_or("you");
 
             if !seen_users.contains(&user_id) {
-                seen_users.push(user_id.clone());
+                seen_users.push(user_id);
             }
             let color_idx =
-                seen_users.iter().position(|u| u == &user_id).unwrap_or(0) % user_colors.len();
+                seen_users.iter().position(|u| *u == user_id).unwrap_or(0) % user_colors.len();
             let user_color = user_colors[color_idx];
 
-            let same_user = prev_user.as_deref() == Some(&user_id);
+            let same_user = prev_user == Some(user_id);
             let timestamp = comment
                 .posted_at
                 .as_deref()
@@ -168,11 +349,11 @@ pub fn render(
 
             if !same_user {
                 if prev_user.is_some() {
-                    lines.push(Line::default());
+                    thread.push(Line::default());
                 }
-                let is_me = current_user_id == Some(user_id.as_str());
+                let is_me = current_user_id == Some(user_id);
                 let resolved = user_names
-                    .get(&user_id)
+                    .get(user_id)
                     .map(|r| r.display.as_str())
                     .unwrap_or_else(|| &user_id[..user_id.len().min(8)]);
                 let display_name = if is_me {
@@ -187,17 +368,26 @@ pub fn render(
                 } else {
                     Style::default().fg(user_color).add_modifier(Modifier::BOLD)
                 };
-                lines.push(Line::from(Span::styled(display_name, name_style)));
+                thread.push(Line::from(Span::styled(display_name, name_style)));
             }
 
             let has_attachment = comment.attachment.is_some();
+            let selected = selected_comment == Some(i);
+            let first_line = thread.len();
+            let bar = if selected {
+                Span::styled("┃ ", theme.key_hint())
+            } else {
+                Span::styled("│ ", Style::default().fg(user_color))
+            };
 
             if !comment.content.is_empty() {
                 for content_line in comment.content.lines() {
-                    lines.push(Line::from(vec![
-                        Span::styled("│ ", Style::default().fg(user_color)),
+                    let mut spans = vec![
+                        bar.clone(),
                         Span::styled(content_line.to_string(), theme.normal_text()),
-                    ]));
+                    ];
+                    spans.extend(link_labels(content_line, &mut link_no, theme));
+                    thread.push(Line::from(spans));
                 }
             }
 
@@ -220,56 +410,157 @@ pub fn render(
                     format!("[+] {resource_type} attachment")
                 };
 
-                lines.push(Line::from(vec![
-                    Span::styled("│ ", Style::default().fg(user_color)),
+                let mut spans = vec![
+                    bar.clone(),
                     Span::styled(
                         display,
                         theme.due_upcoming().add_modifier(Modifier::UNDERLINED),
                     ),
-                ]));
+                ];
+                if file_url.is_some() {
+                    link_no += 1;
+                    spans.push(Span::styled(format!(" [{link_no}]"), theme.key_hint()));
+                }
+                thread.push(Line::from(spans));
             }
 
             if comment.content.is_empty() && !has_attachment {
-                lines.push(Line::from(vec![
-                    Span::styled("│ ", Style::default().fg(user_color)),
+                thread.push(Line::from(vec![
+                    bar.clone(),
                     Span::styled("(empty)", theme.muted_text()),
                 ]));
             }
 
-            if let Some(last_line) = lines.last_mut() {
+            if let Some(last_line) = thread.last_mut() {
                 last_line.spans.push(Span::styled(
                     format!("  {timestamp}"),
                     theme.muted_text().add_modifier(Modifier::ITALIC),
                 ));
             }
 
+            if selected {
+                selected_rows = Some((first_line, thread.len()));
+            }
             prev_user = Some(user_id);
         }
-        lines.push(Line::default());
     }
 
-    lines.push(Line::default());
-    lines.push(Line::from(vec![
+    let mut hints = vec![
         Span::styled("i", theme.key_hint()),
         Span::styled(" edit  ", theme.muted_text()),
         Span::styled("c", theme.key_hint()),
         Span::styled(" comment  ", theme.muted_text()),
         Span::styled("x", theme.key_hint()),
         Span::styled(" complete  ", theme.muted_text()),
-        Span::styled("Esc", theme.key_hint()),
-        Span::styled(" back", theme.muted_text()),
-    ]));
+    ];
+    if !ancestors.is_empty() {
+        hints.push(Span::styled("p", theme.key_hint()));
+        hints.push(Span::styled(" parent  ", theme.muted_text()));
+    }
+    if link_no > 0 {
+        hints.push(Span::styled(
+            format!("1-{}", link_no.min(9)),
+            theme.key_hint(),
+        ));
+        hints.push(Span::styled(" open link  ", theme.muted_text()));
+    }
+    hints.push(Span::styled("Esc", theme.key_hint()));
+    hints.push(Span::styled(" back", theme.muted_text()));
+
+    // Fields take what they need up to two thirds of the pane, scrolled to
+    // keep the selected one in view; the thread scrolls on its own below.
+    let width = inner.width.max(1);
+    let fields_height = wrapped_height(&lines, width).min(inner.height * 2 / 3);
+    let [fields_area, thread_area, hints_area] = Layout::vertical([
+        Constraint::Length(fields_height),
+        Constraint::Min(3),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let selected_line = lines
+        .iter()
+        .position(|l| l.spans.iter().any(|s| s.content == FIELD_MARKER))
+        .unwrap_or(0);
+    let selected_bottom = wrapped_height(
+        &lines[..=selected_line.min(lines.len().saturating_sub(1))],
+        width,
+    );
+    let fields_scroll = selected_bottom.saturating_sub(fields_area.height);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .scroll((fields_scroll, 0))
+            .wrap(Wrap { trim: false }),
+        fields_area,
+    );
 
-    let paragraph = Paragraph::new(lines)
-        .scroll((scroll, 0))
-        .wrap(Wrap { trim: false });
+    let thread_block = Block::default()
+        .title(format!(" Comments ({}) ", comments.len()))
+        .title_style(theme.subtle_text())
+        .borders(Borders::TOP)
+        .border_style(theme.inactive_border());
+    let thread_inner = block_inner_with_gutter(&thread_block, thread_area);
+    frame.render_widget(thread_block, thread_area);
+
+    let thread_height = wrapped_height(&thread, thread_inner.width.max(1));
+    let max_scroll = thread_height.saturating_sub(thread_inner.height);
+    let scroll = match selected_rows {
+        Some((first, end)) => {
+            let top = wrapped_height(&thread[..first], thread_inner.width.max(1));
+            let bottom = wrapped_height(&thread[..end], thread_inner.width.max(1));
+            scroll
+                .min(top)
+                .max(bottom.saturating_sub(thread_inner.height))
+        }
+        None => scroll,
+    }
+    .min(max_scroll);
+    frame.render_widget(
+        Paragraph::new(thread)
+            .scroll((scroll, 0))
+            .wrap(Wrap { trim: false }),
+        thread_inner,
+    );
+    if max_scroll > 0 {
+        let mut state = ScrollbarState::new(max_scroll as usize).position(scroll as usize);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .style(theme.muted_text()),
+            thread_area.inner(Margin::new(0, 1)),
+            &mut state,
+        );
+    }
 
-    frame.render_widget(paragraph, inner);
+    frame.render_widget(Paragraph::new(Line::from(hints)), hints_area);
+    (max_scroll, scroll)
 }
 
+/// The thread's text area: below the title rule, one column short of the
+/// scrollbar.
+fn block_inner_with_gutter(block: &Block, area: Rect) -> Rect {
+    let inner = block.inner(area);
+    Rect {
+        width: inner.width.saturating_sub(1),
+        ..inner
+    }
+}
+
+/// Rows `lines` take once wrapped to `width`.
+fn wrapped_height(lines: &[Line], width: u16) -> u16 {
+    lines
+        .iter()
+        .map(|l| (l.width() as u16).div_ceil(width).max(1))
+        .sum()
+}
+
+/// Trails the selected field's row.
+const FIELD_MARKER: &str = "  ◂";
+
 fn field_hint(active: bool, theme: &Theme) -> Span<'static> {
     if active {
-        Span::styled("  ◂", theme.key_hint())
+        Span::styled(FIELD_MARKER, theme.key_hint())
     } else {
         Span::raw("")
     }
@@ -0,0 +1,65 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Flex, Layout};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Gauge, Paragraph, Wrap};
+
+use crate::app::FocusSession;
+use crate::ui::theme::Theme;
+
+/// Full-screen pomodoro view: the pinned task, a countdown and a progress bar.
+pub fn render(frame: &mut Frame, session: &FocusSession, theme: &Theme) {
+    let area = frame.area();
+    frame.render_widget(Block::default().style(theme.base_bg()), area);
+
+    let [_, title_area, _, clock_area, _, bar_area, _, hint_area, _] = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(2),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(2),
+        Constraint::Length(1),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
+
+    let [title_area] = Layout::horizontal([Constraint::Percentage(60)])
+        .flex(Flex::Center)
+        .areas(title_area);
+    let title = Paragraph::new(Line::from(Span::styled(
+        session.content.as_str(),
+        theme.active_title(),
+    )))
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    frame.render_widget(title, title_area);
+
+    let remaining = session.remaining().as_secs();
+    let clock = Paragraph::new(Line::from(Span::styled(
+        format!("{:02}:{:02}", remaining / 60, remaining % 60),
+        theme.title(),
+    )))
+    .alignment(Alignment::Center);
+    frame.render_widget(clock, clock_area);
+
+    let [bar_area] = Layout::horizontal([Constraint::Percentage(40)])
+        .flex(Flex::Center)
+        .areas(bar_area);
+    let total = session.length.as_secs_f64().max(1.0);
+    let ratio = (1.0 - session.remaining().as_secs_f64() / total).clamp(0.0, 1.0);
+    let gauge = Gauge::default()
+        .ratio(ratio)
+        .label("")
+        .gauge_style(theme.due_upcoming().bg(theme.surface));
+    frame.render_widget(gauge, bar_area);
+
+    let hints = Paragraph::new(Line::from(vec![
+        Span::styled("Enter", theme.key_hint()),
+        Span::styled(" finish and log  ", theme.muted_text()),
+        Span::styled("Esc", theme.key_hint()),
+        Span::styled(" abandon", theme.muted_text()),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(hints, hint_area);
+}
@@ -1,4 +1,7 @@
 pub mod detail;
+pub mod focus;
+pub mod overview;
+pub mod preview;
 pub mod projects;
 pub mod settings;
 pub mod tasks;
@@ -1,44 +1,45 @@
 use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Padding, Paragraph};
+use ratatui::widgets::Paragraph;
 
-use crate::app::{App, SortMode};
+use crate::app::App;
 
+/// The stats dock's weekly goal row: a progress bar filled by this week's
+/// completions, or a muted placeholder until the goal is known.
 pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let theme = app.theme();
     let stats = app.overview_stats();
 
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(theme.inactive_border())
-        .padding(Padding::horizontal(1))
-        .style(theme.base_bg());
-
-    let inner = block.inner(area);
-    frame.render_widget(block, area);
-
-    let bar_width = 20usize;
-    let filled = if stats.week_total > 0 {
-        (bar_width as f64 * stats.week_done as f64 / stats.week_total as f64) as usize
-    } else {
-        0
-    };
+    if stats.week_total == 0 {
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("Week ", theme.muted_text()),
+                Span::styled("no weekly goal", theme.subtle_text()),
+            ])),
+            area,
+        );
+        return;
+    }
+
+    let label = format!(" {}/{}", stats.week_done, stats.week_total);
+    let bar_width = (area.width as usize)
+        .saturating_sub(5 + label.chars().count())
+        .min(20);
+    let filled = bar_width * stats.week_progress as usize / 100;
     let progress_bar: String = "█".repeat(filled) + &"░".repeat(bar_width - filled);
 
-    let sort_label = app.sort_mode.label();
-    let sort_indicator = if app.sort_mode != SortMode::Default {
-        format!("  ⟳ {sort_label}")
+    let label_style = if stats.week_done >= stats.week_total {
+        theme.success()
     } else {
-        String::new()
+        theme.muted_text()
     };
 
     let line = Line::from(vec![
+        Span::styled("Week ", theme.muted_text()),
         Span::styled(progress_bar, theme.success()),
-        Span::styled(format!(" {}%", stats.week_progress), theme.muted_text()),
-        Span::styled(sort_indicator, theme.due_upcoming()),
+        Span::styled(label, label_style),
     ]);
 
-    frame.render_widget(Paragraph::new(line), inner);
+    frame.render_widget(Paragraph::new(line), area);
 }
@@ -0,0 +1,134 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+
+use crate::app::App;
+use crate::ui::dates;
+
+const PREVIEW_COMMENTS: usize = 3;
+
+/// Read-only summary of the selected task, shown beside the task list. Comments
+/// come from the sync cache only — the preview never triggers a fetch.
+pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = app.theme();
+
+    let block = Block::default()
+        .title(" Preview ")
+        .title_style(theme.title())
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(theme.inactive_border())
+        .padding(Padding::horizontal(1))
+        .style(theme.base_bg());
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(task) = app.selected_task() else {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "no task selected",
+                theme.muted_text(),
+            ))),
+            inner,
+        );
+        return;
+    };
+
+    let mut lines: Vec<Line> = Vec::new();
+
+    lines.push(Line::from(Span::styled(
+        &task.content,
+        theme.active_title(),
+    )));
+    lines.push(Line::default());
+
+    if let Some(due) = &task.due {
+        let formatted = dates::format_due(due, theme);
+        let recurring_marker = if due.is_recurring { " ↻" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled("Due   ", theme.muted_text()),
+            Span::styled(
+                format!("{}{recurring_marker}", formatted.text),
+                formatted.style,
+            ),
+        ]));
+    }
+
+    if task.priority > 1 {
+        lines.push(Line::from(vec![
+            Span::styled("Prio  ", theme.muted_text()),
+            Span::styled(
+                format!("● P{}", 5 - task.priority),
+                theme.priority_style(task.priority),
+            ),
+        ]));
+    }
+
+    if !task.labels.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Tags  ", theme.muted_text()),
+            Span::styled(task.labels.join("  "), theme.label_tag()),
+        ]));
+    }
+
+    if !task.description.is_empty() {
+        lines.push(Line::default());
+        for desc_line in task.description.lines() {
+            lines.push(Line::from(Span::styled(
+                desc_line.to_string(),
+                theme.normal_text(),
+            )));
+        }
+    }
+
+    let comments = app
+        .comments_by_task
+        .get(&task.id)
+        .map(|c| c.as_slice())
+        .unwrap_or_default();
+    if !comments.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(
+            format!("─── Comments ({}) ───", comments.len()),
+            theme.subtle_text(),
+        )));
+        let user_colors = theme.user_colors();
+        let mut seen_users: Vec<&str> = Vec::new();
+        for comment in comments.iter().take(PREVIEW_COMMENTS) {
+            let uid = comment.posted_by_uid.as_deref().unwrap_or("");
+            if !seen_users.contains(&uid) {
+                seen_users.push(uid);
+            }
+            let color_idx = seen_users.iter().position(|u| *u == uid).unwrap_or(0);
+            let name = app
+                .user_names
+                .get(uid)
+                .map(|r| r.full_name.as_str())
+                .filter(|n| !n.is_empty())
+                .unwrap_or("someone");
+            let color = user_colors[color_idx % user_colors.len()];
+            lines.push(Line::from(Span::styled(
+                name.to_string(),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            )));
+            for content_line in comment.content.lines() {
+                lines.push(Line::from(vec![
+                    Span::styled("│ ", Style::default().fg(color)),
+                    Span::styled(content_line.to_string(), theme.normal_text()),
+                ]));
+            }
+        }
+        if comments.len() > PREVIEW_COMMENTS {
+            lines.push(Line::from(Span::styled(
+                format!("… {} more", comments.len() - PREVIEW_COMMENTS),
+                theme.muted_text(),
+            )));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, inner);
+}
@@ -1,27 +1,91 @@
 use ratatui::Frame;
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{List, ListItem, ListState};
+use ratatui::widgets::{List, ListItem, ListState, Paragraph};
 
-use crate::app::{App, ProjectEntry};
+use crate::app::{App, FavoriteView, ProjectEntry};
 
 pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
     let theme = app.theme();
+
+    let area = if let Some(query) = &app.project_filter {
+        let [filter_area, list_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(area);
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("/ ", theme.key_hint()),
+                Span::styled(query.as_str(), theme.normal_text()),
+                Span::styled("▎", theme.due_upcoming()),
+            ])),
+            filter_area,
+        );
+        list_area
+    } else {
+        area
+    };
+
     let entries = app.project_list_entries();
+    let counts = app.project_task_counts();
 
-    let selected_visual = entries.iter().position(|e| match e {
-        ProjectEntry::Project(i) => {
-            !app.today_view_active && app.folder_cursor.is_none() && *i == app.selected_project
-        }
-        ProjectEntry::FolderHeader(fi) => app.folder_cursor == Some(*fi),
-        ProjectEntry::TodayView => app.today_view_active && app.folder_cursor.is_none(),
-        _ => false,
+    // While filtering, the highlight tracks the match Enter would jump to.
+    let filter_target = app.project_filter.as_ref().and_then(|_| {
+        entries
+            .iter()
+            .position(|e| matches!(e, ProjectEntry::Project(_)))
+    });
+
+    let selected_visual = filter_target.or_else(|| {
+        entries.iter().position(|e| match e {
+            ProjectEntry::Favorite(view) => {
+                app.folder_cursor.is_none() && app.favorite_view.as_ref() == Some(view)
+            }
+            ProjectEntry::Project(i) => {
+                !app.today_view_active
+                    && app.favorite_view.is_none()
+                    && app.folder_cursor.is_none()
+                    && *i == app.selected_project
+            }
+            ProjectEntry::FolderHeader(fi) => app.folder_cursor == Some(*fi),
+            ProjectEntry::TodayView => app.today_view_active && app.folder_cursor.is_none(),
+            _ => false,
+        })
     });
 
     let items: Vec<ListItem> = entries
         .iter()
         .map(|entry| match entry {
+            ProjectEntry::FavoritesHeader => ListItem::new(Line::from(Span::styled(
+                "  Favorites",
+                theme.muted_text().add_modifier(Modifier::BOLD),
+            ))),
+
+            ProjectEntry::Favorite(FavoriteView::Label(id)) => {
+                let label = app.labels.iter().find(|l| &l.id == id);
+                let name = label.map(|l| l.name.as_str()).unwrap_or("");
+                let color = label
+                    .map(|l| theme.color_for(&l.color))
+                    .unwrap_or(theme.subtle);
+                ListItem::new(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled("@ ", Style::default().fg(color)),
+                    Span::styled(name, theme.normal_text()),
+                ]))
+            }
+
+            ProjectEntry::Favorite(FavoriteView::Filter(id)) => {
+                let filter = app.filters.iter().find(|f| &f.id == id);
+                let name = filter.map(|f| f.name.as_str()).unwrap_or("");
+                let color = filter
+                    .map(|f| theme.color_for(&f.color))
+                    .unwrap_or(theme.subtle);
+                ListItem::new(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled("⚲ ", Style::default().fg(color)),
+                    Span::styled(name, theme.normal_text()),
+                ]))
+            }
+
             ProjectEntry::PersonalHeader => {
                 let name = app.current_user_name.as_deref().unwrap_or("Personal");
                 ListItem::new(Line::from(Span::styled(
@@ -90,11 +154,18 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
                     Span::styled("# ", Style::default().fg(dot_color))
                 };
 
-                ListItem::new(Line::from(vec![
+                let mut spans = vec![
                     Span::raw(indent),
                     icon,
                     Span::styled(&project.name, theme.normal_text()),
-                ]))
+                ];
+                if let Some(c) = counts.get(project.id.as_str()) {
+                    spans.push(Span::styled(format!("  {}", c.active), theme.muted_text()));
+                    if c.overdue > 0 {
+                        spans.push(Span::styled(format!(" {}", c.overdue), theme.due_overdue()));
+                    }
+                }
+                ListItem::new(Line::from(spans))
             }
         })
         .collect();
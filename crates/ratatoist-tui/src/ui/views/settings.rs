@@ -1,7 +1,7 @@
 use ratatui::Frame;
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph};
 
 use crate::app::App;
 
@@ -50,7 +50,85 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
         Span::styled(idle_label, theme.key_hint()),
     ]));
 
-    let items = vec![mode_item, theme_item, idle_item];
+    let preview_label = if app.show_preview { "on" } else { "off" };
+    let preview_item = ListItem::new(Line::from(vec![
+        Span::styled("Preview ", theme.muted_text()),
+        Span::styled(preview_label, theme.key_hint()),
+    ]));
+
+    let titles_item = ListItem::new(Line::from(vec![
+        Span::styled("Titles  ", theme.muted_text()),
+        Span::styled(app.title_overflow.label(), theme.key_hint()),
+    ]));
+
+    let focus_item = ListItem::new(Line::from(vec![
+        Span::styled("Focus   ", theme.muted_text()),
+        Span::styled(format!("{}m", app.focus_minutes), theme.key_hint()),
+    ]));
+
+    let notify_label = if app.focus_notify { "on" } else { "off" };
+    let notify_item = ListItem::new(Line::from(vec![
+        Span::styled("Notify  ", theme.muted_text()),
+        Span::styled(notify_label, theme.key_hint()),
+    ]));
+
+    let plain_label = if app.plain_mode { "on" } else { "off" };
+    let plain_item = ListItem::new(Line::from(vec![
+        Span::styled("Plain   ", theme.muted_text()),
+        Span::styled(plain_label, theme.key_hint()),
+    ]));
+
+    let marker_item = ListItem::new(Line::from(vec![
+        Span::styled("Prio    ", theme.muted_text()),
+        Span::styled(app.priority_marker.label(), theme.key_hint()),
+    ]));
+
+    let clock_label = if app.show_clock { "on" } else { "off" };
+    let clock_item = ListItem::new(Line::from(vec![
+        Span::styled("Clock   ", theme.muted_text()),
+        Span::styled(clock_label, theme.key_hint()),
+    ]));
+
+    let hints_item = ListItem::new(Line::from(vec![
+        Span::styled("Hints   ", theme.muted_text()),
+        Span::styled(app.key_hints.label(), theme.key_hint()),
+    ]));
+
+    let updates_label = match (&app.latest_version, app.check_updates) {
+        (Some(version), _) => format!("v{version} available"),
+        (None, true) => "on".to_string(),
+        (None, false) => "off".to_string(),
+    };
+    let updates_item = ListItem::new(Line::from(vec![
+        Span::styled("Updates ", theme.muted_text()),
+        Span::styled(updates_label, theme.key_hint()),
+    ]));
+
+    let poll_label = match app.poll_interval_secs {
+        0 => "off".to_string(),
+        secs if secs < 60 => format!("{secs}s"),
+        secs => format!("{}m", secs / 60),
+    };
+    let poll_item = ListItem::new(Line::from(vec![
+        Span::styled("Poll    ", theme.muted_text()),
+        Span::styled(poll_label, theme.key_hint()),
+    ]));
+
+    let items = vec![
+        mode_item,
+        theme_item,
+        idle_item,
+        preview_item,
+        titles_item,
+        focus_item,
+        notify_item,
+        plain_item,
+        marker_item,
+        clock_item,
+        hints_item,
+        updates_item,
+        poll_item,
+    ];
 
     let highlight_style = if is_active {
         theme.selected_item()
@@ -60,6 +138,59 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
 
     let list = List::new(items).highlight_style(highlight_style);
 
+    let account = account_lines(app);
+    let [list_area, account_area] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(account.len() as u16)])
+            .areas(inner);
+
     let mut state = ListState::default().with_selected(Some(app.settings_selection));
-    frame.render_stateful_widget(list, inner, &mut state);
+    frame.render_stateful_widget(list, list_area, &mut state);
+    frame.render_widget(Paragraph::new(account), account_area);
+}
+
+/// Read-only Todoist account settings, once the user record has synced.
+fn account_lines(app: &App) -> Vec<Line<'static>> {
+    let Some(user) = &app.account else {
+        return Vec::new();
+    };
+    let theme = app.theme();
+    let row = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(label, theme.muted_text()),
+            Span::styled(value, theme.normal_text()),
+        ])
+    };
+
+    let start = match user.start_page.as_deref() {
+        Some(page) => match page.strip_prefix("project?id=") {
+            Some(id) => app
+                .projects
+                .iter()
+                .find(|p| p.id == id)
+                .map_or_else(|| "a project".to_string(), |p| p.name.clone()),
+            None => page.replace('_', " "),
+        },
+        None => "—".to_string(),
+    };
+    let goal = |n: Option<u32>| n.map_or_else(|| "—".to_string(), |n| n.to_string());
+    let goals = format!(
+        "{} a day · {} a week",
+        goal(user.daily_goal),
+        goal(user.weekly_goal)
+    );
+    let zone = match &user.tz_info {
+        Some(tz) => match &tz.gmt_string {
+            Some(gmt) => format!("{} {gmt}", tz.timezone),
+            None => tz.timezone.clone(),
+        },
+        None => "system".to_string(),
+    };
+
+    vec![
+        Line::default(),
+        Line::from(Span::styled("Account", theme.title())),
+        row("Start   ", start),
+        row("Goals   ", goals),
+        row("Zone    ", zone),
+    ]
 }
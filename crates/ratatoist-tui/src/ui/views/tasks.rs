@@ -1,31 +1,53 @@
 use ratatoist_core::api::models::Task;
 use ratatui::Frame;
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{List, ListItem, ListState};
+use ratatui::widgets::{List, ListItem, ListState, Paragraph};
 
-use crate::app::{App, InputMode};
-use crate::ui::dates;
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::{App, DockItem, InputMode, TitleOverflow};
 use crate::ui::theme::Theme;
+use crate::ui::{dates, text};
 
 pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
     let theme = app.theme();
     let visible = app.visible_tasks();
 
-    if app.today_view_active && visible.is_empty() {
-        let lines = vec![
-            ListItem::new(Line::default()),
-            ListItem::new(Line::from(Span::styled(
-                "All caught up for today",
-                theme.muted_text(),
-            ))),
+    let area = if let Some(search) = &app.task_search {
+        let [search_area, list_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(area);
+        let mut spans = vec![
+            Span::styled("/ ", theme.key_hint()),
+            Span::styled(search.query.as_str(), theme.normal_text()),
         ];
-        frame.render_widget(List::new(lines), area);
+        if search.editing {
+            spans.push(Span::styled("▎", theme.due_upcoming()));
+        } else {
+            let count = visible
+                .iter()
+                .filter(|t| !app.is_context_task(t) && app.task_matches_search(t))
+                .count();
+            spans.push(Span::styled(format!("  [{count}]"), theme.muted_text()));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), search_area);
+        list_area
+    } else {
+        area
+    };
+
+    if visible.is_empty() && (app.today_view_active || app.dock_filter == Some(DockItem::DueToday))
+    {
+        render_all_done(frame, app, area);
         return;
     }
 
-    if visible.is_empty() && !app.today_view_active && app.dock_filter.is_none() {
+    if visible.is_empty()
+        && !app.today_view_active
+        && app.favorite_view.is_none()
+        && app.dock_filter.is_none()
+    {
         let hint = match app.input_mode {
             InputMode::Vim(_) => "press a to add a task",
             InputMode::Standard => "press Ctrl-a to add a task",
@@ -54,20 +76,23 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
         return;
     }
 
-    let cross_project = app.today_view_active || app.dock_filter.is_some();
+    let cross_project =
+        app.today_view_active || app.favorite_view.is_some() || app.dock_filter.is_some();
 
     let mut items: Vec<ListItem> = Vec::new();
     let mut visual_selected: Option<usize> = None;
-    let mut current_project_id: Option<String> = None;
-    let mut last_section_id: Option<String> = None;
+    let mut current_project_id: Option<&str> = None;
+    let mut last_section_id: Option<&str> = None;
 
-    let today = dates::today_str();
+    let today = dates::today();
     let stats = if app.today_view_active {
         Some(app.overview_stats())
     } else {
         None
     };
     let mut overdue_header_shown = false;
+    // `J` / `K` move this one, so its header stands out.
+    let selected_section = app.selected_task().and_then(|t| t.section_id.as_deref());
 
     for (task_idx, task) in visible.iter().enumerate() {
         if app.today_view_active
@@ -75,7 +100,8 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
             && task
                 .due
                 .as_ref()
-                .is_some_and(|d| dates::date_part(&d.date) < today.as_str())
+                .and_then(dates::due_date)
+                .is_some_and(|d| d < today)
         {
             let overdue_count = stats.as_ref().map(|s| s.overdue).unwrap_or(0);
             let arrow = if app.overdue_section_collapsed {
@@ -90,12 +116,15 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
             overdue_header_shown = true;
         }
 
-        if cross_project && current_project_id.as_deref() != Some(&task.project_id) {
-            current_project_id = Some(task.project_id.clone());
+        if cross_project && current_project_id != Some(task.project_id.as_str()) {
+            current_project_id = Some(&task.project_id);
         }
 
-        if !cross_project && task.parent_id.is_none() && task.section_id != last_section_id {
-            last_section_id = task.section_id.clone();
+        if !cross_project
+            && task.parent_id.is_none()
+            && task.section_id.as_deref() != last_section_id
+        {
+            last_section_id = task.section_id.as_deref();
             if let Some(sid) = &task.section_id {
                 let name = app
                     .sections
@@ -106,9 +135,14 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
                 if !items.is_empty() {
                     items.push(ListItem::new(Line::default()));
                 }
+                let header_style = if is_active && selected_section == Some(sid.as_str()) {
+                    theme.active_title()
+                } else {
+                    theme.muted_text()
+                };
                 items.push(ListItem::new(Line::from(Span::styled(
                     format!("  {name}"),
-                    theme.muted_text().add_modifier(Modifier::BOLD),
+                    header_style.add_modifier(Modifier::BOLD),
                 ))));
             }
         }
@@ -116,7 +150,13 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
         if task_idx == app.selected_task {
             visual_selected = Some(items.len());
         }
-        items.push(build_task_item(task, app, theme, cross_project));
+        items.push(build_task_item(
+            task,
+            app,
+            theme,
+            cross_project,
+            area.width as usize,
+        ));
     }
 
     let highlight_style = if is_active {
@@ -130,13 +170,49 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
     frame.render_stateful_widget(list, area, &mut state);
 }
 
+const ALL_DONE_ART: [&str; 5] = [
+    r"    \  |  /    ",
+    r"  '.  .-.  .'  ",
+    r" -- (  ✓  ) -- ",
+    r"  .'  `-'  '.  ",
+    r"    /  |  \    ",
+];
+
+/// Nothing left for today: a little sun and the day's completion count.
+fn render_all_done(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = app.theme();
+    let mut lines = vec![Line::default()];
+    lines.extend(
+        ALL_DONE_ART
+            .iter()
+            .map(|row| Line::from(Span::styled(*row, theme.due_today()))),
+    );
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        "All caught up for today",
+        theme.success().add_modifier(Modifier::BOLD),
+    )));
+    if let Some(done) = app.completed_today().filter(|n| *n > 0) {
+        let noun = if done == 1 { "task" } else { "tasks" };
+        lines.push(Line::from(Span::styled(
+            format!("{done} {noun} completed today"),
+            theme.muted_text(),
+        )));
+    }
+    frame.render_widget(
+        Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center),
+        area,
+    );
+}
+
 fn build_task_item<'a>(
     task: &'a Task,
     app: &App,
     theme: &Theme,
     show_project: bool,
+    width: usize,
 ) -> ListItem<'a> {
-    let mut spans = Vec::new();
+    let mut prefix = Vec::new();
     let depth = if show_project {
         0
     } else {
@@ -146,7 +222,7 @@ fn build_task_item<'a>(
     let collapsed = app.is_collapsed(&task.id);
 
     if depth > 0 {
-        spans.push(Span::styled("  ".repeat(depth), theme.muted_text()));
+        prefix.push(Span::styled("  ".repeat(depth), theme.muted_text()));
     }
 
     let tree_icon = if has_children {
@@ -158,26 +234,36 @@ fn build_task_item<'a>(
             _ => "· ",
         }
     };
-    spans.push(Span::styled(tree_icon, theme.muted_text()));
+    prefix.push(Span::styled(tree_icon, theme.muted_text()));
 
     if app.is_context_task(task) {
-        spans.push(Span::styled(&task.content, theme.muted_text()));
-        return ListItem::new(Line::from(spans));
+        return layout_row(
+            prefix,
+            &task.content,
+            theme.muted_text(),
+            Vec::new(),
+            width,
+            app.title_overflow,
+        );
     }
 
-    if task.checked {
-        spans.push(Span::styled("✓ ", theme.success()));
-        spans.push(Span::styled(
-            &task.content,
-            theme.muted_text().add_modifier(Modifier::CROSSED_OUT),
-        ));
+    let title_style = if task.checked {
+        prefix.push(Span::styled("✓ ", theme.success()));
+        theme.muted_text().add_modifier(Modifier::CROSSED_OUT)
     } else {
-        spans.push(Span::styled(
-            Theme::priority_dot(task.priority),
+        prefix.push(Span::styled(
+            app.priority_marker.task_prefix(task.priority),
             theme.priority_style(task.priority),
         ));
-        spans.push(Span::styled(&task.content, theme.normal_text()));
-    }
+        theme.normal_text()
+    };
+    let title_style = if app.task_matches_search(task) {
+        title_style.patch(theme.search_match())
+    } else {
+        title_style
+    };
+
+    let mut chips = Vec::new();
 
     if !task.labels.is_empty() && !task.checked {
         for label_name in &task.labels {
@@ -187,7 +273,7 @@ fn build_task_item<'a>(
                 .find(|l| &l.name == label_name)
                 .map(|l| theme.color_for(&l.color))
                 .unwrap_or(theme.purple);
-            spans.push(Span::styled(
+            chips.push(Span::styled(
                 format!("  {label_name}"),
                 Style::default().fg(color),
             ));
@@ -198,22 +284,100 @@ fn build_task_item<'a>(
         && count > 0
         && !task.checked
     {
-        spans.push(Span::styled(format!("  [{count}]"), theme.muted_text()));
+        chips.push(Span::styled(format!("  [{count}]"), theme.muted_text()));
     }
 
     if task.due.as_ref().is_some_and(|d| d.is_recurring) && !task.checked {
-        spans.push(Span::styled("  ↻", theme.muted_text()));
+        chips.push(Span::styled("  ↻", theme.muted_text()));
+    }
+
+    if app.pinned.contains(&task.id) && !task.checked {
+        chips.push(Span::styled("  ⚑", Style::default().fg(theme.orange)));
     }
 
     if let Some(due) = &task.due
         && !task.checked
     {
         let formatted = dates::format_due(due, theme);
-        spans.push(Span::styled(
+        chips.push(Span::styled(
             format!("  {}", formatted.text),
             formatted.style,
         ));
     }
 
-    ListItem::new(Line::from(spans))
+    layout_row(
+        prefix,
+        &task.content,
+        title_style,
+        chips,
+        width,
+        app.title_overflow,
+    )
+}
+
+/// Narrowest the title may be squeezed to before the trailing chips are
+/// allowed to overflow instead.
+const MIN_TITLE_WIDTH: usize = 12;
+
+/// Fits `prefix + title + chips` into `width` columns. Truncation shortens only
+/// the title so due-date and label chips stay visible; wrapping continues the
+/// title on indented lines and puts the chips after its last line.
+fn layout_row<'a>(
+    prefix: Vec<Span<'a>>,
+    title: &'a str,
+    title_style: Style,
+    chips: Vec<Span<'a>>,
+    width: usize,
+    overflow: TitleOverflow,
+) -> ListItem<'a> {
+    let prefix_w: usize = prefix.iter().map(Span::width).sum();
+    let chips_w: usize = chips.iter().map(Span::width).sum();
+
+    if prefix_w + title.width() + chips_w <= width {
+        let mut spans = prefix;
+        spans.push(Span::styled(title, title_style));
+        spans.extend(chips);
+        return ListItem::new(Line::from(spans));
+    }
+
+    match overflow {
+        TitleOverflow::Truncate => {
+            let room = width
+                .saturating_sub(prefix_w + chips_w)
+                .max(MIN_TITLE_WIDTH);
+            let mut spans = prefix;
+            spans.push(Span::styled(
+                text::truncate_to_width(title, room),
+                title_style,
+            ));
+            spans.extend(chips);
+            ListItem::new(Line::from(spans))
+        }
+        TitleOverflow::Wrap => {
+            let room = width.saturating_sub(prefix_w).max(MIN_TITLE_WIDTH);
+            let indent = " ".repeat(prefix_w);
+            let mut lines: Vec<Line> = Vec::new();
+            let mut prefix = Some(prefix);
+            for chunk in text::wrap_to_width(title, room) {
+                let mut spans = prefix
+                    .take()
+                    .unwrap_or_else(|| vec![Span::raw(indent.clone())]);
+                spans.push(Span::styled(chunk, title_style));
+                lines.push(Line::from(spans));
+            }
+            if !chips.is_empty() {
+                let last_w = lines.last().map(Line::width).unwrap_or(0);
+                if last_w + chips_w <= width {
+                    if let Some(last) = lines.last_mut() {
+                        last.spans.extend(chips);
+                    }
+                } else {
+                    let mut spans = vec![Span::raw(indent)];
+                    spans.extend(chips);
+                    lines.push(Line::from(spans));
+                }
+            }
+            ListItem::new(lines)
+        }
+    }
 }
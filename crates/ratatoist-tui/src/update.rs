@@ -0,0 +1,215 @@
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tracing::info;
+
+const RELEASES_URL: &str = "https://api.github.com/repos/cxrlos/ratatoist/releases?per_page=30";
+/// Releases are shared with ratatoist-core; only these tags ship the binary.
+const TAG_PREFIX: &str = "ratatoist-tui-v";
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Deserialize)]
+pub struct Release {
+    tag_name: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+    #[serde(default)]
+    assets: Vec<Asset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Asset {
+    name: String,
+    browser_download_url: String,
+}
+
+impl Release {
+    pub fn version(&self) -> &str {
+        self.tag_name.trim_start_matches(TAG_PREFIX)
+    }
+
+    fn asset_url(&self, name: &str) -> Result<&str> {
+        self.assets
+            .iter()
+            .find(|a| a.name == name)
+            .map(|a| a.browser_download_url.as_str())
+            .with_context(|| format!("release {} has no {name}", self.tag_name))
+    }
+}
+
+fn http() -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .user_agent(concat!("ratatoist/", env!("CARGO_PKG_VERSION")))
+        .timeout(Duration::from_secs(60))
+        .build()
+        .context("failed to build HTTP client")
+}
+
+/// The newest stable TUI release on GitHub, if it is newer than this build.
+pub async fn check() -> Result<Option<Release>> {
+    let releases: Vec<Release> = http()?
+        .get(RELEASES_URL)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .context("failed to fetch releases")?
+        .json()
+        .await
+        .context("failed to parse releases")?;
+    Ok(newest(releases).filter(|r| is_newer(r.version(), VERSION)))
+}
+
+fn newest(releases: Vec<Release>) -> Option<Release> {
+    releases
+        .into_iter()
+        .filter(|r| !r.draft && !r.prerelease && r.tag_name.starts_with(TAG_PREFIX))
+        .filter(|r| parse_version(r.version()).is_some())
+        .max_by_key(|r| parse_version(r.version()))
+}
+
+fn parse_version(v: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = v.split('.').map(|p| p.parse::<u64>().ok());
+    let version = (parts.next()??, parts.next()??, parts.next()??);
+    parts.next().is_none().then_some(version)
+}
+
+fn is_newer(candidate: &str, current: &str) -> bool {
+    match (parse_version(candidate), parse_version(current)) {
+        (Some(c), Some(cur)) => c > cur,
+        _ => false,
+    }
+}
+
+/// Target triple of the matching release tarball (see publish.yml).
+fn release_target() -> Option<&'static str> {
+    match (std::env::consts::ARCH, std::env::consts::OS) {
+        ("aarch64", "macos") => Some("aarch64-apple-darwin"),
+        ("x86_64", "macos") => Some("x86_64-apple-darwin"),
+        ("x86_64", "linux") => Some("x86_64-unknown-linux-gnu"),
+        ("aarch64", "linux") => Some("aarch64-unknown-linux-gnu"),
+        _ => None,
+    }
+}
+
+/// `ratatoist self-update`: downloads the newest release tarball for this
+/// platform, verifies its checksum and swaps it in for the running binary.
+pub async fn self_update() -> Result<()> {
+    let exe = std::env::current_exe().context("failed to locate the running binary")?;
+    if exe.components().any(|c| c.as_os_str() == ".cargo") {
+        bail!("ratatoist was installed with cargo; update it with `cargo install ratatoist-tui`");
+    }
+    let target = release_target().context("no prebuilt release for this platform")?;
+
+    let Some(release) = check().await? else {
+        println!("ratatoist {VERSION} is up to date");
+        return Ok(());
+    };
+    let archive_name = format!("ratatoist-{target}.tar.gz");
+    let archive_url = release.asset_url(&archive_name)?;
+    let sum_url = release.asset_url(&format!("{archive_name}.sha256"))?;
+
+    println!("downloading ratatoist {} ...", release.version());
+    let client = http()?;
+    let archive = client
+        .get(archive_url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .context("failed to download release")?
+        .bytes()
+        .await
+        .context("failed to download release")?;
+    let sums = client
+        .get(sum_url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .context("failed to download checksum")?
+        .text()
+        .await
+        .context("failed to download checksum")?;
+
+    let expected = sums.split_whitespace().next().unwrap_or_default();
+    let actual: String = Sha256::digest(&archive)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    if !expected.eq_ignore_ascii_case(&actual) {
+        bail!("checksum mismatch for {archive_name}; not installing");
+    }
+
+    let dir = exe.parent().context("binary has no parent directory")?;
+    let staging = dir.join(".ratatoist-update");
+    let result = install(&archive, &staging, &exe);
+    let _ = std::fs::remove_dir_all(&staging);
+    result?;
+
+    info!(
+        from = VERSION,
+        to = release.version(),
+        "self-update complete"
+    );
+    println!("updated ratatoist {VERSION} -> {}", release.version());
+    Ok(())
+}
+
+/// Unpacks the archive next to the binary (same filesystem, so the final
+/// rename is atomic) and moves the new binary over the old one.
+fn install(archive: &[u8], staging: &Path, exe: &Path) -> Result<()> {
+    std::fs::create_dir_all(staging).context("failed to create staging directory")?;
+    let archive_path = staging.join("ratatoist.tar.gz");
+    std::fs::write(&archive_path, archive).context("failed to write archive")?;
+
+    let status = std::process::Command::new("tar")
+        .arg("xzf")
+        .arg(&archive_path)
+        .arg("-C")
+        .arg(staging)
+        .status()
+        .context("failed to run tar")?;
+    if !status.success() {
+        bail!("tar failed to unpack the release ({status})");
+    }
+
+    let new_bin = staging.join("ratatoist");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&new_bin, std::fs::Permissions::from_mode(0o755))
+            .context("failed to mark new binary executable")?;
+    }
+    std::fs::rename(&new_bin, exe).with_context(|| format!("failed to replace {}", exe.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(tag: &str, prerelease: bool) -> Release {
+        Release {
+            tag_name: tag.to_string(),
+            draft: false,
+            prerelease,
+            assets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn picks_newest_stable_tui_release() {
+        let releases = vec![
+            release("ratatoist-tui-v0.9.0", false),
+            release("ratatoist-core-v2.0.0", false),
+            release("ratatoist-tui-v0.10.0", false),
+            release("ratatoist-tui-v0.11.0", true),
+        ];
+        assert_eq!(newest(releases).unwrap().version(), "0.10.0");
+        assert!(is_newer("0.10.0", "0.9.3"));
+        assert!(!is_newer("0.5.0", "0.5.0"));
+        assert!(!is_newer("garbage", "0.5.0"));
+    }
+}